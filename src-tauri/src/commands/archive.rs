@@ -1,6 +1,7 @@
 //! Archive Commands
 //!
-//! Handles archiving (soft-delete) and restoring chapters and scenes.
+//! Handles archiving (soft-delete) and restoring chapters and scenes, and the
+//! separate trash, whose items are permanently deleted when it is emptied.
 
 use tauri::State;
 use uuid::Uuid;
@@ -80,6 +81,45 @@ pub async fn restore_scene(scene_id: String, state: State<'_, AppState>) -> Resu
     Ok(scene)
 }
 
+/// Archive a chapter together with its scenes
+#[tauri::command]
+pub async fn archive_chapter_cascade(
+    chapter_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::archive_chapter_cascade(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    // Update project modified time
+    if let Some(project_id) = db::get_chapter_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Restore a chapter and the scenes that were archived along with it
+#[tauri::command]
+pub async fn restore_chapter_cascade(
+    chapter_id: String,
+    state: State<'_, AppState>,
+) -> Result<Chapter, String> {
+    let uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::restore_chapter_cascade(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    let chapter = db::get_chapter_by_id(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Chapter not found".to_string())?;
+
+    db::update_project_modified(&conn, &chapter.project_id).map_err(|e| e.to_string())?;
+
+    Ok(chapter)
+}
+
 #[derive(serde::Serialize)]
 pub struct ArchivedItems {
     pub chapters: Vec<Chapter>,
@@ -99,3 +139,129 @@ pub async fn get_archived_items(
 
     Ok(ArchivedItems { chapters, scenes })
 }
+
+// ============================================================================
+// Trash
+// ============================================================================
+
+#[tauri::command]
+pub async fn trash_chapter(chapter_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::trash_chapter(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_chapter_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn trash_scene(scene_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::trash_scene(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn restore_from_trash_chapter(
+    chapter_id: String,
+    state: State<'_, AppState>,
+) -> Result<Chapter, String> {
+    let uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::restore_from_trash_chapter(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    let chapter = db::get_chapter_by_id(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Chapter not found".to_string())?;
+
+    db::update_project_modified(&conn, &chapter.project_id).map_err(|e| e.to_string())?;
+
+    Ok(chapter)
+}
+
+#[tauri::command]
+pub async fn restore_from_trash_scene(
+    scene_id: String,
+    state: State<'_, AppState>,
+) -> Result<Scene, String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::restore_from_trash_scene(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    let scene = db::get_scene_by_id(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Scene not found".to_string())?;
+
+    if let Some(project_id) =
+        db::get_chapter_project_id(&conn, &scene.chapter_id).map_err(|e| e.to_string())?
+    {
+        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    }
+
+    Ok(scene)
+}
+
+#[derive(serde::Serialize)]
+pub struct TrashedItems {
+    pub chapters: Vec<Chapter>,
+    pub scenes: Vec<Scene>,
+}
+
+#[tauri::command]
+pub async fn get_trashed_items(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<TrashedItems, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let chapters = db::get_trashed_chapters(&conn, &uuid).map_err(|e| e.to_string())?;
+    let scenes = db::get_trashed_scenes(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    Ok(TrashedItems { chapters, scenes })
+}
+
+#[derive(serde::Serialize)]
+pub struct EmptyTrashResult {
+    pub chapters_deleted: usize,
+    pub scenes_deleted: usize,
+}
+
+/// Permanently delete items that have been in the trash for at least
+/// `older_than_days` days (0 empties the whole trash)
+#[tauri::command]
+pub async fn empty_trash(
+    project_id: String,
+    older_than_days: u32,
+    state: State<'_, AppState>,
+) -> Result<EmptyTrashResult, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(i64::from(older_than_days));
+    let (chapters_deleted, scenes_deleted) =
+        db::delete_trash_older_than(&conn, &uuid, &cutoff.to_rfc3339())
+            .map_err(|e| e.to_string())?;
+
+    if chapters_deleted + scenes_deleted > 0 {
+        db::update_project_modified(&conn, &uuid).map_err(|e| e.to_string())?;
+    }
+
+    Ok(EmptyTrashResult {
+        chapters_deleted,
+        scenes_deleted,
+    })
+}
@@ -0,0 +1,129 @@
+//! Database Backup Commands
+//!
+//! Copies the whole SQLite database to a user-chosen file while the app keeps
+//! its connection open.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+use super::AppState;
+
+/// Result of a database backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseBackup {
+    /// Path the backup was written to
+    pub path: String,
+    /// Size of the backup file in bytes
+    pub size_bytes: u64,
+}
+
+/// Path of the open database file, or `None` for an in-memory database
+fn database_path(conn: &rusqlite::Connection) -> Option<PathBuf> {
+    conn.path()
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Write a consistent copy of the database to `destination`.
+///
+/// Uses `VACUUM INTO`, which reads through SQLite like any other query, so the
+/// copy includes changes still in the WAL and can't catch a write half-done.
+/// An existing file at `destination` is replaced, but never the live database.
+fn write_backup(conn: &rusqlite::Connection, destination: &Path) -> Result<DatabaseBackup, String> {
+    if let Some(live) = database_path(conn) {
+        let same_file = fs::canonicalize(destination)
+            .ok()
+            .zip(fs::canonicalize(&live).ok())
+            .is_some_and(|(dest, live)| dest == live);
+        if same_file {
+            return Err("Cannot back up the database over itself".to_string());
+        }
+    }
+
+    if let Some(parent) = destination.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create backup directory: {}", e))?;
+    }
+    // VACUUM INTO refuses to overwrite
+    if destination.exists() {
+        fs::remove_file(destination)
+            .map_err(|e| format!("Failed to replace existing backup: {}", e))?;
+    }
+
+    conn.execute(
+        "VACUUM INTO ?1",
+        [destination.to_string_lossy().to_string()],
+    )
+    .map_err(|e| format!("Failed to back up database: {}", e))?;
+
+    let size_bytes = fs::metadata(destination).map_err(|e| e.to_string())?.len();
+
+    Ok(DatabaseBackup {
+        path: destination.to_string_lossy().to_string(),
+        size_bytes,
+    })
+}
+
+/// Back up the database to `destination`
+#[tauri::command]
+pub async fn backup_database(
+    destination: String,
+    state: State<'_, AppState>,
+) -> Result<DatabaseBackup, String> {
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    write_backup(&conn, Path::new(&destination))
+}
+
+/// Get the path of the database file, e.g. to show it next to the backup option
+#[tauri::command]
+pub async fn get_database_path(state: State<'_, AppState>) -> Result<String, String> {
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    database_path(&conn)
+        .map(|path| path.to_string_lossy().to_string())
+        .ok_or_else(|| "Database is not stored in a file".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::models::{Project, SourceType};
+
+    #[test]
+    fn test_backup_reopens_with_project() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Backed Up".to_string(), SourceType::Blank, None);
+        db::insert_project(&conn, &project).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let destination = dir.path().join("backups").join("kindling-backup.db");
+        // A stale file at the destination is replaced
+        fs::create_dir_all(destination.parent().unwrap()).unwrap();
+        fs::write(&destination, b"stale").unwrap();
+
+        let backup = write_backup(&conn, &destination).unwrap();
+        assert_eq!(backup.size_bytes, fs::metadata(&destination).unwrap().len());
+        assert!(backup.size_bytes > 5);
+
+        let restored = rusqlite::Connection::open(&destination).unwrap();
+        let found = db::get_project(&restored, &project.id).unwrap().unwrap();
+        assert_eq!(found.name, "Backed Up");
+    }
+
+    #[test]
+    fn test_backup_refuses_to_overwrite_live_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = dir.path().join("kindling.db");
+        let conn = rusqlite::Connection::open(&live).unwrap();
+        db::initialize_schema(&conn).unwrap();
+
+        assert!(database_path(&conn).is_some());
+        assert!(write_backup(&conn, &live).is_err());
+
+        let memory = rusqlite::Connection::open_in_memory().unwrap();
+        assert!(database_path(&memory).is_none());
+    }
+}
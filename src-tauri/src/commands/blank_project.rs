@@ -38,6 +38,11 @@ pub async fn create_blank_project(
         reference_types: Project::default_reference_types(),
         project_type: Project::default_project_type(),
         target_page_count: None,
+        scene_statuses: Vec::new(),
+        publisher: None,
+        isbn: None,
+        series_name: None,
+        series_index: None,
     };
 
     let chapter = Chapter {
@@ -51,6 +56,8 @@ pub async fn create_blank_project(
         is_part: false,
         synopsis: None,
         planning_status: PlanningStatus::Undefined,
+        color: None,
+        word_target: None,
     };
 
     let scene = Scene {
@@ -67,6 +74,13 @@ pub async fn create_blank_project(
         scene_status: SceneStatus::Draft,
         planning_status: PlanningStatus::Undefined,
         editor_mode: EditorMode::Beat,
+        compile_position: None,
+        include_in_compile: true,
+        color: None,
+        pov_character_id: None,
+        notes: None,
+        word_target: None,
+        custom_status: None,
     };
 
     let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
@@ -10,8 +10,10 @@ use uuid::Uuid;
 
 use crate::db;
 use crate::models::{
-    Beat, Chapter, Character, DiscoveryNote, EditorMode, Location, PlanningStatus, Project,
-    ReferenceItem, Scene, SceneReferenceState, SceneStatus, SceneType, SourceType,
+    normalize_custom_statuses, validate_color_label, Beat, BeatRevision, Chapter, Character,
+    CharacterAppearance, DiscoveryNote, EditorMode, Location, OrphanedRef, PlanningStatus, Project,
+    RecentProjectInfo, ReferenceItem, ReplacePreview, Scene, SceneReferenceState, SceneStatus,
+    SceneType, SourceType,
 };
 
 use super::AppState;
@@ -30,10 +32,27 @@ pub async fn get_project(id: String, state: State<'_, AppState>) -> Result<Proje
         .ok_or_else(|| "Project not found".to_string())
 }
 
+/// Number of projects listed as recent when the caller gives no limit
+const DEFAULT_RECENT_PROJECTS_LIMIT: usize = 10;
+
+#[tauri::command]
+pub async fn get_recent_projects(
+    limit: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<Vec<Project>, String> {
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_recent_projects(&conn, limit.unwrap_or(DEFAULT_RECENT_PROJECTS_LIMIT))
+        .map_err(|e| e.to_string())
+}
+
+/// Recent projects with scene count, cached word count and relative modified time
 #[tauri::command]
-pub async fn get_recent_projects(state: State<'_, AppState>) -> Result<Vec<Project>, String> {
+pub async fn get_recent_project_infos(
+    limit: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<Vec<RecentProjectInfo>, String> {
     let conn = state.db.lock().map_err(|e| e.to_string())?;
-    db::get_recent_projects(&conn, 10).map_err(|e| e.to_string())
+    super::recent_project_infos(&conn, limit.unwrap_or(DEFAULT_RECENT_PROJECTS_LIMIT))
 }
 
 #[tauri::command]
@@ -52,6 +71,11 @@ pub struct ProjectSettingsUpdate {
     pub reference_types: Option<Vec<String>>,
     pub project_type: Option<String>,
     pub target_page_count: Option<i32>,
+    pub scene_statuses: Option<Vec<String>>,
+    pub publisher: Option<String>,
+    pub isbn: Option<String>,
+    pub series_name: Option<String>,
+    pub series_index: Option<i32>,
 }
 
 #[tauri::command]
@@ -80,6 +104,13 @@ pub async fn update_project_settings(
         project.project_type = project_type;
     }
     project.target_page_count = settings.target_page_count;
+    if let Some(scene_statuses) = settings.scene_statuses {
+        project.scene_statuses = normalize_custom_statuses(scene_statuses);
+    }
+    project.publisher = settings.publisher;
+    project.isbn = settings.isbn;
+    project.series_name = settings.series_name;
+    project.series_index = settings.series_index;
 
     // Update modified timestamp
     project.modified_at = chrono::Utc::now().to_rfc3339();
@@ -138,13 +169,24 @@ pub async fn create_chapter(
     title: String,
     is_part: Option<bool>,
     after_id: Option<String>,
+    position: Option<i32>,
     state: State<'_, AppState>,
 ) -> Result<Chapter, String> {
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
-    // Determine position based on after_id or append to end
-    let position = if let Some(ref after_chapter_id) = after_id {
+    // Determine position from an explicit index, after_id, or append to end
+    let position = if let Some(position) = position {
+        // Clamp so an out-of-range index inserts at the start or end
+        let end =
+            db::get_max_chapter_position(&conn, &project_uuid).map_err(|e| e.to_string())? + 1;
+        let position = position.clamp(0, end);
+
+        db::shift_chapters_after_position(&conn, &project_uuid, position)
+            .map_err(|e| e.to_string())?;
+
+        position
+    } else if let Some(ref after_chapter_id) = after_id {
         let after_uuid = Uuid::parse_str(after_chapter_id).map_err(|e| e.to_string())?;
         let after_chapter = db::get_chapter_by_id(&conn, &after_uuid)
             .map_err(|e| e.to_string())?
@@ -174,6 +216,8 @@ pub async fn create_chapter(
         is_part: is_part.unwrap_or(false),
         synopsis: None,
         planning_status: PlanningStatus::Fixed,
+        color: None,
+        word_target: None,
     };
 
     db::insert_chapter(&conn, &chapter).map_err(|e| e.to_string())?;
@@ -233,6 +277,8 @@ pub async fn duplicate_chapter(
         is_part: original.is_part,
         synopsis: original.synopsis.clone(),
         planning_status: original.planning_status,
+        color: original.color.clone(),
+        word_target: original.word_target,
     };
 
     db::insert_chapter(&tx, &new_chapter).map_err(|e| e.to_string())?;
@@ -253,6 +299,13 @@ pub async fn duplicate_chapter(
             scene_status: scene.scene_status,
             planning_status: PlanningStatus::Fixed,
             editor_mode: scene.editor_mode,
+            compile_position: scene.compile_position,
+            include_in_compile: scene.include_in_compile,
+            color: scene.color.clone(),
+            pov_character_id: scene.pov_character_id,
+            notes: scene.notes.clone(),
+            word_target: scene.word_target,
+            custom_status: scene.custom_status.clone(),
         };
         db::insert_scene(&tx, &new_scene).map_err(|e| e.to_string())?;
 
@@ -338,13 +391,23 @@ pub async fn get_scenes(
 pub async fn create_scene(
     chapter_id: String,
     title: String,
+    position: Option<i32>,
     state: State<'_, AppState>,
 ) -> Result<Scene, String> {
     let chapter_uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
-    // Get next position
-    let position = db::get_max_scene_position(&conn, &chapter_uuid).map_err(|e| e.to_string())? + 1;
+    // Insert at the requested index (clamped to the chapter), or append to end
+    let end = db::get_max_scene_position(&conn, &chapter_uuid).map_err(|e| e.to_string())? + 1;
+    let position = match position {
+        Some(position) => {
+            let position = position.clamp(0, end);
+            db::shift_scenes_after_position(&conn, &chapter_uuid, position)
+                .map_err(|e| e.to_string())?;
+            position
+        }
+        None => end,
+    };
 
     // Blank projects: new scenes default to Undefined; imported projects default to Fixed
     let planning_status = if let Some(project_id) =
@@ -376,6 +439,13 @@ pub async fn create_scene(
         scene_status: SceneStatus::Draft,
         planning_status,
         editor_mode: EditorMode::Beat,
+        compile_position: None,
+        include_in_compile: true,
+        color: None,
+        pov_character_id: None,
+        notes: None,
+        word_target: None,
+        custom_status: None,
     };
 
     db::insert_scene(&conn, &scene).map_err(|e| e.to_string())?;
@@ -394,6 +464,7 @@ pub async fn create_scene(
 pub async fn save_scene_prose(
     scene_id: String,
     prose: String,
+    app_handle: AppHandle,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
@@ -409,6 +480,8 @@ pub async fn save_scene_prose(
     // Update project modified time
     if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
         let _ = db::update_project_modified(&conn, &project_id);
+        // An auto-snapshot failure must never fail the save
+        let _ = super::maybe_create_auto_snapshot(&conn, &app_handle, &project_id);
     }
 
     Ok(())
@@ -425,6 +498,33 @@ pub async fn switch_scene_editor_mode(
     db::switch_scene_editor_mode(&conn, &uuid, &mode).map_err(|e| e.to_string())
 }
 
+/// Concatenate a scene's beat prose into the scene's own prose
+///
+/// Beats are kept unless `clear_beats` is set, in which case the scene is left
+/// with flat prose only.
+#[tauri::command]
+pub async fn consolidate_scene_prose(
+    scene_id: String,
+    clear_beats: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Scene, String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    if db::is_scene_locked(&conn, &uuid).map_err(|e| e.to_string())? {
+        return Err("Cannot edit a locked scene".to_string());
+    }
+
+    let scene = db::consolidate_scene_prose(&conn, &uuid, clear_beats.unwrap_or(false))
+        .map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(scene)
+}
+
 #[tauri::command]
 pub async fn save_scene_page_prose(
     scene_id: String,
@@ -471,6 +571,26 @@ pub async fn save_scene_synopsis(
     Ok(())
 }
 
+/// Save a scene's private notes. Notes never reach an export, so they stay
+/// editable on locked scenes.
+#[tauri::command]
+pub async fn update_scene_notes(
+    scene_id: String,
+    notes: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::update_scene_notes(&conn, &uuid, notes.as_deref()).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(())
+}
+
 #[derive(serde::Deserialize)]
 pub struct SceneMetadataUpdate {
     pub scene_type: String,
@@ -492,13 +612,39 @@ pub async fn update_scene_metadata(
     }
 
     let scene_type = SceneType::parse(&metadata.scene_type);
-    let scene_status = SceneStatus::parse(&metadata.scene_status);
+    let (scene_status, custom_status) = SceneStatus::parse_stored(&metadata.scene_status);
+    let project_id = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    // Custom labels must come from the project's own status vocabulary
+    let custom_status = match custom_status {
+        Some(label) => {
+            let project = match project_id {
+                Some(project_id) => {
+                    db::get_project(&conn, &project_id).map_err(|e| e.to_string())?
+                }
+                None => None,
+            };
+            let known = project.and_then(|p| {
+                p.scene_statuses
+                    .into_iter()
+                    .find(|status| status.eq_ignore_ascii_case(&label))
+            });
+            Some(known.ok_or_else(|| format!("Unknown scene status: {}", label))?)
+        }
+        None => None,
+    };
 
-    db::update_scene_metadata(&conn, &uuid, &scene_type, &scene_status)
-        .map_err(|e| e.to_string())?;
+    db::update_scene_metadata(
+        &conn,
+        &uuid,
+        &scene_type,
+        &scene_status,
+        custom_status.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
 
     // Update project modified time
-    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+    if let Some(project_id) = project_id {
         let _ = db::update_project_modified(&conn, &project_id);
     }
 
@@ -524,6 +670,46 @@ pub async fn update_scene_planning_status(
     Ok(())
 }
 
+/// Set a scene's place in compiled output, or clear it with `None` to follow
+/// binder order again
+#[tauri::command]
+pub async fn update_scene_compile_position(
+    scene_id: String,
+    compile_position: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::update_scene_compile_position(&conn, &uuid, compile_position).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(())
+}
+
+/// Include a scene in, or exclude it from, exports and manuscript word counts
+#[tauri::command]
+pub async fn set_scene_compile_inclusion(
+    scene_id: String,
+    include_in_compile: bool,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::update_scene_compile_inclusion(&conn, &uuid, include_in_compile)
+        .map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn update_chapter_planning_status(
     chapter_id: String,
@@ -543,6 +729,102 @@ pub async fn update_chapter_planning_status(
     Ok(())
 }
 
+/// Set a chapter's `#RRGGBB` color label, or clear it with `None`
+#[tauri::command]
+pub async fn set_chapter_color(
+    chapter_id: String,
+    color: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    let color = color.as_deref().map(validate_color_label).transpose()?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::set_chapter_color(&conn, &uuid, color.as_deref()).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_chapter_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(())
+}
+
+/// Set a chapter's word-count goal, or clear it with `None`
+#[tauri::command]
+pub async fn set_chapter_word_target(
+    chapter_id: String,
+    word_target: Option<i32>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    if word_target.is_some_and(|t| t < 0) {
+        return Err("Word target cannot be negative".to_string());
+    }
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::set_chapter_word_target(&conn, &uuid, word_target).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_chapter_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(())
+}
+
+/// Set a scene's `#RRGGBB` color label, or clear it with `None`
+#[tauri::command]
+pub async fn set_scene_color(
+    scene_id: String,
+    color: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let color = color.as_deref().map(validate_color_label).transpose()?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::set_scene_color(&conn, &uuid, color.as_deref()).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    Ok(())
+}
+
+/// Set a scene's point-of-view character, or clear it with `None`
+#[tauri::command]
+pub async fn set_scene_pov(
+    scene_id: String,
+    character_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let character_uuid = character_id
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let project_id = db::get_scene_project_id(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Scene not found: {}", scene_id))?;
+
+    if let Some(ref character_uuid) = character_uuid {
+        let character_project = db::get_character_project_id(&conn, character_uuid)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Character not found".to_string())?;
+        if character_project != project_id {
+            return Err("POV character belongs to a different project".to_string());
+        }
+    }
+
+    db::set_scene_pov(&conn, &uuid, character_uuid.as_ref()).map_err(|e| e.to_string())?;
+    let _ = db::update_project_modified(&conn, &project_id);
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn update_chapter_synopsis(
     chapter_id: String,
@@ -619,6 +901,13 @@ pub async fn duplicate_scene(
         scene_status: original.scene_status,
         planning_status: original.planning_status,
         editor_mode: original.editor_mode,
+        compile_position: original.compile_position,
+        include_in_compile: original.include_in_compile,
+        color: original.color.clone(),
+        pov_character_id: original.pov_character_id,
+        notes: original.notes.clone(),
+        word_target: original.word_target,
+        custom_status: original.custom_status.clone(),
     };
 
     db::insert_scene(&tx, &new_scene).map_err(|e| e.to_string())?;
@@ -684,6 +973,45 @@ pub async fn delete_scene(
     Ok(())
 }
 
+/// Merge the second scene into the first, appending its beats and references
+#[tauri::command]
+pub async fn merge_scenes(
+    first_scene_id: String,
+    second_scene_id: String,
+    state: State<'_, AppState>,
+) -> Result<Scene, String> {
+    let first_uuid = Uuid::parse_str(&first_scene_id).map_err(|e| e.to_string())?;
+    let second_uuid = Uuid::parse_str(&second_scene_id).map_err(|e| e.to_string())?;
+    if first_uuid == second_uuid {
+        return Err("Cannot merge a scene with itself".to_string());
+    }
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let first = db::get_scene_by_id(&conn, &first_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Scene not found".to_string())?;
+    let second = db::get_scene_by_id(&conn, &second_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Scene not found".to_string())?;
+
+    if first.chapter_id != second.chapter_id {
+        return Err("Only scenes in the same chapter can be merged".to_string());
+    }
+    if first.locked || second.locked {
+        return Err("Cannot merge a locked scene".to_string());
+    }
+
+    let merged = db::merge_scenes(&conn, &first_uuid, &second_uuid).map_err(|e| e.to_string())?;
+
+    if let Some(project_id) =
+        db::get_chapter_project_id(&conn, &merged.chapter_id).map_err(|e| e.to_string())?
+    {
+        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    }
+
+    Ok(merged)
+}
+
 // ============================================================================
 // Beat Commands
 // ============================================================================
@@ -730,6 +1058,8 @@ pub async fn create_beat(
 pub async fn save_beat_prose(
     beat_id: String,
     prose: String,
+    record_revision: Option<bool>,
+    app_handle: AppHandle,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     let uuid = Uuid::parse_str(&beat_id).map_err(|e| e.to_string())?;
@@ -744,17 +1074,80 @@ pub async fn save_beat_prose(
         return Err("Cannot edit beats in a locked scene".to_string());
     }
 
-    db::update_beat_prose(&conn, &uuid, &prose).map_err(|e| e.to_string())?;
+    if record_revision.unwrap_or(false) {
+        db::update_beat_prose_with_revision(&conn, &uuid, &prose).map_err(|e| e.to_string())?;
+    } else {
+        db::update_beat_prose(&conn, &uuid, &prose).map_err(|e| e.to_string())?;
+    }
 
     if let Some(project_id) =
         db::get_scene_project_id(&conn, &scene_id).map_err(|e| e.to_string())?
     {
-        let _ = db::update_project_modified(&conn, &project_id);
+        let _ = super::mark_modified_after_beat_prose_edit(
+            &conn,
+            &project_id,
+            &scene_id,
+            beat.prose.as_deref(),
+            &prose,
+        );
+        // An auto-snapshot failure must never fail the save
+        let _ = super::maybe_create_auto_snapshot(&conn, &app_handle, &project_id);
     }
 
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_beat_revisions(
+    beat_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<BeatRevision>, String> {
+    let uuid = Uuid::parse_str(&beat_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_beat_revisions(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+/// Set a beat's prose back to an earlier revision; the prose it replaces becomes a revision
+#[tauri::command]
+pub async fn restore_beat_revision(
+    revision_id: String,
+    state: State<'_, AppState>,
+) -> Result<Beat, String> {
+    let uuid = Uuid::parse_str(&revision_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let revision = db::get_beat_revision(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Revision not found".to_string())?;
+    let beat = db::get_beat(&conn, &revision.beat_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Beat not found".to_string())?;
+
+    if db::is_scene_locked(&conn, &beat.scene_id).map_err(|e| e.to_string())? {
+        return Err("Cannot edit beats in a locked scene".to_string());
+    }
+
+    db::update_beat_prose_with_revision(&conn, &beat.id, &revision.prose)
+        .map_err(|e| e.to_string())?;
+
+    if let Some(project_id) =
+        db::get_scene_project_id(&conn, &beat.scene_id).map_err(|e| e.to_string())?
+    {
+        let _ = super::mark_modified_after_beat_prose_edit(
+            &conn,
+            &project_id,
+            &beat.scene_id,
+            beat.prose.as_deref(),
+            &revision.prose,
+        );
+    }
+
+    Ok(Beat {
+        prose: Some(revision.prose),
+        ..beat
+    })
+}
+
 #[tauri::command]
 pub async fn delete_beat(beat_id: String, state: State<'_, AppState>) -> Result<(), String> {
     let beat_uuid = Uuid::parse_str(&beat_id).map_err(|e| e.to_string())?;
@@ -826,14 +1219,17 @@ pub async fn reorder_beats(
         return Err("Beat order must include all beats in the scene".to_string());
     }
 
-    for (position, id_str) in beat_ids.iter().enumerate() {
+    let mut beat_uuids = Vec::with_capacity(beat_ids.len());
+    for id_str in &beat_ids {
         let beat_uuid = Uuid::parse_str(id_str).map_err(|e| e.to_string())?;
         if !existing_ids.contains(&beat_uuid) {
             return Err(format!("Beat {} does not belong to this scene", id_str));
         }
-        db::update_beat_position(&conn, &beat_uuid, position as i32).map_err(|e| e.to_string())?;
+        beat_uuids.push(beat_uuid);
     }
 
+    db::reorder_beats(&conn, &scene_uuid, &beat_uuids).map_err(|e| e.to_string())?;
+
     if let Some(project_id) =
         db::get_scene_project_id(&conn, &scene_uuid).map_err(|e| e.to_string())?
     {
@@ -1217,6 +1613,81 @@ pub async fn get_characters(
     db::get_characters(&conn, &uuid).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn get_characters_with_attribute(
+    project_id: String,
+    key: String,
+    value: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<Character>, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_characters_with_attribute(&conn, &uuid, &key, &value).map_err(|e| e.to_string())
+}
+
+/// Scenes the character is referenced in, ordered by chapter then scene position
+#[tauri::command]
+pub async fn get_character_appearances(
+    character_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<CharacterAppearance>, String> {
+    let uuid = Uuid::parse_str(&character_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_character_appearances(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_character_aliases(
+    character_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&character_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_character_aliases(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_character_alias(
+    character_id: String,
+    alias: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&character_id).map_err(|e| e.to_string())?;
+    let alias = alias.trim();
+    if alias.is_empty() {
+        return Err("Alias cannot be empty".to_string());
+    }
+
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let project_id = db::get_character_project_id(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Character not found".to_string())?;
+
+    db::add_character_alias(&conn, &uuid, alias).map_err(|e| e.to_string())?;
+    let _ = db::update_project_modified(&conn, &project_id);
+
+    db::get_character_aliases(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn remove_character_alias(
+    character_id: String,
+    alias: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&character_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::remove_character_alias(&conn, &uuid, &alias).map_err(|e| e.to_string())?;
+    if let Some(project_id) =
+        db::get_character_project_id(&conn, &uuid).map_err(|e| e.to_string())?
+    {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+
+    db::get_character_aliases(&conn, &uuid).map_err(|e| e.to_string())
+}
+
 // ============================================================================
 // Location Commands
 // ============================================================================
@@ -1294,8 +1765,42 @@ pub async fn get_scene_reference_items(
 ) -> Result<Vec<ReferenceItem>, String> {
     let scene_uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
+    scene_references(&conn, &scene_uuid, &reference_type)
+}
 
-    db::get_scene_reference_items(&conn, &scene_uuid, &reference_type).map_err(|e| e.to_string())
+/// A scene's linked references of one type, resolved and sorted by name
+fn scene_references(
+    conn: &rusqlite::Connection,
+    scene_uuid: &Uuid,
+    reference_type: &str,
+) -> Result<Vec<ReferenceItem>, String> {
+    let mut references = Vec::new();
+    match reference_type {
+        "characters" => {
+            for id in db::get_scene_characters(conn, scene_uuid).map_err(|e| e.to_string())? {
+                if let Some(character) =
+                    db::get_character_by_id(conn, &id).map_err(|e| e.to_string())?
+                {
+                    references.push(character_to_reference(character));
+                }
+            }
+        }
+        "locations" => {
+            for id in db::get_scene_locations(conn, scene_uuid).map_err(|e| e.to_string())? {
+                if let Some(location) =
+                    db::get_location_by_id(conn, &id).map_err(|e| e.to_string())?
+                {
+                    references.push(location_to_reference(location));
+                }
+            }
+        }
+        _ => {
+            return db::get_scene_reference_items(conn, scene_uuid, reference_type)
+                .map_err(|e| e.to_string())
+        }
+    }
+    references.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(references)
 }
 
 #[derive(serde::Deserialize)]
@@ -1336,58 +1841,77 @@ pub async fn save_scene_reference_state(
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
     let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let project_id = replace_scene_references(&tx, &scene_uuid, &reference_type, &states)?;
+    db::update_project_modified(&tx, &project_id).map_err(|e| e.to_string())?;
+
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Replace a scene's links and panel state for one reference type. Every
+/// reference must belong to the scene's project; returns that project's id.
+fn replace_scene_references(
+    tx: &rusqlite::Connection,
+    scene_uuid: &Uuid,
+    reference_type: &str,
+    states: &[SceneReferenceStateUpdate],
+) -> Result<Uuid, String> {
+    let project_id = db::get_scene_project_id(tx, scene_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Scene not found".to_string())?;
 
-    db::delete_scene_reference_states_for_type(&tx, &scene_uuid, &reference_type)
+    db::delete_scene_reference_states_for_type(tx, scene_uuid, reference_type)
         .map_err(|e| e.to_string())?;
 
     let mut reference_ids = Vec::new();
-    for state_update in &states {
+    for state_update in states {
         let reference_id =
             Uuid::parse_str(&state_update.reference_id).map_err(|e| e.to_string())?;
+        let owner = reference_project_id(tx, &reference_id, reference_type)
+            .map_err(|e| format!("{}: {}", e, reference_id))?;
+        if owner != project_id {
+            return Err(format!(
+                "Reference {} belongs to a different project",
+                reference_id
+            ));
+        }
         reference_ids.push(reference_id);
         let state = SceneReferenceState {
-            scene_id: scene_uuid,
-            reference_type: reference_type.clone(),
+            scene_id: *scene_uuid,
+            reference_type: reference_type.to_string(),
             reference_id,
             position: state_update.position,
             expanded: state_update.expanded,
         };
-        db::insert_scene_reference_state(&tx, &state).map_err(|e| e.to_string())?;
+        db::insert_scene_reference_state(tx, &state).map_err(|e| e.to_string())?;
     }
 
-    match reference_type.as_str() {
+    match reference_type {
         "characters" => {
-            db::clear_scene_character_refs(&tx, &scene_uuid).map_err(|e| e.to_string())?;
+            db::clear_scene_character_refs(tx, scene_uuid).map_err(|e| e.to_string())?;
             for reference_id in reference_ids {
-                db::add_scene_character_ref(&tx, &scene_uuid, &reference_id)
+                db::add_scene_character_ref(tx, scene_uuid, &reference_id)
                     .map_err(|e| e.to_string())?;
             }
         }
         "locations" => {
-            db::clear_scene_location_refs(&tx, &scene_uuid).map_err(|e| e.to_string())?;
+            db::clear_scene_location_refs(tx, scene_uuid).map_err(|e| e.to_string())?;
             for reference_id in reference_ids {
-                db::add_scene_location_ref(&tx, &scene_uuid, &reference_id)
+                db::add_scene_location_ref(tx, scene_uuid, &reference_id)
                     .map_err(|e| e.to_string())?;
             }
         }
         _ => {
-            db::clear_scene_reference_item_refs_for_type(&tx, &scene_uuid, &reference_type)
+            db::clear_scene_reference_item_refs_for_type(tx, scene_uuid, reference_type)
                 .map_err(|e| e.to_string())?;
             for reference_id in reference_ids {
-                db::add_scene_reference_item_ref(&tx, &scene_uuid, &reference_id)
+                db::add_scene_reference_item_ref(tx, scene_uuid, &reference_id)
                     .map_err(|e| e.to_string())?;
             }
         }
     }
 
-    if let Some(project_id) =
-        db::get_scene_project_id(&tx, &scene_uuid).map_err(|e| e.to_string())?
-    {
-        db::update_project_modified(&tx, &project_id).map_err(|e| e.to_string())?;
-    }
-
-    tx.commit().map_err(|e| e.to_string())?;
-    Ok(())
+    Ok(project_id)
 }
 
 #[derive(serde::Deserialize)]
@@ -1664,6 +2188,17 @@ pub async fn reclassify_references(
     }
 }
 
+/// Fetch every reference item of a project, across all custom reference types
+#[tauri::command]
+pub async fn get_all_reference_items(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<ReferenceItem>, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_all_reference_items(&conn, &uuid).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn create_reference(
     project_id: String,
@@ -1673,6 +2208,31 @@ pub async fn create_reference(
 ) -> Result<String, String> {
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let entity_id = insert_reference(&conn, &project_uuid, reference_type, reference)?;
+
+    db::update_project_modified(&conn, &project_uuid).map_err(|e| e.to_string())?;
+    Ok(entity_id.to_string())
+}
+
+/// Create a character, location or reference item, refusing types the project
+/// has not enabled in its `reference_types`
+fn insert_reference(
+    conn: &rusqlite::Connection,
+    project_uuid: &Uuid,
+    reference_type: String,
+    reference: ReferenceUpsert,
+) -> Result<Uuid, String> {
+    let project = db::get_project(conn, project_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Project not found".to_string())?;
+    if !project.reference_types.contains(&reference_type) {
+        return Err(format!(
+            "Reference type '{}' is not enabled for this project",
+            reference_type
+        ));
+    }
+
+    let project_uuid = *project_uuid;
     let attributes = reference.attributes.unwrap_or_default();
 
     let entity_id = match reference_type.as_str() {
@@ -1681,14 +2241,14 @@ pub async fn create_reference(
                 Character::new(project_uuid, reference.name, reference.description, None)
                     .with_attributes(attributes);
             let id = character.id;
-            db::insert_character(&conn, &character).map_err(|e| e.to_string())?;
+            db::insert_character(conn, &character).map_err(|e| e.to_string())?;
             id
         }
         "locations" => {
             let location = Location::new(project_uuid, reference.name, reference.description, None)
                 .with_attributes(attributes);
             let id = location.id;
-            db::insert_location(&conn, &location).map_err(|e| e.to_string())?;
+            db::insert_location(conn, &location).map_err(|e| e.to_string())?;
             id
         }
         _ => {
@@ -1701,13 +2261,12 @@ pub async fn create_reference(
             )
             .with_attributes(attributes);
             let id = item.id;
-            db::insert_reference_item(&conn, &item).map_err(|e| e.to_string())?;
+            db::insert_reference_item(conn, &item).map_err(|e| e.to_string())?;
             id
         }
     };
 
-    db::update_project_modified(&conn, &project_uuid).map_err(|e| e.to_string())?;
-    Ok(entity_id.to_string())
+    Ok(entity_id)
 }
 
 #[tauri::command]
@@ -1719,49 +2278,64 @@ pub async fn update_reference(
 ) -> Result<(), String> {
     let reference_uuid = Uuid::parse_str(&reference_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
-    let attributes = reference.attributes.unwrap_or_default();
+    let project_id = modify_reference(&conn, &reference_uuid, &reference_type, reference)?;
 
-    let project_id = match reference_type.as_str() {
-        "characters" => {
-            db::update_character(
-                &conn,
-                &reference_uuid,
-                &reference.name,
-                reference.description.as_deref(),
-                &attributes,
-            )
-            .map_err(|e| e.to_string())?;
-            db::get_character_project_id(&conn, &reference_uuid).map_err(|e| e.to_string())?
-        }
-        "locations" => {
-            db::update_location(
-                &conn,
-                &reference_uuid,
-                &reference.name,
-                reference.description.as_deref(),
-                &attributes,
-            )
-            .map_err(|e| e.to_string())?;
-            db::get_location_project_id(&conn, &reference_uuid).map_err(|e| e.to_string())?
-        }
-        _ => {
-            db::update_reference_item(
-                &conn,
-                &reference_uuid,
-                &reference.name,
-                reference.description.as_deref(),
-                &attributes,
-            )
-            .map_err(|e| e.to_string())?;
-            db::get_reference_item_project_id(&conn, &reference_uuid).map_err(|e| e.to_string())?
-        }
-    };
+    db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-    if let Some(project_id) = project_id {
-        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
-    }
+/// Project that owns a character, location or reference item
+fn reference_project_id(
+    conn: &rusqlite::Connection,
+    reference_uuid: &Uuid,
+    reference_type: &str,
+) -> Result<Uuid, String> {
+    let project_id = match reference_type {
+        "characters" => db::get_character_project_id(conn, reference_uuid),
+        "locations" => db::get_location_project_id(conn, reference_uuid),
+        _ => db::get_reference_item_project_id(conn, reference_uuid),
+    }
+    .map_err(|e| e.to_string())?;
+    project_id.ok_or_else(|| "Reference not found".to_string())
+}
 
-    Ok(())
+/// Update an existing reference, returning the project it belongs to
+fn modify_reference(
+    conn: &rusqlite::Connection,
+    reference_uuid: &Uuid,
+    reference_type: &str,
+    reference: ReferenceUpsert,
+) -> Result<Uuid, String> {
+    let project_id = reference_project_id(conn, reference_uuid, reference_type)?;
+    let attributes = reference.attributes.unwrap_or_default();
+    let description = reference.description.as_deref();
+
+    match reference_type {
+        "characters" => db::update_character(
+            conn,
+            reference_uuid,
+            &reference.name,
+            description,
+            &attributes,
+        ),
+        "locations" => db::update_location(
+            conn,
+            reference_uuid,
+            &reference.name,
+            description,
+            &attributes,
+        ),
+        _ => db::update_reference_item(
+            conn,
+            reference_uuid,
+            &reference.name,
+            description,
+            &attributes,
+        ),
+    }
+    .map_err(|e| e.to_string())?;
+
+    Ok(project_id)
 }
 
 #[tauri::command]
@@ -1772,36 +2346,117 @@ pub async fn delete_reference(
 ) -> Result<(), String> {
     let reference_uuid = Uuid::parse_str(&reference_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
 
-    let project_id = match reference_type.as_str() {
-        "characters" => {
-            let project_id =
-                db::get_character_project_id(&conn, &reference_uuid).map_err(|e| e.to_string())?;
-            db::delete_character(&conn, &reference_uuid).map_err(|e| e.to_string())?;
-            project_id
-        }
-        "locations" => {
-            let project_id =
-                db::get_location_project_id(&conn, &reference_uuid).map_err(|e| e.to_string())?;
-            db::delete_location(&conn, &reference_uuid).map_err(|e| e.to_string())?;
-            project_id
-        }
-        _ => {
-            let project_id = db::get_reference_item_project_id(&conn, &reference_uuid)
-                .map_err(|e| e.to_string())?;
-            db::delete_reference_item(&conn, &reference_uuid).map_err(|e| e.to_string())?;
-            project_id
-        }
-    };
+    let project_id = remove_reference(&tx, &reference_uuid, &reference_type)?;
+    db::update_project_modified(&tx, &project_id).map_err(|e| e.to_string())?;
+
+    tx.commit().map_err(|e| e.to_string())
+}
+
+/// Delete a reference and its scene panel state, returning the owning project
+fn remove_reference(
+    conn: &rusqlite::Connection,
+    reference_uuid: &Uuid,
+    reference_type: &str,
+) -> Result<Uuid, String> {
+    let project_id = reference_project_id(conn, reference_uuid, reference_type)?;
+
+    match reference_type {
+        "characters" => db::delete_character(conn, reference_uuid),
+        "locations" => db::delete_location(conn, reference_uuid),
+        _ => db::delete_reference_item(conn, reference_uuid),
+    }
+    .map_err(|e| e.to_string())?;
 
-    db::delete_scene_reference_states_for_reference(&conn, &reference_type, &reference_uuid)
+    db::delete_scene_reference_states_for_reference(conn, reference_type, reference_uuid)
         .map_err(|e| e.to_string())?;
 
-    if let Some(project_id) = project_id {
-        db::update_project_modified(&conn, &project_id).map_err(|e| e.to_string())?;
+    Ok(project_id)
+}
+
+fn reference_aliases(
+    conn: &rusqlite::Connection,
+    reference_uuid: &Uuid,
+    reference_type: &str,
+) -> Result<Vec<String>, String> {
+    match reference_type {
+        "characters" => db::get_character_aliases(conn, reference_uuid),
+        "locations" => db::get_location_aliases(conn, reference_uuid),
+        _ => db::get_reference_item_aliases(conn, reference_uuid),
+    }
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_reference_aliases(
+    reference_id: String,
+    reference_type: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&reference_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    reference_aliases(&conn, &uuid, &reference_type)
+}
+
+#[tauri::command]
+pub async fn add_reference_alias(
+    reference_id: String,
+    reference_type: String,
+    alias: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&reference_id).map_err(|e| e.to_string())?;
+    let alias = alias.trim();
+    if alias.is_empty() {
+        return Err("Alias cannot be empty".to_string());
     }
 
-    Ok(())
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let project_id = reference_project_id(&conn, &uuid, &reference_type)?;
+
+    match reference_type.as_str() {
+        "characters" => db::add_character_alias(&conn, &uuid, alias),
+        "locations" => db::add_location_alias(&conn, &uuid, alias),
+        _ => db::add_reference_item_alias(&conn, &uuid, alias),
+    }
+    .map_err(|e| e.to_string())?;
+    let _ = db::update_project_modified(&conn, &project_id);
+
+    reference_aliases(&conn, &uuid, &reference_type)
+}
+
+#[tauri::command]
+pub async fn remove_reference_alias(
+    reference_id: String,
+    reference_type: String,
+    alias: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&reference_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let project_id = reference_project_id(&conn, &uuid, &reference_type)?;
+
+    match reference_type.as_str() {
+        "characters" => db::remove_character_alias(&conn, &uuid, &alias),
+        "locations" => db::remove_location_alias(&conn, &uuid, &alias),
+        _ => db::remove_reference_item_alias(&conn, &uuid, &alias),
+    }
+    .map_err(|e| e.to_string())?;
+    let _ = db::update_project_modified(&conn, &project_id);
+
+    reference_aliases(&conn, &uuid, &reference_type)
+}
+
+/// Diagnostic: scene references pointing at characters, locations or items that no longer exist
+#[tauri::command]
+pub async fn find_orphaned_refs(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<OrphanedRef>, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::find_orphaned_refs(&conn, &uuid).map_err(|e| e.to_string())
 }
 
 // ============================================================================
@@ -1827,6 +2482,25 @@ pub async fn reorder_chapters(
     Ok(())
 }
 
+/// Move a chapter to `new_position`, shifting the chapters in between
+#[tauri::command]
+pub async fn move_chapter(
+    project_id: String,
+    chapter_id: String,
+    new_position: i32,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let chapter_uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::move_chapter_to_position(&conn, &project_uuid, &chapter_uuid, new_position)
+        .map_err(|e| e.to_string())?;
+    db::update_project_modified(&conn, &project_uuid).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn reorder_scenes(
     chapter_id: String,
@@ -1852,6 +2526,42 @@ pub async fn reorder_scenes(
     Ok(())
 }
 
+/// Move a scene to a position in another (or the same) chapter, renumbering the
+/// scenes of both chapters
+#[tauri::command]
+pub async fn move_and_reorder_scene(
+    scene_id: String,
+    target_chapter_id: String,
+    target_position: i32,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let scene_uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let target_chapter_uuid = Uuid::parse_str(&target_chapter_id).map_err(|e| e.to_string())?;
+
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let target_chapter = db::get_chapter_by_id(&conn, &target_chapter_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Chapter not found".to_string())?;
+    if target_chapter.locked {
+        return Err("Cannot move a scene into a locked chapter".to_string());
+    }
+    let scene = db::get_scene_by_id(&conn, &scene_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Scene not found".to_string())?;
+    if scene.chapter_id != target_chapter_uuid
+        && db::is_chapter_locked(&conn, &scene.chapter_id).map_err(|e| e.to_string())?
+    {
+        return Err("Cannot move a scene out of a locked chapter".to_string());
+    }
+
+    db::move_and_reorder_scene(&conn, &scene_uuid, &target_chapter_uuid, target_position)
+        .map_err(|e| e.to_string())?;
+    db::update_project_modified(&conn, &target_chapter.project_id).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn move_scene_to_chapter(
     scene_id: String,
@@ -1875,3 +2585,230 @@ pub async fn move_scene_to_chapter(
 
     Ok(())
 }
+
+// ============================================================================
+// Find and Replace Commands
+// ============================================================================
+
+/// Replace text across the prose of a project's scenes and beats
+///
+/// Only text between tags changes, so formatting markup survives. Locked,
+/// archived and trashed scenes and chapters are left alone. Returns the number
+/// of replacements made.
+#[tauri::command]
+pub async fn find_and_replace(
+    project_id: String,
+    find: String,
+    replace: String,
+    case_sensitive: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    if find.is_empty() {
+        return Err("Search text cannot be empty".to_string());
+    }
+
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let count = db::replace_in_prose(
+        &conn,
+        &project_uuid,
+        &find,
+        &replace,
+        case_sensitive.unwrap_or(false),
+    )
+    .map_err(|e| e.to_string())?;
+
+    if count > 0 {
+        db::update_project_modified(&conn, &project_uuid).map_err(|e| e.to_string())?;
+    }
+
+    Ok(count)
+}
+
+/// Preview `find_and_replace` without changing anything
+///
+/// Returns each scene or beat that would change, with its match count and an
+/// excerpt around the first match so the user can review before replacing.
+#[tauri::command]
+pub async fn preview_find_and_replace(
+    project_id: String,
+    find: String,
+    case_sensitive: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ReplacePreview>, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    if find.is_empty() {
+        return Err("Search text cannot be empty".to_string());
+    }
+
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::preview_replace_in_prose(&conn, &project_uuid, &find, case_sensitive.unwrap_or(false))
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::schema::initialize_schema;
+    use rusqlite::Connection;
+
+    fn setup_project(reference_types: &[&str]) -> (Connection, Project) {
+        let conn = Connection::open_in_memory().unwrap();
+        initialize_schema(&conn).unwrap();
+        let mut project = Project::new("Refs".to_string(), SourceType::Markdown, None);
+        project.reference_types = reference_types.iter().map(|t| t.to_string()).collect();
+        db::insert_project(&conn, &project).unwrap();
+        (conn, project)
+    }
+
+    fn upsert(name: &str) -> ReferenceUpsert {
+        ReferenceUpsert {
+            name: name.to_string(),
+            description: Some("Old family sword".to_string()),
+            attributes: Some(HashMap::from([("origin".to_string(), "North".to_string())])),
+        }
+    }
+
+    #[test]
+    fn test_insert_reference_item_round_trips() {
+        let (conn, project) = setup_project(&["characters", "items"]);
+
+        let id =
+            insert_reference(&conn, &project.id, "items".to_string(), upsert("Sword")).unwrap();
+
+        let items = db::get_reference_items(&conn, &project.id, "items").unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, id);
+        assert_eq!(items[0].name, "Sword");
+        assert_eq!(items[0].description.as_deref(), Some("Old family sword"));
+        assert_eq!(
+            items[0].attributes.get("origin").map(String::as_str),
+            Some("North")
+        );
+        assert_eq!(
+            db::get_all_reference_items(&conn, &project.id)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_insert_reference_rejects_disabled_type() {
+        let (conn, project) = setup_project(&["characters", "locations"]);
+
+        let err =
+            insert_reference(&conn, &project.id, "items".to_string(), upsert("Sword")).unwrap_err();
+
+        assert!(err.contains("not enabled"), "{}", err);
+        assert!(db::get_all_reference_items(&conn, &project.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_character_create_update_delete() {
+        let (conn, project) = setup_project(&["characters", "locations"]);
+
+        let id =
+            insert_reference(&conn, &project.id, "characters".to_string(), upsert("Mara")).unwrap();
+        let owner = modify_reference(&conn, &id, "characters", upsert("Mara Vey")).unwrap();
+        assert_eq!(owner, project.id);
+        let character = db::get_character_by_id(&conn, &id).unwrap().unwrap();
+        assert_eq!(character.name, "Mara Vey");
+
+        assert_eq!(
+            remove_reference(&conn, &id, "characters").unwrap(),
+            project.id
+        );
+        assert!(db::get_character_by_id(&conn, &id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_location_create_update_delete() {
+        let (conn, project) = setup_project(&["characters", "locations"]);
+
+        let id = insert_reference(
+            &conn,
+            &project.id,
+            "locations".to_string(),
+            upsert("Harbor"),
+        )
+        .unwrap();
+        modify_reference(&conn, &id, "locations", upsert("Old Harbor")).unwrap();
+        let location = db::get_location_by_id(&conn, &id).unwrap().unwrap();
+        assert_eq!(location.name, "Old Harbor");
+
+        remove_reference(&conn, &id, "locations").unwrap();
+        assert!(db::get_location_by_id(&conn, &id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_unknown_reference_is_rejected() {
+        let (conn, project) = setup_project(&["characters", "locations"]);
+        let id =
+            insert_reference(&conn, &project.id, "characters".to_string(), upsert("Mara")).unwrap();
+
+        // A character id is not a location
+        let err = modify_reference(&conn, &id, "locations", upsert("Harbor")).unwrap_err();
+        assert_eq!(err, "Reference not found");
+        let err = remove_reference(&conn, &Uuid::new_v4(), "characters").unwrap_err();
+        assert_eq!(err, "Reference not found");
+        assert!(db::get_character_by_id(&conn, &id).unwrap().is_some());
+    }
+
+    fn create_scene(conn: &Connection, project: &Project) -> Scene {
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Opening".to_string(), None, 0);
+        db::insert_scene(conn, &scene).unwrap();
+        scene
+    }
+
+    fn state_for(id: &Uuid, position: i32) -> SceneReferenceStateUpdate {
+        SceneReferenceStateUpdate {
+            reference_id: id.to_string(),
+            position,
+            expanded: false,
+        }
+    }
+
+    #[test]
+    fn test_replace_scene_characters() {
+        let (conn, project) = setup_project(&["characters", "locations"]);
+        let scene = create_scene(&conn, &project);
+        let mut ids = Vec::new();
+        for name in ["Mara", "Tobin", "Ash"] {
+            let id = insert_reference(&conn, &project.id, "characters".to_string(), upsert(name));
+            ids.push(id.unwrap());
+        }
+
+        let states = [state_for(&ids[0], 0), state_for(&ids[1], 1)];
+        replace_scene_references(&conn, &scene.id, "characters", &states).unwrap();
+        let states = [state_for(&ids[1], 0), state_for(&ids[2], 1)];
+        let owner = replace_scene_references(&conn, &scene.id, "characters", &states).unwrap();
+        assert_eq!(owner, project.id);
+
+        let names: Vec<String> = scene_references(&conn, &scene.id, "characters")
+            .unwrap()
+            .into_iter()
+            .map(|r| r.name)
+            .collect();
+        assert_eq!(names, vec!["Ash", "Tobin"]);
+    }
+
+    #[test]
+    fn test_replace_scene_references_rejects_other_project() {
+        let (conn, project) = setup_project(&["characters", "locations"]);
+        let scene = create_scene(&conn, &project);
+        let other = Project::new("Other".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &other).unwrap();
+        let foreign =
+            insert_reference(&conn, &other.id, "locations".to_string(), upsert("Harbor")).unwrap();
+
+        let states = [state_for(&foreign, 0)];
+        let err = replace_scene_references(&conn, &scene.id, "locations", &states).unwrap_err();
+
+        assert!(err.contains("different project"), "{}", err);
+    }
+}
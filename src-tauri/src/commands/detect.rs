@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use tauri::State;
 use uuid::Uuid;
 
-use crate::models::ReferenceSuggestion;
+use crate::models::{AutoLinkCounts, ReferenceSuggestion};
 use crate::{db, detect};
 
 use super::AppState;
@@ -38,6 +38,46 @@ pub async fn detect_all_references(
         .collect())
 }
 
+#[tauri::command]
+pub async fn get_unmentioned_linked_characters(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<HashMap<String, Vec<String>>, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let result =
+        detect::find_unmentioned_linked_characters(&conn, &uuid).map_err(|e| e.to_string())?;
+
+    Ok(result
+        .into_iter()
+        .map(|(scene_id, ids)| {
+            (
+                scene_id.to_string(),
+                ids.into_iter().map(|id| id.to_string()).collect(),
+            )
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn auto_link_references(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<AutoLinkCounts, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let counts = detect::auto_link_references(&tx, &uuid).map_err(|e| e.to_string())?;
+    if counts != AutoLinkCounts::default() {
+        db::update_project_modified(&tx, &uuid).map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(counts)
+}
+
 #[tauri::command]
 pub async fn dismiss_suggestion(
     scene_id: String,
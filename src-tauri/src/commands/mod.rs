@@ -11,10 +11,15 @@
 //! - [`lock`]: Lock/unlock commands
 //! - [`export`]: Export commands for Markdown, DOCX
 //! - [`snapshot`]: Snapshot/versioning commands
+//! - [`backup`]: Whole-database backup
+//! - [`project_archive`]: Portable single-file project export/import
+//! - [`search`]: Full-text search across scenes and beats
 //! - [`settings`]: App-wide settings
+//! - [`stats`]: Daily writing session statistics
 //! - [`feedback`]: Feedback payload model, builder, and validation
 
 mod archive;
+mod backup;
 mod blank_project;
 mod crud;
 mod detect;
@@ -23,17 +28,21 @@ pub mod feedback;
 mod fields;
 mod import;
 mod lock;
+mod project_archive;
 mod sample_project;
 mod screenplay_project;
+mod search;
 mod settings;
 mod snapshot;
 mod state;
+mod stats;
 mod sync;
 mod tags;
 mod templates;
 
 // Re-export everything for backwards compatibility with lib.rs
 pub use archive::*;
+pub use backup::*;
 pub use blank_project::*;
 pub use crud::*;
 pub use detect::*;
@@ -42,11 +51,14 @@ pub use feedback::*;
 pub use fields::*;
 pub use import::*;
 pub use lock::*;
+pub use project_archive::*;
 pub use sample_project::*;
 pub use screenplay_project::*;
+pub use search::*;
 pub use settings::*;
 pub use snapshot::*;
 pub use state::*;
+pub use stats::*;
 pub use sync::*;
 pub use tags::*;
 pub use templates::*;
@@ -0,0 +1,437 @@
+//! Project Archive Commands
+//!
+//! Exports a whole project to a single portable file and imports it again,
+//! e.g. to move a project to another machine. The archive is a zip holding
+//! one JSON document built from the same data a snapshot captures.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+use tauri::State;
+use uuid::Uuid;
+use zip::write::FileOptions;
+use zip::CompressionMethod;
+
+use crate::db;
+use crate::models::{BeatRevision, FieldDefinition, FieldValue, Project, SnapshotData};
+
+use super::snapshot::{collect_project_data, insert_with_fresh_ids};
+use super::AppState;
+
+/// Archive format version, bumped whenever the layout changes incompatibly
+pub const PROJECT_ARCHIVE_SCHEMA_VERSION: i32 = 1;
+
+/// Name of the JSON document inside the zip
+const ARCHIVE_ENTRY: &str = "project.json";
+
+/// An alternate name for a character
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedCharacterAlias {
+    pub character_id: Uuid,
+    pub alias: String,
+}
+
+/// An alternate name for a location
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedLocationAlias {
+    pub location_id: Uuid,
+    pub alias: String,
+}
+
+/// An alternate name for a reference item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedReferenceItemAlias {
+    pub reference_item_id: Uuid,
+    pub alias: String,
+}
+
+/// Everything needed to recreate a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectArchive {
+    pub schema_version: i32,
+    pub exported_at: String,
+    pub data: SnapshotData,
+    #[serde(default)]
+    pub field_definitions: Vec<FieldDefinition>,
+    #[serde(default)]
+    pub field_values: Vec<FieldValue>,
+    #[serde(default)]
+    pub character_aliases: Vec<ArchivedCharacterAlias>,
+    #[serde(default)]
+    pub location_aliases: Vec<ArchivedLocationAlias>,
+    #[serde(default)]
+    pub reference_item_aliases: Vec<ArchivedReferenceItemAlias>,
+    /// Prose history of every beat, oldest first
+    #[serde(default)]
+    pub beat_revisions: Vec<BeatRevision>,
+}
+
+/// Collect a project and its custom field data into an archive
+fn build_project_archive(
+    conn: &rusqlite::Connection,
+    project_id: &Uuid,
+) -> Result<ProjectArchive, String> {
+    let data = collect_project_data(conn, project_id)?;
+    let field_definitions =
+        db::get_all_field_definitions(conn, project_id).map_err(|e| e.to_string())?;
+
+    let entity_ids: Vec<Uuid> = data
+        .characters
+        .iter()
+        .map(|c| c.id)
+        .chain(data.locations.iter().map(|l| l.id))
+        .chain(data.reference_items.iter().map(|r| r.id))
+        .chain(data.scenes.iter().map(|s| s.id))
+        .chain(data.chapters.iter().map(|c| c.id))
+        .collect();
+    let field_values = db::get_field_values_bulk(conn, &entity_ids)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|v| {
+            field_definitions
+                .iter()
+                .any(|d| d.id == v.field_definition_id)
+        })
+        .collect();
+
+    let character_aliases = db::get_project_character_aliases(conn, project_id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|(character_id, alias)| ArchivedCharacterAlias {
+            character_id,
+            alias,
+        })
+        .collect();
+    let location_aliases = db::get_project_location_aliases(conn, project_id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|(location_id, alias)| ArchivedLocationAlias { location_id, alias })
+        .collect();
+    let reference_item_aliases = db::get_project_reference_item_aliases(conn, project_id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|(reference_item_id, alias)| ArchivedReferenceItemAlias {
+            reference_item_id,
+            alias,
+        })
+        .collect();
+    let beat_revisions =
+        db::get_project_beat_revisions(conn, project_id).map_err(|e| e.to_string())?;
+
+    Ok(ProjectArchive {
+        schema_version: PROJECT_ARCHIVE_SCHEMA_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        data,
+        field_definitions,
+        field_values,
+        character_aliases,
+        location_aliases,
+        reference_item_aliases,
+        beat_revisions,
+    })
+}
+
+/// Write an archive to `output_path` as a zip with a single JSON entry
+fn write_project_archive(archive: &ProjectArchive, output_path: &Path) -> Result<(), String> {
+    if let Some(parent) = output_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create output directory: {}", e))?;
+    }
+
+    let json = serde_json::to_vec(archive).map_err(|e| e.to_string())?;
+    let file = fs::File::create(output_path)
+        .map_err(|e| format!("Failed to create archive file: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let deflated = FileOptions::<()>::default()
+        .compression_method(CompressionMethod::Deflated)
+        .unix_permissions(0o644);
+
+    zip.start_file(ARCHIVE_ENTRY, deflated)
+        .map_err(|e| format!("Failed to write archive: {}", e))?;
+    zip.write_all(&json)
+        .map_err(|e| format!("Failed to write archive: {}", e))?;
+    zip.finish()
+        .map_err(|e| format!("Failed to write archive: {}", e))?;
+
+    Ok(())
+}
+
+/// Read an archive, rejecting ones written by a newer version of the app
+fn read_project_archive(path: &Path) -> Result<ProjectArchive, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut zip =
+        zip::ZipArchive::new(file).map_err(|e| format!("Not a project archive: {}", e))?;
+    let mut entry = zip
+        .by_name(ARCHIVE_ENTRY)
+        .map_err(|_| format!("Not a project archive: missing {}", ARCHIVE_ENTRY))?;
+
+    let mut json = String::new();
+    entry
+        .read_to_string(&mut json)
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+
+    let version: serde_json::Value = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+    let schema_version = version
+        .get("schema_version")
+        .and_then(|v| v.as_i64())
+        .ok_or_else(|| "Not a project archive: missing schema_version".to_string())?;
+    if schema_version > PROJECT_ARCHIVE_SCHEMA_VERSION as i64 {
+        return Err(format!(
+            "This archive was created by a newer version of Kindling (format {})",
+            schema_version
+        ));
+    }
+
+    serde_json::from_value(version).map_err(|e| e.to_string())
+}
+
+/// Insert an archive as a new project with fresh UUIDs for every entity
+fn insert_project_archive(
+    conn: &rusqlite::Connection,
+    archive: ProjectArchive,
+) -> Result<Project, String> {
+    let name = archive.data.project.name.clone();
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let (project, mut id_map) = insert_with_fresh_ids(&tx, archive.data, Some(name))?;
+
+    for def in &archive.field_definitions {
+        let new_def = FieldDefinition {
+            id: Uuid::new_v4(),
+            project_id: project.id,
+            ..def.clone()
+        };
+        db::create_field_definition(&tx, &new_def).map_err(|e| e.to_string())?;
+        id_map.insert(def.id, new_def.id);
+    }
+
+    for value in &archive.field_values {
+        let (Some(def_id), Some(entity_id)) = (
+            id_map.get(&value.field_definition_id),
+            id_map.get(&value.entity_id),
+        ) else {
+            continue;
+        };
+        db::set_field_value(&tx, def_id, entity_id, value.value.as_deref())
+            .map_err(|e| e.to_string())?;
+    }
+
+    for alias in &archive.character_aliases {
+        if let Some(character_id) = id_map.get(&alias.character_id) {
+            db::add_character_alias(&tx, character_id, &alias.alias).map_err(|e| e.to_string())?;
+        }
+    }
+    for alias in &archive.location_aliases {
+        if let Some(location_id) = id_map.get(&alias.location_id) {
+            db::add_location_alias(&tx, location_id, &alias.alias).map_err(|e| e.to_string())?;
+        }
+    }
+    for alias in &archive.reference_item_aliases {
+        if let Some(item_id) = id_map.get(&alias.reference_item_id) {
+            db::add_reference_item_alias(&tx, item_id, &alias.alias).map_err(|e| e.to_string())?;
+        }
+    }
+
+    for revision in &archive.beat_revisions {
+        if let Some(beat_id) = id_map.get(&revision.beat_id) {
+            let new_revision = BeatRevision {
+                id: Uuid::new_v4(),
+                beat_id: *beat_id,
+                ..revision.clone()
+            };
+            db::insert_beat_revision(&tx, &new_revision).map_err(|e| e.to_string())?;
+        }
+    }
+
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(project)
+}
+
+/// Export a project to a portable archive at `output_path`
+#[tauri::command]
+pub async fn export_project_archive(
+    project_id: String,
+    output_path: String,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let archive = build_project_archive(&conn, &uuid)?;
+    write_project_archive(&archive, Path::new(&output_path))?;
+
+    Ok(output_path)
+}
+
+/// Import a portable archive as a new project
+#[tauri::command]
+pub async fn import_project_archive(
+    path: String,
+    state: State<'_, AppState>,
+) -> Result<Project, String> {
+    let archive = read_project_archive(Path::new(&path))?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    insert_project_archive(&conn, archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Beat, Chapter, Character, Location, ReferenceItem, Scene, SourceType};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_project_archive_roundtrip() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Portable".to_string(), SourceType::Blank, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Arrival".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut beat = Beat::new(scene.id, "She lands".to_string(), 0);
+        beat.prose = Some("<p>The ship touched down.</p>".to_string());
+        db::insert_beat(&conn, &beat).unwrap();
+        let mut attributes = HashMap::new();
+        attributes.insert("role".to_string(), "pilot".to_string());
+        let character = Character {
+            id: Uuid::new_v4(),
+            project_id: project.id,
+            name: "Ada".to_string(),
+            description: None,
+            attributes,
+            source_id: None,
+        };
+        db::insert_character(&conn, &character).unwrap();
+        db::add_scene_character_ref(&conn, &scene.id, &character.id).unwrap();
+        let def = FieldDefinition::new(
+            project.id,
+            "character".to_string(),
+            "Age".to_string(),
+            "text".to_string(),
+            0,
+        );
+        db::create_field_definition(&conn, &def).unwrap();
+        db::set_field_value(&conn, &def.id, &character.id, Some("34")).unwrap();
+        db::add_character_alias(&conn, &character.id, "Captain").unwrap();
+        let location = Location::new(project.id, "Harbor".to_string(), None, None);
+        db::insert_location(&conn, &location).unwrap();
+        db::add_location_alias(&conn, &location.id, "The Docks").unwrap();
+        let item = ReferenceItem::new(
+            project.id,
+            "items".to_string(),
+            "Compass".to_string(),
+            None,
+            None,
+        );
+        db::insert_reference_item(&conn, &item).unwrap();
+        db::add_reference_item_alias(&conn, &item.id, "Needle").unwrap();
+        db::add_scene_tag(&conn, &scene.id, "landing").unwrap();
+        db::update_beat_prose_with_revision(&conn, &beat.id, "<p>The ship landed.</p>").unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("portable.kindling");
+        let original = build_project_archive(&conn, &project.id).unwrap();
+        write_project_archive(&original, &path).unwrap();
+
+        db::delete_project(&conn, &project.id).unwrap();
+        assert!(db::get_project(&conn, &project.id).unwrap().is_none());
+
+        let archive = read_project_archive(&path).unwrap();
+        assert_eq!(archive.schema_version, PROJECT_ARCHIVE_SCHEMA_VERSION);
+        let imported = insert_project_archive(&conn, archive).unwrap();
+        assert_ne!(imported.id, project.id);
+        assert_eq!(imported.name, "Portable");
+
+        let restored = build_project_archive(&conn, &imported.id).unwrap();
+        let (before, after) = (&original.data, &restored.data);
+        assert_eq!(after.chapters.len(), before.chapters.len());
+        assert_eq!(after.chapters[0].title, before.chapters[0].title);
+        assert_ne!(after.chapters[0].id, before.chapters[0].id);
+        assert_eq!(after.scenes[0].title, before.scenes[0].title);
+        assert_eq!(after.scenes[0].chapter_id, after.chapters[0].id);
+        assert_eq!(after.beats[0].prose, before.beats[0].prose);
+        assert_eq!(after.beats[0].scene_id, after.scenes[0].id);
+        assert_eq!(after.characters[0].name, "Ada");
+        assert_eq!(
+            after.characters[0].attributes,
+            before.characters[0].attributes
+        );
+        assert_eq!(after.scene_character_refs.len(), 1);
+        assert_eq!(after.scene_character_refs[0].scene_id, after.scenes[0].id);
+        assert_eq!(
+            after.scene_character_refs[0].character_id,
+            after.characters[0].id
+        );
+
+        assert_eq!(restored.field_definitions.len(), 1);
+        assert_eq!(restored.field_definitions[0].name, "Age");
+        assert_eq!(restored.field_values.len(), 1);
+        assert_eq!(restored.field_values[0].entity_id, after.characters[0].id);
+        assert_eq!(restored.field_values[0].value.as_deref(), Some("34"));
+
+        assert_eq!(after.scene_tags.len(), 1);
+        assert_eq!(after.scene_tags[0].scene_id, after.scenes[0].id);
+        assert_eq!(after.scene_tags[0].tag, "landing");
+        assert_eq!(
+            db::get_character_aliases(&conn, &after.characters[0].id).unwrap(),
+            vec!["Captain"]
+        );
+        assert_eq!(
+            db::get_location_aliases(&conn, &after.locations[0].id).unwrap(),
+            vec!["The Docks"]
+        );
+        assert_eq!(
+            db::get_reference_item_aliases(&conn, &after.reference_items[0].id).unwrap(),
+            vec!["Needle"]
+        );
+        let revisions = db::get_beat_revisions(&conn, &after.beats[0].id).unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].prose, "<p>The ship touched down.</p>");
+    }
+
+    #[test]
+    fn test_project_archive_rejects_newer_schema() {
+        let project = Project::new("Future".to_string(), SourceType::Blank, None);
+        let mut archive = ProjectArchive {
+            schema_version: PROJECT_ARCHIVE_SCHEMA_VERSION + 1,
+            exported_at: String::new(),
+            data: SnapshotData::new(
+                project,
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+            ),
+            field_definitions: vec![],
+            field_values: vec![],
+            character_aliases: vec![],
+            location_aliases: vec![],
+            reference_item_aliases: vec![],
+            beat_revisions: vec![],
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.kindling");
+        write_project_archive(&archive, &path).unwrap();
+        assert!(read_project_archive(&path).is_err());
+
+        archive.schema_version = PROJECT_ARCHIVE_SCHEMA_VERSION;
+        write_project_archive(&archive, &path).unwrap();
+        assert!(read_project_archive(&path).is_ok());
+    }
+}
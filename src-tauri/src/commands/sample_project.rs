@@ -38,6 +38,11 @@ pub async fn create_sample_project(state: State<'_, AppState>) -> Result<Project
         reference_types: Project::default_reference_types(),
         project_type: Project::default_project_type(),
         target_page_count: None,
+        scene_statuses: Vec::new(),
+        publisher: None,
+        isbn: None,
+        series_name: None,
+        series_index: None,
     };
 
     let chapter_id = Uuid::new_v4();
@@ -52,6 +57,8 @@ pub async fn create_sample_project(state: State<'_, AppState>) -> Result<Project
         is_part: false,
         synopsis: None,
         planning_status: PlanningStatus::Fixed,
+        color: None,
+        word_target: None,
     };
 
     let scene1_id = Uuid::new_v4();
@@ -76,6 +83,13 @@ pub async fn create_sample_project(state: State<'_, AppState>) -> Result<Project
             scene_status: SceneStatus::Draft,
             planning_status: PlanningStatus::Fixed,
             editor_mode: EditorMode::Beat,
+            compile_position: None,
+            include_in_compile: true,
+            color: None,
+            pov_character_id: None,
+            notes: None,
+            word_target: None,
+            custom_status: None,
         },
         Scene {
             id: scene2_id,
@@ -91,6 +105,13 @@ pub async fn create_sample_project(state: State<'_, AppState>) -> Result<Project
             scene_status: SceneStatus::Draft,
             planning_status: PlanningStatus::Fixed,
             editor_mode: EditorMode::Beat,
+            compile_position: None,
+            include_in_compile: true,
+            color: None,
+            pov_character_id: None,
+            notes: None,
+            word_target: None,
+            custom_status: None,
         },
         Scene {
             id: scene3_id,
@@ -108,6 +129,13 @@ pub async fn create_sample_project(state: State<'_, AppState>) -> Result<Project
             scene_status: SceneStatus::Draft,
             planning_status: PlanningStatus::Fixed,
             editor_mode: EditorMode::Beat,
+            compile_position: None,
+            include_in_compile: true,
+            color: None,
+            pov_character_id: None,
+            notes: None,
+            word_target: None,
+            custom_status: None,
         },
     ];
 
@@ -51,6 +51,11 @@ pub async fn create_screenplay_project(
         reference_types: Project::default_reference_types(),
         project_type: "screenplay".to_string(),
         target_page_count: target_page,
+        scene_statuses: Vec::new(),
+        publisher: None,
+        isbn: None,
+        series_name: None,
+        series_index: None,
     };
 
     let acts = [
@@ -77,6 +82,8 @@ pub async fn create_screenplay_project(
             is_part: true,
             synopsis: None,
             planning_status: PlanningStatus::Undefined,
+            color: None,
+            word_target: None,
         };
         db::insert_chapter(&tx, &act_chapter).map_err(|e| e.to_string())?;
 
@@ -93,6 +100,8 @@ pub async fn create_screenplay_project(
             is_part: false,
             synopsis: None,
             planning_status: PlanningStatus::Undefined,
+            color: None,
+            word_target: None,
         };
         db::insert_chapter(&tx, &seq_chapter).map_err(|e| e.to_string())?;
 
@@ -111,6 +120,13 @@ pub async fn create_screenplay_project(
             scene_status: SceneStatus::Draft,
             planning_status: PlanningStatus::Undefined,
             editor_mode: EditorMode::Beat,
+            compile_position: None,
+            include_in_compile: true,
+            color: None,
+            pov_character_id: None,
+            notes: None,
+            word_target: None,
+            custom_status: None,
         };
         db::insert_scene(&tx, &scene).map_err(|e| e.to_string())?;
     }
@@ -160,7 +176,10 @@ pub async fn get_page_count_estimate(
 
     for chapter in chapters.iter().filter(|c| !c.archived) {
         let scenes = db::get_scenes(&conn, &chapter.id).map_err(|e| e.to_string())?;
-        for scene in scenes.iter().filter(|s| !s.archived) {
+        for scene in scenes
+            .iter()
+            .filter(|s| !s.archived && s.include_in_compile)
+        {
             if let Some(ref prose) = scene.prose {
                 if !prose.is_empty() {
                     total_words += count_words_in_html(prose);
@@ -301,6 +320,11 @@ mod tests {
             reference_types: Project::default_reference_types(),
             project_type: "screenplay".to_string(),
             target_page_count: Some(120),
+            scene_statuses: Vec::new(),
+            publisher: None,
+            isbn: None,
+            series_name: None,
+            series_index: None,
         };
 
         db::insert_project(&conn, &project).unwrap();
@@ -325,6 +349,8 @@ mod tests {
                     is_part: true,
                     synopsis: None,
                     planning_status: PlanningStatus::Undefined,
+                    color: None,
+                    word_target: None,
                 },
             )
             .unwrap();
@@ -342,6 +368,8 @@ mod tests {
                     is_part: false,
                     synopsis: None,
                     planning_status: PlanningStatus::Undefined,
+                    color: None,
+                    word_target: None,
                 },
             )
             .unwrap();
@@ -361,6 +389,13 @@ mod tests {
                     scene_status: SceneStatus::Draft,
                     planning_status: PlanningStatus::Undefined,
                     editor_mode: EditorMode::Beat,
+                    compile_position: None,
+                    include_in_compile: true,
+                    color: None,
+                    pov_character_id: None,
+                    notes: None,
+                    word_target: None,
+                    custom_status: None,
                 },
             )
             .unwrap();
@@ -402,6 +437,11 @@ mod tests {
             reference_types: Project::default_reference_types(),
             project_type: "screenplay".to_string(),
             target_page_count: Some(120),
+            scene_statuses: Vec::new(),
+            publisher: None,
+            isbn: None,
+            series_name: None,
+            series_index: None,
         };
         db::insert_project(&conn, &project).unwrap();
 
@@ -419,6 +459,8 @@ mod tests {
                 is_part: false,
                 synopsis: None,
                 planning_status: PlanningStatus::Undefined,
+                color: None,
+                word_target: None,
             },
         )
         .unwrap();
@@ -440,6 +482,13 @@ mod tests {
                 scene_status: SceneStatus::Draft,
                 planning_status: PlanningStatus::Undefined,
                 editor_mode: EditorMode::Beat,
+                compile_position: None,
+                include_in_compile: true,
+                color: None,
+                pov_character_id: None,
+                notes: None,
+                word_target: None,
+                custom_status: None,
             },
         )
         .unwrap();
@@ -506,6 +555,11 @@ mod tests {
             reference_types: Project::default_reference_types(),
             project_type: "screenplay".to_string(),
             target_page_count: None,
+            scene_statuses: Vec::new(),
+            publisher: None,
+            isbn: None,
+            series_name: None,
+            series_index: None,
         };
         db::insert_project(&conn, &project).unwrap();
 
@@ -523,6 +577,8 @@ mod tests {
                 is_part: false,
                 synopsis: None,
                 planning_status: PlanningStatus::Undefined,
+                color: None,
+                word_target: None,
             },
         )
         .unwrap();
@@ -544,6 +600,13 @@ mod tests {
                 scene_status: SceneStatus::Draft,
                 planning_status: PlanningStatus::Undefined,
                 editor_mode: EditorMode::Beat,
+                compile_position: None,
+                include_in_compile: true,
+                color: None,
+                pov_character_id: None,
+                notes: None,
+                word_target: None,
+                custom_status: None,
             },
         )
         .unwrap();
@@ -0,0 +1,26 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::db;
+use crate::models::SearchHit;
+
+use super::AppState;
+
+/// Full-text search across scene titles, synopses and prose in a project.
+///
+/// Pass `chapter_id` to restrict the search to a single chapter.
+#[tauri::command]
+pub async fn search_project(
+    project_id: String,
+    query: String,
+    chapter_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<SearchHit>, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let chapter_uuid = chapter_id
+        .map(|id| Uuid::parse_str(&id).map_err(|e| e.to_string()))
+        .transpose()?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::search_text(&conn, &project_uuid, &query, chapter_uuid.as_ref()).map_err(|e| e.to_string())
+}
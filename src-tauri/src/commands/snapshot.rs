@@ -6,20 +6,23 @@ use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager, State};
 use uuid::Uuid;
 
 use crate::db;
 use crate::models::{
-    Beat, Chapter, Character, DiscoveryNote, Location, Project, ReferenceItem, RestoreMode, Scene,
-    SceneReferenceState, SnapshotData, SnapshotMetadata, SnapshotTrigger,
+    Beat, BinderItemFlags, Chapter, Character, DiscoveryNote, Location, Project, ReferenceItem,
+    RestoreMode, Scene, SceneReferenceState, SnapshotCompression, SnapshotData, SnapshotMetadata,
+    SnapshotTrigger,
 };
 
-use super::AppState;
+use super::export::{cached_project_word_count, strip_html};
+use super::{load_app_settings, AppState};
 
 /// Get the snapshots directory for a project
 fn get_snapshots_dir(app_handle: &AppHandle, project_id: &Uuid) -> Result<PathBuf, String> {
@@ -34,15 +37,23 @@ fn get_snapshots_dir(app_handle: &AppHandle, project_id: &Uuid) -> Result<PathBu
     Ok(snapshots_dir)
 }
 
-/// Generate a snapshot filename based on trigger type
-fn generate_snapshot_filename(trigger: &SnapshotTrigger) -> String {
+/// Generate a snapshot filename based on trigger type and compression
+fn generate_snapshot_filename(
+    trigger: &SnapshotTrigger,
+    compression: SnapshotCompression,
+) -> String {
     let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H%M%S");
     let trigger_str = trigger.as_str();
-    format!("{}_{}.json.gz", timestamp, trigger_str)
+    format!(
+        "{}_{}.{}",
+        timestamp,
+        trigger_str,
+        compression.file_extension()
+    )
 }
 
 /// Collect all project data for snapshotting
-fn collect_project_data(
+pub(super) fn collect_project_data(
     conn: &rusqlite::Connection,
     project_id: &Uuid,
 ) -> Result<SnapshotData, String> {
@@ -80,6 +91,11 @@ fn collect_project_data(
     let discovery_notes =
         db::get_all_discovery_notes_for_project(conn, project_id).map_err(|e| e.to_string())?;
 
+    let scene_tags =
+        db::get_all_scene_tags_for_project(conn, project_id).map_err(|e| e.to_string())?;
+
+    let binder_flags = db::get_binder_item_flags(conn, project_id).map_err(|e| e.to_string())?;
+
     Ok(SnapshotData::new(
         project,
         chapters,
@@ -93,44 +109,102 @@ fn collect_project_data(
         scene_reference_item_refs,
         scene_reference_states,
         discovery_notes,
+        scene_tags,
+        binder_flags,
     ))
 }
 
+/// SHA-256 of a snapshot's uncompressed JSON payload, as lowercase hex
+fn payload_checksum(json: &str) -> String {
+    format!("{:x}", Sha256::digest(json.as_bytes()))
+}
+
 /// Serialize and compress snapshot data to a file
-fn serialize_and_compress(data: &SnapshotData, file_path: &PathBuf) -> Result<(i64, i64), String> {
+///
+/// Returns the file size, the uncompressed size and the payload checksum.
+fn serialize_and_compress(
+    data: &SnapshotData,
+    file_path: &PathBuf,
+    compression: SnapshotCompression,
+) -> Result<(i64, i64, String), String> {
     let json = serde_json::to_string(data).map_err(|e| e.to_string())?;
     let uncompressed_size = json.len() as i64;
-
-    let file = File::create(file_path).map_err(|e| e.to_string())?;
-    let mut encoder = GzEncoder::new(file, Compression::default());
-    encoder
-        .write_all(json.as_bytes())
-        .map_err(|e| e.to_string())?;
-    encoder.finish().map_err(|e| e.to_string())?;
+    let checksum = payload_checksum(&json);
+
+    let mut file = File::create(file_path).map_err(|e| e.to_string())?;
+    match compression {
+        SnapshotCompression::None => {
+            file.write_all(json.as_bytes()).map_err(|e| e.to_string())?;
+        }
+        SnapshotCompression::Gzip => {
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            encoder
+                .write_all(json.as_bytes())
+                .map_err(|e| e.to_string())?;
+            encoder.finish().map_err(|e| e.to_string())?;
+        }
+        SnapshotCompression::Zstd => {
+            let mut encoder = zstd::Encoder::new(file, zstd::DEFAULT_COMPRESSION_LEVEL)
+                .map_err(|e| e.to_string())?;
+            encoder
+                .write_all(json.as_bytes())
+                .map_err(|e| e.to_string())?;
+            encoder.finish().map_err(|e| e.to_string())?;
+        }
+    }
 
     let file_size = fs::metadata(file_path).map_err(|e| e.to_string())?.len() as i64;
 
-    Ok((file_size, uncompressed_size))
+    Ok((file_size, uncompressed_size, checksum))
 }
 
 /// Decompress and deserialize snapshot data from a file
-fn decompress_and_deserialize(file_path: &PathBuf) -> Result<SnapshotData, String> {
+///
+/// When `expected_checksum` is given, a payload that does not match it is rejected
+/// so a corrupted or truncated archive is never restored.
+fn decompress_and_deserialize(
+    file_path: &PathBuf,
+    compression: SnapshotCompression,
+    expected_checksum: Option<&str>,
+) -> Result<SnapshotData, String> {
     let file = File::open(file_path).map_err(|e| e.to_string())?;
-    let mut decoder = GzDecoder::new(file);
+    let mut reader: Box<dyn Read> = match compression {
+        SnapshotCompression::None => Box::new(file),
+        SnapshotCompression::Gzip => Box::new(GzDecoder::new(file)),
+        SnapshotCompression::Zstd => Box::new(zstd::Decoder::new(file).map_err(|e| e.to_string())?),
+    };
     let mut json = String::new();
-    decoder
+    reader
         .read_to_string(&mut json)
         .map_err(|e| e.to_string())?;
 
+    if let Some(expected) = expected_checksum {
+        if payload_checksum(&json) != expected {
+            return Err("Snapshot checksum mismatch: the snapshot file is corrupted".to_string());
+        }
+    }
+
     let data: SnapshotData = serde_json::from_str(&json).map_err(|e| e.to_string())?;
     Ok(data)
 }
 
+/// Load the data of a recorded snapshot, using the compression it was written with
+/// and verifying its checksum when one was recorded
+fn load_snapshot_data(metadata: &SnapshotMetadata) -> Result<SnapshotData, String> {
+    decompress_and_deserialize(
+        &PathBuf::from(&metadata.file_path),
+        metadata.compression,
+        metadata.checksum.as_deref(),
+    )
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateSnapshotOptions {
     pub name: String,
     pub description: Option<String>,
     pub trigger_type: SnapshotTrigger,
+    #[serde(default)]
+    pub compression: SnapshotCompression,
 }
 
 #[tauri::command]
@@ -143,20 +217,31 @@ pub async fn create_snapshot(
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
+    let snapshots_dir = get_snapshots_dir(&app_handle, &project_uuid)?;
+    write_snapshot(&conn, &project_uuid, &snapshots_dir, options)
+}
+
+/// Snapshot a project into `snapshots_dir` and record its metadata
+fn write_snapshot(
+    conn: &rusqlite::Connection,
+    project_uuid: &Uuid,
+    snapshots_dir: &Path,
+    options: CreateSnapshotOptions,
+) -> Result<SnapshotMetadata, String> {
     // Collect project data
-    let data = collect_project_data(&conn, &project_uuid)?;
+    let data = collect_project_data(conn, project_uuid)?;
 
     // Generate file path
-    let snapshots_dir = get_snapshots_dir(&app_handle, &project_uuid)?;
-    let filename = generate_snapshot_filename(&options.trigger_type);
+    let filename = generate_snapshot_filename(&options.trigger_type, options.compression);
     let file_path = snapshots_dir.join(&filename);
 
     // Serialize and compress
-    let (file_size, uncompressed_size) = serialize_and_compress(&data, &file_path)?;
+    let (file_size, uncompressed_size, checksum) =
+        serialize_and_compress(&data, &file_path, options.compression)?;
 
     // Create metadata
     let metadata = SnapshotMetadata::new(
-        project_uuid,
+        *project_uuid,
         options.name,
         options.description,
         options.trigger_type,
@@ -167,14 +252,75 @@ pub async fn create_snapshot(
         data.scenes.len() as i32,
         data.beats.len() as i32,
         Some(data.word_count()),
-    );
+    )
+    .with_compression(options.compression)
+    .with_checksum(checksum);
 
     // Store metadata in database
-    db::insert_snapshot_metadata(&conn, &metadata).map_err(|e| e.to_string())?;
+    db::insert_snapshot_metadata(conn, &metadata).map_err(|e| e.to_string())?;
 
     Ok(metadata)
 }
 
+/// Whether a project has grown past the auto-snapshot threshold since its last snapshot
+fn should_auto_snapshot(
+    current_words: i64,
+    last_snapshot_words: Option<i64>,
+    threshold: Option<u32>,
+) -> bool {
+    match threshold {
+        Some(threshold) if threshold > 0 => {
+            current_words - last_snapshot_words.unwrap_or(0) > i64::from(threshold)
+        }
+        _ => false,
+    }
+}
+
+/// Take an `Auto` snapshot if the project has grown past `threshold` words
+fn auto_snapshot_if_due(
+    conn: &rusqlite::Connection,
+    project_uuid: &Uuid,
+    snapshots_dir: &Path,
+    threshold: Option<u32>,
+) -> Result<Option<SnapshotMetadata>, String> {
+    if threshold.unwrap_or(0) == 0 {
+        return Ok(None);
+    }
+
+    let current_words = cached_project_word_count(conn, project_uuid)? as i64;
+    let last_words = db::get_latest_snapshot(conn, project_uuid)
+        .map_err(|e| e.to_string())?
+        .map(|s| i64::from(s.word_count.unwrap_or(0)));
+
+    if !should_auto_snapshot(current_words, last_words, threshold) {
+        return Ok(None);
+    }
+
+    let options = CreateSnapshotOptions {
+        name: "Auto snapshot".to_string(),
+        description: Some(format!("Automatic snapshot at {} words", current_words)),
+        trigger_type: SnapshotTrigger::Auto,
+        compression: SnapshotCompression::default(),
+    };
+    write_snapshot(conn, project_uuid, snapshots_dir, options).map(Some)
+}
+
+/// Called after prose saves; takes an automatic snapshot when the configured
+/// word-count threshold has been crossed
+pub(crate) fn maybe_create_auto_snapshot(
+    conn: &rusqlite::Connection,
+    app_handle: &AppHandle,
+    project_uuid: &Uuid,
+) -> Result<Option<SnapshotMetadata>, String> {
+    let threshold = load_app_settings(app_handle)?.auto_snapshot_word_threshold;
+    if threshold.unwrap_or(0) == 0 {
+        return Ok(None);
+    }
+
+    let snapshots_dir = get_snapshots_dir(app_handle, project_uuid)?;
+    auto_snapshot_if_due(conn, project_uuid, &snapshots_dir, threshold)
+}
+
 #[tauri::command]
 pub async fn list_snapshots(
     project_id: String,
@@ -214,18 +360,175 @@ pub async fn delete_snapshot(
     Ok(())
 }
 
+/// One point in a project's growth over time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotHistoryPoint {
+    pub snapshot_id: Uuid,
+    pub created_at: String,
+    /// `None` only when the snapshot predates word counts and its archive is unreadable
+    pub word_count: Option<i32>,
+    pub chapter_count: i32,
+    pub scene_count: i32,
+}
+
+/// Snapshot sizes for a project in chronological order, for plotting manuscript growth
+#[tauri::command]
+pub async fn get_snapshot_history(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<SnapshotHistoryPoint>, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    build_snapshot_history(&conn, &project_uuid)
+}
+
+fn build_snapshot_history(
+    conn: &rusqlite::Connection,
+    project_uuid: &Uuid,
+) -> Result<Vec<SnapshotHistoryPoint>, String> {
+    let mut snapshots =
+        db::get_snapshots_for_project(conn, project_uuid).map_err(|e| e.to_string())?;
+    snapshots.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    Ok(snapshots
+        .into_iter()
+        .map(|metadata| {
+            // Older snapshots didn't record a word count; read it from the archive
+            let word_count = metadata.word_count.or_else(|| {
+                load_snapshot_data(&metadata)
+                    .ok()
+                    .map(|data| data.word_count())
+            });
+            SnapshotHistoryPoint {
+                snapshot_id: metadata.id,
+                created_at: metadata.created_at,
+                word_count,
+                chapter_count: metadata.chapter_count,
+                scene_count: metadata.scene_count,
+            }
+        })
+        .collect())
+}
+
+/// Which snapshots `prune_snapshots` keeps; manual snapshots are always kept
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotRetentionPolicy {
+    /// Keep this many of the most recent snapshots
+    #[serde(default)]
+    pub keep_last_n: Option<usize>,
+    /// Keep snapshots created within this many days
+    #[serde(default)]
+    pub keep_days: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PruneSnapshotsResult {
+    pub pruned: usize,
+    /// Sum of the pruned snapshots' compressed file sizes
+    pub bytes_reclaimed: i64,
+}
+
+/// Delete snapshots outside a retention policy, preserving manual snapshots
+#[tauri::command]
+pub async fn prune_snapshots(
+    project_id: String,
+    policy: SnapshotRetentionPolicy,
+    state: State<'_, AppState>,
+) -> Result<PruneSnapshotsResult, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    prune_project_snapshots(&conn, &project_uuid, &policy, chrono::Utc::now())
+}
+
+fn prune_project_snapshots(
+    conn: &rusqlite::Connection,
+    project_uuid: &Uuid,
+    policy: &SnapshotRetentionPolicy,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<PruneSnapshotsResult, String> {
+    let mut result = PruneSnapshotsResult {
+        pruned: 0,
+        bytes_reclaimed: 0,
+    };
+
+    // An empty policy keeps everything
+    if policy.keep_last_n.is_none() && policy.keep_days.is_none() {
+        return Ok(result);
+    }
+
+    let cutoff = policy
+        .keep_days
+        .map(|days| (now - chrono::Duration::days(days)).to_rfc3339());
+    let expired = db::get_snapshots_outside_retention(
+        conn,
+        project_uuid,
+        policy.keep_last_n.unwrap_or(0),
+        cutoff.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    for metadata in expired {
+        let file_path = PathBuf::from(&metadata.file_path);
+        if file_path.exists() {
+            fs::remove_file(&file_path).map_err(|e| e.to_string())?;
+        }
+        db::delete_snapshot_metadata(conn, &metadata.id).map_err(|e| e.to_string())?;
+
+        result.pruned += 1;
+        result.bytes_reclaimed += metadata.file_size;
+    }
+
+    Ok(result)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RestoreSnapshotOptions {
     pub mode: RestoreMode,
     pub new_project_name: Option<String>,
+    /// Apply the restore; when false only the preview is returned
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// What a restore will change, compared against the live project
+#[derive(Debug, Clone, Serialize)]
+pub struct RestorePreview {
+    /// True when the live project's data will be replaced
+    pub overwrites_current: bool,
+    pub current_chapter_count: usize,
+    pub current_scene_count: usize,
+    pub current_beat_count: usize,
+    pub current_word_count: i32,
+    pub snapshot_chapter_count: usize,
+    pub snapshot_scene_count: usize,
+    pub snapshot_beat_count: usize,
+    pub snapshot_word_count: i32,
+    pub warnings: Vec<String>,
 }
 
+/// Result of `restore_snapshot`: a preview, plus the restored project once confirmed
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreSnapshotResult {
+    pub applied: bool,
+    pub preview: RestorePreview,
+    pub project: Option<Project>,
+    /// Snapshot of the live project taken just before it was overwritten
+    pub safety_snapshot: Option<SnapshotMetadata>,
+}
+
+/// Restore a snapshot in two steps
+///
+/// Without `options.confirm` nothing is changed and only the preview is returned.
+/// A confirmed replace first snapshots the current state so it can be recovered.
 #[tauri::command]
 pub async fn restore_snapshot(
     snapshot_id: String,
     options: RestoreSnapshotOptions,
+    app_handle: AppHandle,
     state: State<'_, AppState>,
-) -> Result<Project, String> {
+) -> Result<RestoreSnapshotResult, String> {
     let snapshot_uuid = Uuid::parse_str(&snapshot_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
@@ -235,13 +538,93 @@ pub async fn restore_snapshot(
         .ok_or_else(|| "Snapshot not found".to_string())?;
 
     // Load snapshot data
-    let file_path = PathBuf::from(&metadata.file_path);
-    let data = decompress_and_deserialize(&file_path)?;
+    let data = load_snapshot_data(&metadata)?;
+
+    let snapshots_dir = get_snapshots_dir(&app_handle, &data.project.id)?;
+    restore_snapshot_data(&conn, data, options, &snapshots_dir)
+}
+
+/// Compare snapshot data with the live project it would replace
+fn build_restore_preview(
+    conn: &rusqlite::Connection,
+    data: &SnapshotData,
+    mode: &RestoreMode,
+) -> Result<RestorePreview, String> {
+    let live = match db::get_project(conn, &data.project.id).map_err(|e| e.to_string())? {
+        Some(_) => Some(collect_project_data(conn, &data.project.id)?),
+        None => None,
+    };
 
-    match options.mode {
-        RestoreMode::ReplaceCurrent => restore_replace_current(&conn, data),
-        RestoreMode::CreateNew => restore_create_new(&conn, data, options.new_project_name),
+    let mut preview = RestorePreview {
+        overwrites_current: matches!(mode, RestoreMode::ReplaceCurrent) && live.is_some(),
+        current_chapter_count: live.as_ref().map_or(0, |l| l.chapters.len()),
+        current_scene_count: live.as_ref().map_or(0, |l| l.scenes.len()),
+        current_beat_count: live.as_ref().map_or(0, |l| l.beats.len()),
+        current_word_count: live.as_ref().map_or(0, |l| l.word_count()),
+        snapshot_chapter_count: data.chapters.len(),
+        snapshot_scene_count: data.scenes.len(),
+        snapshot_beat_count: data.beats.len(),
+        snapshot_word_count: data.word_count(),
+        warnings: Vec::new(),
+    };
+
+    if preview.overwrites_current && preview.current_word_count > preview.snapshot_word_count {
+        preview.warnings.push(format!(
+            "The current project has {} more words than this snapshot; they will be lost",
+            preview.current_word_count - preview.snapshot_word_count
+        ));
+    }
+
+    Ok(preview)
+}
+
+/// Preview or apply a restore of already-loaded snapshot data
+fn restore_snapshot_data(
+    conn: &rusqlite::Connection,
+    data: SnapshotData,
+    options: RestoreSnapshotOptions,
+    snapshots_dir: &Path,
+) -> Result<RestoreSnapshotResult, String> {
+    let preview = build_restore_preview(conn, &data, &options.mode)?;
+
+    if !options.confirm {
+        return Ok(RestoreSnapshotResult {
+            applied: false,
+            preview,
+            project: None,
+            safety_snapshot: None,
+        });
     }
+
+    let safety_snapshot = if preview.overwrites_current {
+        Some(write_snapshot(
+            conn,
+            &data.project.id,
+            snapshots_dir,
+            CreateSnapshotOptions {
+                name: "Before restore".to_string(),
+                description: Some(
+                    "Automatic snapshot created before restoring a snapshot".to_string(),
+                ),
+                trigger_type: SnapshotTrigger::Auto,
+                compression: SnapshotCompression::default(),
+            },
+        )?)
+    } else {
+        None
+    };
+
+    let project = match options.mode {
+        RestoreMode::ReplaceCurrent => restore_replace_current(conn, data)?,
+        RestoreMode::CreateNew => restore_create_new(conn, data, options.new_project_name)?,
+    };
+
+    Ok(RestoreSnapshotResult {
+        applied: true,
+        preview,
+        project: Some(project),
+        safety_snapshot,
+    })
 }
 
 /// Restore by replacing current project data
@@ -257,6 +640,11 @@ fn restore_replace_current(
     // Delete all existing project content
     db::delete_all_project_content(&tx, &project_id).map_err(|e| e.to_string())?;
 
+    // Insert characters before scenes, which reference their POV character
+    for character in &data.characters {
+        db::insert_character(&tx, character).map_err(|e| e.to_string())?;
+    }
+
     // Insert chapters
     for chapter in &data.chapters {
         db::insert_chapter(&tx, chapter).map_err(|e| e.to_string())?;
@@ -272,11 +660,6 @@ fn restore_replace_current(
         db::insert_beat(&tx, beat).map_err(|e| e.to_string())?;
     }
 
-    // Insert characters
-    for character in &data.characters {
-        db::insert_character(&tx, character).map_err(|e| e.to_string())?;
-    }
-
     // Insert locations
     for location in &data.locations {
         db::insert_location(&tx, location).map_err(|e| e.to_string())?;
@@ -314,6 +697,16 @@ fn restore_replace_current(
         db::insert_discovery_note(&tx, note).map_err(|e| e.to_string())?;
     }
 
+    // Insert scene tags
+    for t in &data.scene_tags {
+        db::add_scene_tag(&tx, &t.scene_id, &t.tag).map_err(|e| e.to_string())?;
+    }
+
+    // Put trashed and cascade-archived items back in that state
+    for flags in &data.binder_flags {
+        db::set_binder_item_flags(&tx, flags).map_err(|e| e.to_string())?;
+    }
+
     db::update_project_modified(&tx, &project_id).map_err(|e| e.to_string())?;
 
     tx.commit().map_err(|e| e.to_string())?;
@@ -329,6 +722,22 @@ fn restore_create_new(
     data: SnapshotData,
     new_name: Option<String>,
 ) -> Result<Project, String> {
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let (project, _) = insert_with_fresh_ids(&tx, data, new_name)?;
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(project)
+}
+
+/// Insert snapshot data as a new project, giving every entity a fresh UUID.
+///
+/// Returns the new project and the old-to-new ID mapping. The caller owns the
+/// transaction.
+pub(super) fn insert_with_fresh_ids(
+    conn: &rusqlite::Connection,
+    data: SnapshotData,
+    new_name: Option<String>,
+) -> Result<(Project, HashMap<Uuid, Uuid>), String> {
     // Build ID mappings
     let mut id_map: HashMap<Uuid, Uuid> = HashMap::new();
 
@@ -360,9 +769,6 @@ fn restore_create_new(
         id_map.insert(note.id, Uuid::new_v4());
     }
 
-    // Begin transaction
-    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
-
     // Create new project
     let now = chrono::Utc::now().to_rfc3339();
     let new_project = Project {
@@ -380,11 +786,15 @@ fn restore_create_new(
         reference_types: data.project.reference_types,
         project_type: data.project.project_type,
         target_page_count: data.project.target_page_count,
+        scene_statuses: data.project.scene_statuses,
+        publisher: data.project.publisher,
+        isbn: data.project.isbn,
+        series_name: data.project.series_name,
+        series_index: data.project.series_index,
     };
 
-    db::insert_project(&tx, &new_project).map_err(|e| e.to_string())?;
+    db::insert_project(conn, &new_project).map_err(|e| e.to_string())?;
 
-    // Insert chapters with remapped IDs
     let map_id = |old: &Uuid| -> Result<Uuid, String> {
         id_map
             .get(old)
@@ -392,6 +802,20 @@ fn restore_create_new(
             .ok_or_else(|| format!("Missing ID mapping for {old}"))
     };
 
+    // Insert characters with remapped IDs, before the scenes that name a POV character
+    for character in &data.characters {
+        let new_character = Character {
+            id: map_id(&character.id)?,
+            project_id: new_project_id,
+            name: character.name.clone(),
+            description: character.description.clone(),
+            attributes: character.attributes.clone(),
+            source_id: character.source_id.clone(),
+        };
+        db::insert_character(conn, &new_character).map_err(|e| e.to_string())?;
+    }
+
+    // Insert chapters with remapped IDs
     for chapter in &data.chapters {
         let new_chapter = Chapter {
             id: map_id(&chapter.id)?,
@@ -404,8 +828,10 @@ fn restore_create_new(
             is_part: chapter.is_part,
             synopsis: chapter.synopsis.clone(),
             planning_status: chapter.planning_status,
+            color: chapter.color.clone(),
+            word_target: chapter.word_target,
         };
-        db::insert_chapter(&tx, &new_chapter).map_err(|e| e.to_string())?;
+        db::insert_chapter(conn, &new_chapter).map_err(|e| e.to_string())?;
     }
 
     // Insert scenes with remapped IDs
@@ -424,8 +850,17 @@ fn restore_create_new(
             scene_status: scene.scene_status,
             planning_status: scene.planning_status,
             editor_mode: scene.editor_mode,
+            compile_position: scene.compile_position,
+            include_in_compile: scene.include_in_compile,
+            color: scene.color.clone(),
+            pov_character_id: scene
+                .pov_character_id
+                .and_then(|id| id_map.get(&id).copied()),
+            notes: scene.notes.clone(),
+            word_target: scene.word_target,
+            custom_status: scene.custom_status.clone(),
         };
-        db::insert_scene(&tx, &new_scene).map_err(|e| e.to_string())?;
+        db::insert_scene(conn, &new_scene).map_err(|e| e.to_string())?;
     }
 
     // Insert beats with remapped IDs
@@ -438,20 +873,7 @@ fn restore_create_new(
             position: beat.position,
             source_id: beat.source_id.clone(),
         };
-        db::insert_beat(&tx, &new_beat).map_err(|e| e.to_string())?;
-    }
-
-    // Insert characters with remapped IDs
-    for character in &data.characters {
-        let new_character = Character {
-            id: map_id(&character.id)?,
-            project_id: new_project_id,
-            name: character.name.clone(),
-            description: character.description.clone(),
-            attributes: character.attributes.clone(),
-            source_id: character.source_id.clone(),
-        };
-        db::insert_character(&tx, &new_character).map_err(|e| e.to_string())?;
+        db::insert_beat(conn, &new_beat).map_err(|e| e.to_string())?;
     }
 
     // Insert locations with remapped IDs
@@ -464,7 +886,7 @@ fn restore_create_new(
             attributes: location.attributes.clone(),
             source_id: location.source_id.clone(),
         };
-        db::insert_location(&tx, &new_location).map_err(|e| e.to_string())?;
+        db::insert_location(conn, &new_location).map_err(|e| e.to_string())?;
     }
 
     // Insert reference items with remapped IDs
@@ -478,14 +900,14 @@ fn restore_create_new(
             attributes: item.attributes.clone(),
             source_id: item.source_id.clone(),
         };
-        db::insert_reference_item(&tx, &new_item).map_err(|e| e.to_string())?;
+        db::insert_reference_item(conn, &new_item).map_err(|e| e.to_string())?;
     }
 
     // Insert scene-character references with remapped IDs
     for r in &data.scene_character_refs {
         let new_scene_id = map_id(&r.scene_id)?;
         let new_character_id = map_id(&r.character_id)?;
-        db::add_scene_character_ref(&tx, &new_scene_id, &new_character_id)
+        db::add_scene_character_ref(conn, &new_scene_id, &new_character_id)
             .map_err(|e| e.to_string())?;
     }
 
@@ -493,7 +915,7 @@ fn restore_create_new(
     for r in &data.scene_location_refs {
         let new_scene_id = map_id(&r.scene_id)?;
         let new_location_id = map_id(&r.location_id)?;
-        db::add_scene_location_ref(&tx, &new_scene_id, &new_location_id)
+        db::add_scene_location_ref(conn, &new_scene_id, &new_location_id)
             .map_err(|e| e.to_string())?;
     }
 
@@ -501,7 +923,7 @@ fn restore_create_new(
     for r in &data.scene_reference_item_refs {
         let new_scene_id = map_id(&r.scene_id)?;
         let new_reference_item_id = map_id(&r.reference_item_id)?;
-        db::add_scene_reference_item_ref(&tx, &new_scene_id, &new_reference_item_id)
+        db::add_scene_reference_item_ref(conn, &new_scene_id, &new_reference_item_id)
             .map_err(|e| e.to_string())?;
     }
 
@@ -519,7 +941,7 @@ fn restore_create_new(
             position: state.position,
             expanded: state.expanded,
         };
-        db::insert_scene_reference_state(&tx, &new_state).map_err(|e| e.to_string())?;
+        db::insert_scene_reference_state(conn, &new_state).map_err(|e| e.to_string())?;
     }
 
     // Insert discovery notes with remapped IDs
@@ -533,12 +955,24 @@ fn restore_create_new(
             position: note.position,
             created_at: note.created_at.clone(),
         };
-        db::insert_discovery_note(&tx, &new_note).map_err(|e| e.to_string())?;
+        db::insert_discovery_note(conn, &new_note).map_err(|e| e.to_string())?;
     }
 
-    tx.commit().map_err(|e| e.to_string())?;
+    // Insert scene tags with remapped scene IDs
+    for t in &data.scene_tags {
+        db::add_scene_tag(conn, &map_id(&t.scene_id)?, &t.tag).map_err(|e| e.to_string())?;
+    }
+
+    // Put trashed and cascade-archived items back in that state
+    for flags in &data.binder_flags {
+        let new_flags = BinderItemFlags {
+            id: map_id(&flags.id)?,
+            ..flags.clone()
+        };
+        db::set_binder_item_flags(conn, &new_flags).map_err(|e| e.to_string())?;
+    }
 
-    Ok(new_project)
+    Ok((new_project, id_map))
 }
 
 /// Preview snapshot - returns light metadata without full deserialization
@@ -562,8 +996,7 @@ pub async fn preview_snapshot(
         .ok_or_else(|| "Snapshot not found".to_string())?;
 
     // Load snapshot data to get project name
-    let file_path = PathBuf::from(&metadata.file_path);
-    let data = decompress_and_deserialize(&file_path)?;
+    let data = load_snapshot_data(&metadata)?;
 
     Ok(SnapshotPreview {
         metadata,
@@ -571,6 +1004,227 @@ pub async fn preview_snapshot(
     })
 }
 
+/// How an item in the current project differs from a snapshot
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotDiffStatus {
+    /// Exists now but not in the snapshot
+    Added,
+    /// Exists in the snapshot but not now
+    Removed,
+    Modified,
+    Unchanged,
+}
+
+/// Comparison of one chapter, scene, or beat against a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDiffItem {
+    /// "chapter", "scene", or "beat"
+    pub item_type: String,
+    /// Current id, or the snapshot id for removed items
+    pub id: Uuid,
+    pub title: String,
+    pub status: SnapshotDiffStatus,
+    /// Whether the prose text differs once HTML markup is stripped
+    pub prose_changed: bool,
+    /// Prose words now minus prose words in the snapshot
+    pub word_delta: i64,
+}
+
+/// Changes between a snapshot and the current project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub snapshot_id: Uuid,
+    pub snapshot_word_count: i64,
+    pub current_word_count: i64,
+    pub word_delta: i64,
+    pub items: Vec<SnapshotDiffItem>,
+}
+
+/// Fields an item exposes for snapshot comparison
+trait SnapshotDiffEntry {
+    const ITEM_TYPE: &'static str;
+    fn id(&self) -> Uuid;
+    fn source_id(&self) -> Option<&str>;
+    fn title(&self) -> &str;
+    fn prose(&self) -> Option<&str>;
+    /// Whether the non-prose fields match; position is ignored so reorders don't count
+    fn same_outline(&self, other: &Self) -> bool;
+}
+
+impl SnapshotDiffEntry for Chapter {
+    const ITEM_TYPE: &'static str = "chapter";
+    fn id(&self) -> Uuid {
+        self.id
+    }
+    fn source_id(&self) -> Option<&str> {
+        self.source_id.as_deref()
+    }
+    fn title(&self) -> &str {
+        &self.title
+    }
+    fn prose(&self) -> Option<&str> {
+        None
+    }
+    fn same_outline(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.synopsis == other.synopsis
+            && self.archived == other.archived
+    }
+}
+
+impl SnapshotDiffEntry for Scene {
+    const ITEM_TYPE: &'static str = "scene";
+    fn id(&self) -> Uuid {
+        self.id
+    }
+    fn source_id(&self) -> Option<&str> {
+        self.source_id.as_deref()
+    }
+    fn title(&self) -> &str {
+        &self.title
+    }
+    fn prose(&self) -> Option<&str> {
+        self.prose.as_deref()
+    }
+    fn same_outline(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.synopsis == other.synopsis
+            && self.archived == other.archived
+            && self.scene_status == other.scene_status
+    }
+}
+
+impl SnapshotDiffEntry for Beat {
+    const ITEM_TYPE: &'static str = "beat";
+    fn id(&self) -> Uuid {
+        self.id
+    }
+    fn source_id(&self) -> Option<&str> {
+        self.source_id.as_deref()
+    }
+    fn title(&self) -> &str {
+        &self.content
+    }
+    fn prose(&self) -> Option<&str> {
+        self.prose.as_deref()
+    }
+    fn same_outline(&self, other: &Self) -> bool {
+        self.content == other.content
+    }
+}
+
+fn prose_text(prose: Option<&str>) -> String {
+    prose.map(strip_html).unwrap_or_default()
+}
+
+fn prose_words(prose: Option<&str>) -> i64 {
+    prose_text(prose).split_whitespace().count() as i64
+}
+
+/// Match current items to snapshot items by UUID, falling back to `source_id`
+fn diff_entries<T: SnapshotDiffEntry>(snapshot: &[T], current: &[T]) -> Vec<SnapshotDiffItem> {
+    let mut matched = vec![false; snapshot.len()];
+    let mut items = Vec::new();
+
+    for item in current {
+        let found = snapshot
+            .iter()
+            .position(|s| s.id() == item.id())
+            .or_else(|| {
+                let source_id = item.source_id()?;
+                snapshot
+                    .iter()
+                    .enumerate()
+                    .position(|(i, s)| !matched[i] && s.source_id() == Some(source_id))
+            });
+
+        let diff = match found {
+            Some(index) => {
+                matched[index] = true;
+                let old = &snapshot[index];
+                let prose_changed = prose_text(old.prose()) != prose_text(item.prose());
+                let status = if old.same_outline(item) && old.prose() == item.prose() {
+                    SnapshotDiffStatus::Unchanged
+                } else {
+                    SnapshotDiffStatus::Modified
+                };
+                SnapshotDiffItem {
+                    item_type: T::ITEM_TYPE.to_string(),
+                    id: item.id(),
+                    title: item.title().to_string(),
+                    status,
+                    prose_changed,
+                    word_delta: prose_words(item.prose()) - prose_words(old.prose()),
+                }
+            }
+            None => SnapshotDiffItem {
+                item_type: T::ITEM_TYPE.to_string(),
+                id: item.id(),
+                title: item.title().to_string(),
+                status: SnapshotDiffStatus::Added,
+                prose_changed: !prose_text(item.prose()).trim().is_empty(),
+                word_delta: prose_words(item.prose()),
+            },
+        };
+        items.push(diff);
+    }
+
+    for (old, _) in snapshot.iter().zip(&matched).filter(|(_, m)| !**m) {
+        items.push(SnapshotDiffItem {
+            item_type: T::ITEM_TYPE.to_string(),
+            id: old.id(),
+            title: old.title().to_string(),
+            status: SnapshotDiffStatus::Removed,
+            prose_changed: !prose_text(old.prose()).trim().is_empty(),
+            word_delta: -prose_words(old.prose()),
+        });
+    }
+
+    items
+}
+
+/// Compare loaded snapshot data with the live project
+fn diff_snapshot_data(
+    conn: &rusqlite::Connection,
+    snapshot_id: Uuid,
+    data: &SnapshotData,
+) -> Result<SnapshotDiff, String> {
+    let live = collect_project_data(conn, &data.project.id)?;
+
+    let mut items = diff_entries(&data.chapters, &live.chapters);
+    items.extend(diff_entries(&data.scenes, &live.scenes));
+    items.extend(diff_entries(&data.beats, &live.beats));
+
+    let snapshot_word_count = data.word_count() as i64;
+    let current_word_count = live.word_count() as i64;
+
+    Ok(SnapshotDiff {
+        snapshot_id,
+        snapshot_word_count,
+        current_word_count,
+        word_delta: current_word_count - snapshot_word_count,
+        items,
+    })
+}
+
+/// Compare a snapshot's chapters, scenes, and beats with the current project
+#[tauri::command]
+pub async fn diff_snapshot(
+    snapshot_id: String,
+    state: State<'_, AppState>,
+) -> Result<SnapshotDiff, String> {
+    let snapshot_uuid = Uuid::parse_str(&snapshot_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let metadata = db::get_snapshot_by_id(&conn, &snapshot_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Snapshot not found".to_string())?;
+
+    let data = load_snapshot_data(&metadata)?;
+    diff_snapshot_data(&conn, snapshot_uuid, &data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -579,7 +1233,8 @@ mod tests {
 
     #[test]
     fn test_generate_snapshot_filename_includes_trigger() {
-        let filename = generate_snapshot_filename(&SnapshotTrigger::Manual);
+        let filename =
+            generate_snapshot_filename(&SnapshotTrigger::Manual, SnapshotCompression::Gzip);
         assert!(filename.ends_with("_manual.json.gz"));
         let parts: Vec<&str> = filename.split('_').collect();
         assert_eq!(parts.len(), 3);
@@ -602,18 +1257,545 @@ mod tests {
             vec![],
             vec![],
             vec![],
+            vec![],
+            vec![],
         );
 
+        let dir = tempdir().expect("temp dir");
+        for compression in [
+            SnapshotCompression::None,
+            SnapshotCompression::Gzip,
+            SnapshotCompression::Zstd,
+        ] {
+            let file_path = dir
+                .path()
+                .join(format!("snapshot.{}", compression.file_extension()));
+
+            let (file_size, uncompressed_size, checksum) =
+                serialize_and_compress(&data, &file_path, compression).unwrap();
+            assert!(file_size > 0);
+            assert!(uncompressed_size > 0);
+            assert!(file_path.exists());
+
+            let restored =
+                decompress_and_deserialize(&file_path, compression, Some(&checksum)).unwrap();
+            assert_eq!(restored.project.id, data.project.id);
+            assert_eq!(restored.project.name, data.project.name);
+        }
+    }
+
+    #[test]
+    fn test_write_snapshot_records_chosen_compression() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Zstd Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+
+        let dir = tempdir().expect("temp dir");
+        let metadata = write_snapshot(
+            &conn,
+            &project.id,
+            dir.path(),
+            CreateSnapshotOptions {
+                name: "Compact".to_string(),
+                description: None,
+                trigger_type: SnapshotTrigger::Manual,
+                compression: SnapshotCompression::Zstd,
+            },
+        )
+        .unwrap();
+        assert!(metadata.file_path.ends_with("_manual.json.zst"));
+
+        let stored = db::get_snapshot_by_id(&conn, &metadata.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.compression, SnapshotCompression::Zstd);
+        let data = load_snapshot_data(&stored).unwrap();
+        assert_eq!(data.chapters.len(), 1);
+        assert_eq!(data.chapters[0].title, "One");
+    }
+
+    #[test]
+    fn test_corrupted_snapshot_fails_checksum() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Checksum Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "Original".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+
+        let dir = tempdir().expect("temp dir");
+        let metadata = write_snapshot(
+            &conn,
+            &project.id,
+            dir.path(),
+            CreateSnapshotOptions {
+                name: "Plain".to_string(),
+                description: None,
+                trigger_type: SnapshotTrigger::Manual,
+                compression: SnapshotCompression::None,
+            },
+        )
+        .unwrap();
+        let stored = db::get_snapshot_by_id(&conn, &metadata.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.checksum.as_ref().map(String::len), Some(64));
+        assert!(load_snapshot_data(&stored).is_ok());
+
+        // Still valid JSON, but no longer what was written
+        let contents = fs::read_to_string(&stored.file_path).unwrap();
+        fs::write(&stored.file_path, contents.replace("Original", "Tampered")).unwrap();
+
+        let err = load_snapshot_data(&stored).unwrap_err();
+        assert!(err.contains("checksum mismatch"), "{}", err);
+
+        // Snapshots from before checksums were recorded still load
+        let legacy = SnapshotMetadata {
+            checksum: None,
+            ..stored
+        };
+        assert_eq!(
+            load_snapshot_data(&legacy).unwrap().chapters[0].title,
+            "Tampered"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_preserves_scene_notes_and_pov() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON;").unwrap();
+
+        let project = Project::new("Notes Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let mara = Character::new(project.id, "Mara".to_string(), None, None);
+        db::insert_character(&conn, &mara).unwrap();
+        let mut scene = Scene::new(chapter.id, "Scene".to_string(), None, 0);
+        scene.notes = Some("Move this after the storm".to_string());
+        scene.pov_character_id = Some(mara.id);
+        db::insert_scene(&conn, &scene).unwrap();
+
+        let dir = tempdir().expect("temp dir");
+        let file_path = dir.path().join("snapshot.json.gz");
+        let data = collect_project_data(&conn, &project.id).unwrap();
+        serialize_and_compress(&data, &file_path, SnapshotCompression::Gzip).unwrap();
+        let data = decompress_and_deserialize(&file_path, SnapshotCompression::Gzip, None).unwrap();
+
+        let (copy, id_map) = insert_with_fresh_ids(&conn, data, None).unwrap();
+        let copied_chapter = db::get_chapters(&conn, &copy.id).unwrap().remove(0);
+        let copied_scene = db::get_scenes(&conn, &copied_chapter.id).unwrap().remove(0);
+        assert_eq!(
+            copied_scene.notes.as_deref(),
+            Some("Move this after the storm")
+        );
+        assert_eq!(copied_scene.pov_character_id, id_map.get(&mara.id).copied());
+    }
+
+    #[test]
+    fn test_restore_keeps_scene_tags() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Tag Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Scene".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        db::add_scene_tag(&conn, &scene.id, "subplot: heist").unwrap();
+
+        let data = collect_project_data(&conn, &project.id).unwrap();
+        assert_eq!(data.scene_tags.len(), 1);
+
+        let dir = tempdir().expect("temp dir");
+        let options = RestoreSnapshotOptions {
+            mode: RestoreMode::ReplaceCurrent,
+            new_project_name: None,
+            confirm: true,
+        };
+        restore_snapshot_data(&conn, data.clone(), options, dir.path()).unwrap();
+        assert_eq!(
+            db::get_scene_tags(&conn, &scene.id).unwrap(),
+            vec!["subplot: heist"]
+        );
+
+        let (_, id_map) = insert_with_fresh_ids(&conn, data, None).unwrap();
+        assert_eq!(
+            db::get_scene_tags(&conn, &id_map[&scene.id]).unwrap(),
+            vec!["subplot: heist"]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_round_trip_keeps_trash_and_cascade_archive() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Trash Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let kept = Scene::new(chapter.id, "Kept".to_string(), None, 0);
+        db::insert_scene(&conn, &kept).unwrap();
+        let binned = Scene::new(chapter.id, "Binned".to_string(), None, 1);
+        db::insert_scene(&conn, &binned).unwrap();
+        db::trash_scene(&conn, &binned.id).unwrap();
+        let shelved = Chapter::new(project.id, "Shelved".to_string(), 1);
+        db::insert_chapter(&conn, &shelved).unwrap();
+        let shelved_scene = Scene::new(shelved.id, "Inside".to_string(), None, 0);
+        db::insert_scene(&conn, &shelved_scene).unwrap();
+        db::archive_chapter_cascade(&conn, &shelved.id).unwrap();
+
         let dir = tempdir().expect("temp dir");
         let file_path = dir.path().join("snapshot.json.gz");
+        let data = collect_project_data(&conn, &project.id).unwrap();
+        serialize_and_compress(&data, &file_path, SnapshotCompression::Gzip).unwrap();
+        let data = decompress_and_deserialize(&file_path, SnapshotCompression::Gzip, None).unwrap();
+
+        let options = RestoreSnapshotOptions {
+            mode: RestoreMode::ReplaceCurrent,
+            new_project_name: None,
+            confirm: true,
+        };
+        restore_snapshot_data(&conn, data.clone(), options, dir.path()).unwrap();
+        let live: Vec<Uuid> = db::get_scenes(&conn, &chapter.id)
+            .unwrap()
+            .iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(live, vec![kept.id]);
+        let trashed = db::get_trashed_scenes(&conn, &project.id).unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].id, binned.id);
+        // The cascade-archived scene still comes back with its chapter
+        assert_eq!(db::restore_chapter_cascade(&conn, &shelved.id).unwrap(), 1);
+
+        let (copy, id_map) = insert_with_fresh_ids(&conn, data, None).unwrap();
+        let trashed = db::get_trashed_scenes(&conn, &copy.id).unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].id, id_map[&binned.id]);
+        assert_eq!(
+            db::restore_chapter_cascade(&conn, &id_map[&shelved.id]).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_restore_snapshot_requires_confirm() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Restore Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Scene".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+        beat.prose = Some("<p>Two words</p>".to_string());
+        db::insert_beat(&conn, &beat).unwrap();
+
+        let data = collect_project_data(&conn, &project.id).unwrap();
+
+        // Keep writing after the snapshot was taken
+        let mut newer = Beat::new(scene.id, "Later".to_string(), 1);
+        newer.prose = Some("<p>Three more words</p>".to_string());
+        db::insert_beat(&conn, &newer).unwrap();
 
-        let (file_size, uncompressed_size) = serialize_and_compress(&data, &file_path).unwrap();
-        assert!(file_size > 0);
-        assert!(uncompressed_size > 0);
-        assert!(file_path.exists());
+        let dir = tempdir().expect("temp dir");
+        let options = |confirm| RestoreSnapshotOptions {
+            mode: RestoreMode::ReplaceCurrent,
+            new_project_name: None,
+            confirm,
+        };
 
-        let restored = decompress_and_deserialize(&file_path).unwrap();
-        assert_eq!(restored.project.id, data.project.id);
-        assert_eq!(restored.project.name, data.project.name);
+        let result =
+            restore_snapshot_data(&conn, data.clone(), options(false), dir.path()).unwrap();
+        assert!(!result.applied);
+        assert!(result.project.is_none());
+        assert!(result.preview.overwrites_current);
+        assert_eq!(result.preview.current_beat_count, 2);
+        assert_eq!(result.preview.snapshot_beat_count, 1);
+        assert_eq!(result.preview.warnings.len(), 1);
+        assert_eq!(db::get_beats(&conn, &scene.id).unwrap().len(), 2);
+        assert!(db::get_snapshots_for_project(&conn, &project.id)
+            .unwrap()
+            .is_empty());
+
+        let result = restore_snapshot_data(&conn, data, options(true), dir.path()).unwrap();
+        assert!(result.applied);
+        assert_eq!(db::get_beats(&conn, &scene.id).unwrap().len(), 1);
+
+        let safety = result.safety_snapshot.expect("safety snapshot");
+        assert_eq!(safety.beat_count, 2);
+        assert!(PathBuf::from(&safety.file_path).exists());
+        assert_eq!(
+            db::get_snapshots_for_project(&conn, &project.id)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshot_reports_modified_beat() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Diff Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Scene".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut edited = Beat::new(scene.id, "Edited".to_string(), 0);
+        edited.prose = Some("<p>Two words</p>".to_string());
+        db::insert_beat(&conn, &edited).unwrap();
+        let mut restyled = Beat::new(scene.id, "Restyled".to_string(), 1);
+        restyled.prose = Some("<p>Same text</p>".to_string());
+        db::insert_beat(&conn, &restyled).unwrap();
+        let dropped = Beat::new(scene.id, "Dropped".to_string(), 2);
+        db::insert_beat(&conn, &dropped).unwrap();
+
+        let snapshot = collect_project_data(&conn, &project.id).unwrap();
+
+        db::update_beat_prose(&conn, &edited.id, "<p>Now four words here</p>").unwrap();
+        db::update_beat_prose(&conn, &restyled.id, "<p><em>Same</em> text</p>").unwrap();
+        db::delete_beat(&conn, &dropped.id).unwrap();
+        let mut added = Beat::new(scene.id, "Added".to_string(), 2);
+        added.prose = Some("<p>New</p>".to_string());
+        db::insert_beat(&conn, &added).unwrap();
+
+        let snapshot_id = Uuid::new_v4();
+        let diff = diff_snapshot_data(&conn, snapshot_id, &snapshot).unwrap();
+        let find = |id: Uuid| diff.items.iter().find(|i| i.id == id).unwrap();
+
+        assert_eq!(diff.snapshot_id, snapshot_id);
+        assert_eq!(diff.word_delta, 3);
+        assert_eq!(find(chapter.id).status, SnapshotDiffStatus::Unchanged);
+        assert_eq!(find(scene.id).status, SnapshotDiffStatus::Unchanged);
+
+        let edited_diff = find(edited.id);
+        assert_eq!(edited_diff.status, SnapshotDiffStatus::Modified);
+        assert!(edited_diff.prose_changed);
+        assert_eq!(edited_diff.word_delta, 2);
+
+        // Markup-only edits are modifications without a text change
+        let restyled_diff = find(restyled.id);
+        assert_eq!(restyled_diff.status, SnapshotDiffStatus::Modified);
+        assert!(!restyled_diff.prose_changed);
+
+        assert_eq!(find(dropped.id).status, SnapshotDiffStatus::Removed);
+        assert_eq!(find(added.id).status, SnapshotDiffStatus::Added);
+    }
+
+    #[test]
+    fn test_should_auto_snapshot_threshold() {
+        // Disabled without a positive threshold
+        assert!(!should_auto_snapshot(10_000, Some(0), None));
+        assert!(!should_auto_snapshot(10_000, Some(0), Some(0)));
+
+        // Growth must exceed the threshold
+        assert!(!should_auto_snapshot(1_500, Some(1_000), Some(500)));
+        assert!(should_auto_snapshot(1_501, Some(1_000), Some(500)));
+
+        // Shrinking never triggers
+        assert!(!should_auto_snapshot(200, Some(1_000), Some(500)));
+
+        // With no prior snapshot, growth counts from zero
+        assert!(!should_auto_snapshot(400, None, Some(500)));
+        assert!(should_auto_snapshot(600, None, Some(500)));
+    }
+
+    #[test]
+    fn test_auto_snapshot_if_due_uses_latest_snapshot() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Auto Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Scene".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+        beat.prose = Some("<p>one two three four five six</p>".to_string());
+        db::insert_beat(&conn, &beat).unwrap();
+
+        let dir = tempdir().expect("temp dir");
+        let first = auto_snapshot_if_due(&conn, &project.id, dir.path(), Some(5)).unwrap();
+        let first = first.expect("crossed threshold from zero");
+        assert_eq!(first.trigger_type, SnapshotTrigger::Auto);
+        assert_eq!(
+            db::get_latest_snapshot(&conn, &project.id)
+                .unwrap()
+                .map(|s| s.id),
+            Some(first.id)
+        );
+
+        // No growth since the last snapshot
+        assert!(
+            auto_snapshot_if_due(&conn, &project.id, dir.path(), Some(5))
+                .unwrap()
+                .is_none()
+        );
+        assert!(auto_snapshot_if_due(&conn, &project.id, dir.path(), None)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_prune_snapshots_keeps_manual_and_policy_survivors() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Prune Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+
+        let dir = tempdir().expect("temp dir");
+        let now = chrono::Utc::now();
+
+        // Ten snapshots, one per day going back; every third is manual
+        let mut snapshots = Vec::new();
+        for age in 0..10 {
+            let trigger = if age % 3 == 0 {
+                SnapshotTrigger::Manual
+            } else if age % 2 == 0 {
+                SnapshotTrigger::Export
+            } else {
+                SnapshotTrigger::Auto
+            };
+            let file_path = dir.path().join(format!("{}.json.gz", age));
+            fs::write(&file_path, b"snapshot").unwrap();
+            let mut metadata = SnapshotMetadata::new(
+                project.id,
+                format!("Day -{}", age),
+                None,
+                trigger,
+                file_path.to_string_lossy().to_string(),
+                100,
+                None,
+                0,
+                0,
+                0,
+                None,
+            );
+            metadata.created_at = (now - chrono::Duration::days(age)).to_rfc3339();
+            db::insert_snapshot_metadata(&conn, &metadata).unwrap();
+            snapshots.push(metadata);
+        }
+
+        let surviving_names = || -> Vec<String> {
+            let mut names: Vec<String> = db::get_snapshots_for_project(&conn, &project.id)
+                .unwrap()
+                .into_iter()
+                .map(|s| s.name)
+                .collect();
+            names.sort();
+            names
+        };
+
+        // No policy, nothing pruned
+        let result =
+            prune_project_snapshots(&conn, &project.id, &SnapshotRetentionPolicy::default(), now)
+                .unwrap();
+        assert_eq!(result.pruned, 0);
+        assert_eq!(surviving_names().len(), 10);
+
+        // Keep the last three or anything from the past five days; of the
+        // older snapshots only days -7 and -8 aren't manual
+        let policy = SnapshotRetentionPolicy {
+            keep_last_n: Some(3),
+            keep_days: Some(5),
+        };
+        let result = prune_project_snapshots(&conn, &project.id, &policy, now).unwrap();
+        assert_eq!(result.pruned, 2);
+        assert_eq!(result.bytes_reclaimed, 200);
+        assert!(!dir.path().join("7.json.gz").exists());
+        assert!(!dir.path().join("8.json.gz").exists());
+        assert!(dir.path().join("9.json.gz").exists());
+
+        // Count-only policy prunes every non-manual snapshot past the newest two
+        let policy = SnapshotRetentionPolicy {
+            keep_last_n: Some(2),
+            keep_days: None,
+        };
+        let result = prune_project_snapshots(&conn, &project.id, &policy, now).unwrap();
+        assert_eq!(result.pruned, 3);
+        assert_eq!(
+            surviving_names(),
+            vec!["Day -0", "Day -1", "Day -3", "Day -6", "Day -9"]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_history_is_chronological_and_fills_word_counts() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::db::initialize_schema(&conn).unwrap();
+        let project = Project::new("History Test".to_string(), SourceType::Markdown, None);
+        db::insert_project(&conn, &project).unwrap();
+
+        // An archive for the snapshot that predates word counts
+        let mut data = SnapshotData::new(
+            project.clone(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let mut beat = Beat::new(Uuid::new_v4(), "Beat".to_string(), 0);
+        beat.prose = Some("three archived words".to_string());
+        data.beats.push(beat);
+        let dir = tempdir().expect("temp dir");
+        let legacy_path = dir.path().join("legacy.json.gz");
+        serialize_and_compress(&data, &legacy_path, SnapshotCompression::Gzip).unwrap();
+
+        // Inserted out of order
+        for (day, words, path) in [
+            (3, Some(900), "missing.json.gz".to_string()),
+            (1, None, legacy_path.to_string_lossy().to_string()),
+            (2, Some(500), "missing.json.gz".to_string()),
+        ] {
+            let mut metadata = SnapshotMetadata::new(
+                project.id,
+                format!("Day {}", day),
+                None,
+                SnapshotTrigger::Manual,
+                path,
+                10,
+                None,
+                day,
+                day * 2,
+                0,
+                words,
+            );
+            metadata.created_at = format!("2026-01-0{}T09:00:00+00:00", day);
+            db::insert_snapshot_metadata(&conn, &metadata).unwrap();
+        }
+
+        let history = build_snapshot_history(&conn, &project.id).unwrap();
+        assert_eq!(history.len(), 3);
+        assert!(history
+            .windows(2)
+            .all(|pair| pair[0].created_at < pair[1].created_at));
+        assert_eq!(
+            history.iter().map(|p| p.word_count).collect::<Vec<_>>(),
+            vec![Some(3), Some(500), Some(900)]
+        );
+        assert_eq!(history[0].chapter_count, 1);
+        assert_eq!(history[2].scene_count, 6);
     }
 }
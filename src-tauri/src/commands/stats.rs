@@ -0,0 +1,476 @@
+//! Writing Stats Commands
+//!
+//! Tracks daily writing sessions by recording the project word count when a
+//! session starts and ends, and keeps each project's cached word count.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+use uuid::Uuid;
+
+use super::export::{cached_project_word_count, calculate_chapter_word_count};
+use crate::commands::AppState;
+use crate::db;
+use crate::models::{RecentProjectInfo, SceneStatus, SessionStat, WritingSession};
+
+/// Today's local date, used to bucket sessions by the day the author wrote
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Current project word count as stored in a session
+fn current_word_count(conn: &rusqlite::Connection, project_uuid: &Uuid) -> Result<i64, String> {
+    Ok(cached_project_word_count(conn, project_uuid)? as i64)
+}
+
+/// Start a writing session by recording the project's current word count
+///
+/// If a session is already in progress it is returned unchanged.
+#[tauri::command]
+pub async fn start_writing_session(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<WritingSession, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    if let Some(open) =
+        db::get_open_writing_session(&conn, &project_uuid).map_err(|e| e.to_string())?
+    {
+        return Ok(open);
+    }
+
+    let start_word_count = current_word_count(&conn, &project_uuid)?;
+    let session = WritingSession::new(project_uuid, today(), start_word_count);
+    db::record_session_snapshot(&conn, &session).map_err(|e| e.to_string())?;
+
+    Ok(session)
+}
+
+/// End the in-progress writing session, storing the project's word count now
+#[tauri::command]
+pub async fn end_writing_session(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<WritingSession, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let mut session = db::get_open_writing_session(&conn, &project_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No writing session in progress".to_string())?;
+
+    session.end_word_count = Some(current_word_count(&conn, &project_uuid)?);
+    db::record_session_snapshot(&conn, &session).map_err(|e| e.to_string())?;
+
+    Ok(session)
+}
+
+/// Get words written per day between two dates (YYYY-MM-DD, inclusive)
+///
+/// Days without an ended session are omitted.
+#[tauri::command]
+pub async fn get_writing_stats(
+    project_id: String,
+    start_date: String,
+    end_date: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<SessionStat>, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let sessions = db::get_sessions_for_range(&conn, &project_uuid, &start_date, &end_date)
+        .map_err(|e| e.to_string())?;
+
+    Ok(summarize_sessions(&sessions))
+}
+
+/// Sum ended sessions into one [`SessionStat`] per day, ordered by date
+fn summarize_sessions(sessions: &[WritingSession]) -> Vec<SessionStat> {
+    let mut by_date: BTreeMap<&str, SessionStat> = BTreeMap::new();
+
+    for session in sessions.iter().filter(|s| s.end_word_count.is_some()) {
+        let stat = by_date
+            .entry(session.date.as_str())
+            .or_insert_with(|| SessionStat {
+                date: session.date.clone(),
+                words_written: 0,
+                session_count: 0,
+            });
+        stat.words_written += session.words_written();
+        stat.session_count += 1;
+    }
+
+    by_date.into_values().collect()
+}
+
+/// Progress of the project word count towards `Project.word_target`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTargetProgress {
+    pub target: Option<i32>,
+    pub current: usize,
+    /// Percent of the target reached; may exceed 100. None without a positive target
+    pub percent: Option<f32>,
+    /// Words left to reach the target; negative once it is exceeded
+    pub remaining: Option<i64>,
+}
+
+/// Compute progress towards a word target, treating a zero target as unset
+fn word_target_progress(target: Option<i32>, current: usize) -> WordTargetProgress {
+    let positive_target = target.filter(|t| *t > 0);
+    WordTargetProgress {
+        target,
+        current,
+        percent: positive_target.map(|t| current as f32 / t as f32 * 100.0),
+        remaining: positive_target.map(|t| i64::from(t) - current as i64),
+    }
+}
+
+/// Get the project's word count alongside its word target
+#[tauri::command]
+pub async fn get_word_target_progress(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<WordTargetProgress, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    let project = db::get_project(&conn, &project_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Project not found: {}", project_id))?;
+    let current = cached_project_word_count(&conn, &project_uuid)?;
+
+    Ok(word_target_progress(project.word_target, current))
+}
+
+/// Get a chapter's word count alongside its word target
+#[tauri::command]
+pub async fn get_chapter_target_progress(
+    chapter_id: String,
+    state: State<'_, AppState>,
+) -> Result<WordTargetProgress, String> {
+    let chapter_uuid = Uuid::parse_str(&chapter_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    chapter_target_progress(&conn, &chapter_uuid)
+}
+
+/// Compute a chapter's progress towards its `Chapter.word_target`
+fn chapter_target_progress(
+    conn: &rusqlite::Connection,
+    chapter_uuid: &Uuid,
+) -> Result<WordTargetProgress, String> {
+    let chapter = db::get_chapter_by_id(conn, chapter_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Chapter not found: {}", chapter_uuid))?;
+    let current = calculate_chapter_word_count(conn, chapter_uuid)?;
+
+    Ok(word_target_progress(chapter.word_target, current))
+}
+
+/// Describe how long before `now` an RFC 3339 timestamp was, e.g. "3 days ago"
+fn relative_time(timestamp: &str, now: chrono::DateTime<chrono::Utc>) -> String {
+    let Ok(then) = chrono::DateTime::parse_from_rfc3339(timestamp) else {
+        return String::new();
+    };
+    let seconds = (now - then.with_timezone(&chrono::Utc))
+        .num_seconds()
+        .max(0);
+    let (amount, unit) = match seconds {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => (seconds / 60, "minute"),
+        3_600..=86_399 => (seconds / 3_600, "hour"),
+        86_400..=2_591_999 => (seconds / 86_400, "day"),
+        2_592_000..=31_535_999 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    format!("{} {}{} ago", amount, unit, plural)
+}
+
+/// Recent projects with scene counts and cached word counts
+///
+/// Projects whose word count has never been cached are counted once here.
+pub(crate) fn recent_project_infos(
+    conn: &rusqlite::Connection,
+    limit: usize,
+) -> Result<Vec<RecentProjectInfo>, String> {
+    let now = chrono::Utc::now();
+    let projects = db::get_recent_projects(conn, limit).map_err(|e| e.to_string())?;
+    let mut infos = Vec::with_capacity(projects.len());
+
+    for project in projects {
+        let word_count = cached_project_word_count(conn, &project.id)?;
+        let scene_count = db::count_project_scenes(conn, &project.id).map_err(|e| e.to_string())?;
+        infos.push(RecentProjectInfo {
+            modified_relative: relative_time(&project.modified_at, now),
+            project,
+            scene_count,
+            word_count,
+        });
+    }
+
+    Ok(infos)
+}
+
+/// Scene counts by status for one chapter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterStatusSummary {
+    pub chapter_id: Uuid,
+    pub chapter_title: String,
+    /// Every status is present, with zero for statuses no scene has
+    pub counts: BTreeMap<SceneStatus, usize>,
+}
+
+/// Get per-chapter scene status counts for a progress dashboard
+///
+/// Archived chapters and scenes are left out.
+#[tauri::command]
+pub async fn get_chapter_status_summary(
+    project_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<ChapterStatusSummary>, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    chapter_status_summary(&conn, &project_uuid)
+}
+
+fn chapter_status_summary(
+    conn: &rusqlite::Connection,
+    project_uuid: &Uuid,
+) -> Result<Vec<ChapterStatusSummary>, String> {
+    let chapters = db::get_chapters(conn, project_uuid).map_err(|e| e.to_string())?;
+    let mut summaries = Vec::with_capacity(chapters.len());
+
+    for chapter in chapters {
+        let mut counts: BTreeMap<SceneStatus, usize> = [
+            (SceneStatus::Draft, 0),
+            (SceneStatus::Revised, 0),
+            (SceneStatus::Final, 0),
+        ]
+        .into_iter()
+        .collect();
+        for scene in db::get_scenes(conn, &chapter.id).map_err(|e| e.to_string())? {
+            *counts.entry(scene.scene_status).or_default() += 1;
+        }
+        summaries.push(ChapterStatusSummary {
+            chapter_id: chapter.id,
+            chapter_title: chapter.title,
+            counts,
+        });
+    }
+
+    Ok(summaries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(date: &str, start: i64, end: Option<i64>) -> WritingSession {
+        let mut session = WritingSession::new(Uuid::new_v4(), date.to_string(), start);
+        session.end_word_count = end;
+        session
+    }
+
+    #[test]
+    fn test_words_written_delta() {
+        assert_eq!(session("2026-03-01", 1000, Some(1450)).words_written(), 450);
+        assert_eq!(session("2026-03-01", 1000, Some(900)).words_written(), -100);
+        assert_eq!(session("2026-03-01", 1000, None).words_written(), 0);
+    }
+
+    #[test]
+    fn test_summarize_sessions_groups_by_day() {
+        let stats = summarize_sessions(&[
+            session("2026-03-02", 1500, Some(1800)),
+            session("2026-03-01", 1000, Some(1200)),
+            session("2026-03-01", 1200, Some(1500)),
+            session("2026-03-03", 1800, None),
+        ]);
+
+        assert_eq!(
+            stats,
+            vec![
+                SessionStat {
+                    date: "2026-03-01".to_string(),
+                    words_written: 500,
+                    session_count: 2,
+                },
+                SessionStat {
+                    date: "2026-03-02".to_string(),
+                    words_written: 300,
+                    session_count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_word_target_progress() {
+        let halfway = word_target_progress(Some(80_000), 40_000);
+        assert_eq!(halfway.percent, Some(50.0));
+        assert_eq!(halfway.remaining, Some(40_000));
+
+        let over = word_target_progress(Some(1_000), 1_250);
+        assert_eq!(over.percent, Some(125.0));
+        assert_eq!(over.remaining, Some(-250));
+
+        let zero = word_target_progress(Some(0), 500);
+        assert_eq!(zero.target, Some(0));
+        assert_eq!(zero.percent, None);
+        assert_eq!(zero.remaining, None);
+
+        let unset = word_target_progress(None, 500);
+        assert_eq!(unset.current, 500);
+        assert_eq!(unset.percent, None);
+        assert_eq!(unset.remaining, None);
+    }
+
+    #[test]
+    fn test_chapter_target_progress() {
+        use crate::models::{Beat, Chapter, Project, Scene, SourceType};
+
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Goals".to_string(), SourceType::Blank, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        db::set_chapter_word_target(&conn, &chapter.id, Some(3000)).unwrap();
+
+        // 1500 words split over two scenes' beats
+        let words = |n: usize| format!("<p>{}</p>", vec!["word"; n].join(" "));
+        for (position, count) in [(0, 1000), (1, 500)] {
+            let scene = Scene::new(chapter.id, format!("Scene {}", position), None, position);
+            db::insert_scene(&conn, &scene).unwrap();
+            let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+            beat.prose = Some(words(count));
+            db::insert_beat(&conn, &beat).unwrap();
+        }
+
+        let progress = chapter_target_progress(&conn, &chapter.id).unwrap();
+        assert_eq!(progress.target, Some(3000));
+        assert_eq!(progress.current, 1500);
+        assert_eq!(progress.percent, Some(50.0));
+        assert_eq!(progress.remaining, Some(1500));
+
+        db::set_chapter_word_target(&conn, &chapter.id, None).unwrap();
+        let cleared = chapter_target_progress(&conn, &chapter.id).unwrap();
+        assert_eq!(cleared.target, None);
+        assert_eq!(cleared.percent, None);
+    }
+
+    #[test]
+    fn test_chapter_status_summary() {
+        use crate::models::{Chapter, Project, Scene, SourceType};
+
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Rollup".to_string(), SourceType::Blank, None);
+        db::insert_project(&conn, &project).unwrap();
+        let one = Chapter::new(project.id, "One".to_string(), 0);
+        let two = Chapter::new(project.id, "Two".to_string(), 1);
+        db::insert_chapter(&conn, &one).unwrap();
+        db::insert_chapter(&conn, &two).unwrap();
+
+        let statuses = [
+            SceneStatus::Draft,
+            SceneStatus::Draft,
+            SceneStatus::Final,
+            SceneStatus::Revised,
+            SceneStatus::Final,
+        ];
+        for (position, status) in statuses.into_iter().enumerate() {
+            let mut scene =
+                Scene::new(one.id, format!("Scene {}", position), None, position as i32);
+            scene.scene_status = status;
+            db::insert_scene(&conn, &scene).unwrap();
+        }
+        // Archived scenes do not count towards progress
+        let mut archived = Scene::new(one.id, "Cut".to_string(), None, 5);
+        archived.scene_status = SceneStatus::Final;
+        db::insert_scene(&conn, &archived).unwrap();
+        db::archive_scene(&conn, &archived.id).unwrap();
+
+        let summary = chapter_status_summary(&conn, &project.id).unwrap();
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].chapter_title, "One");
+        assert_eq!(summary[0].counts[&SceneStatus::Draft], 2);
+        assert_eq!(summary[0].counts[&SceneStatus::Revised], 1);
+        assert_eq!(summary[0].counts[&SceneStatus::Final], 2);
+        assert!(summary[1].counts.values().all(|count| *count == 0));
+
+        let json = serde_json::to_value(&summary[0].counts).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"draft": 2, "revised": 1, "final": 2})
+        );
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(relative_time("2026-03-10T11:59:30Z", now), "just now");
+        assert_eq!(relative_time("2026-03-10T11:59:00Z", now), "1 minute ago");
+        assert_eq!(relative_time("2026-03-10T09:00:00Z", now), "3 hours ago");
+        assert_eq!(relative_time("2026-03-07T12:00:00Z", now), "3 days ago");
+        assert_eq!(relative_time("2025-12-10T12:00:00Z", now), "3 months ago");
+        assert_eq!(relative_time("2024-03-10T12:00:00Z", now), "2 years ago");
+        assert_eq!(relative_time("not a date", now), "");
+    }
+
+    #[test]
+    fn test_recent_project_infos_match_contents() {
+        use crate::commands::export::{calculate_project_word_count, refresh_cached_word_count};
+        use crate::models::{Beat, Chapter, Project, Scene, SourceType};
+
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        db::initialize_schema(&conn).unwrap();
+        let project = Project::new("Listing".to_string(), SourceType::Blank, None);
+        db::insert_project(&conn, &project).unwrap();
+        let chapter = Chapter::new(project.id, "One".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+
+        let mut beat_ids = Vec::new();
+        for (position, prose) in [(0, "<p>one two three</p>"), (1, "<p>four five</p>")] {
+            let scene = Scene::new(chapter.id, format!("Scene {}", position), None, position);
+            db::insert_scene(&conn, &scene).unwrap();
+            let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+            beat.prose = Some(prose.to_string());
+            db::insert_beat(&conn, &beat).unwrap();
+            beat_ids.push(beat.id);
+        }
+        let archived = Scene::new(chapter.id, "Cut".to_string(), None, 2);
+        db::insert_scene(&conn, &archived).unwrap();
+        db::archive_scene(&conn, &archived.id).unwrap();
+
+        // First listing fills the cache from the project's contents
+        let infos = recent_project_infos(&conn, 10).unwrap();
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].project.id, project.id);
+        assert_eq!(infos[0].scene_count, 2);
+        assert_eq!(infos[0].word_count, 5);
+        assert_eq!(infos[0].modified_relative, "just now");
+        assert_eq!(
+            db::get_cached_word_count(&conn, &project.id).unwrap(),
+            Some(5)
+        );
+
+        // Later listings read the cache, which a save refreshes
+        db::update_beat_prose(&conn, &beat_ids[1], "<p>four five six seven</p>").unwrap();
+        assert_eq!(recent_project_infos(&conn, 10).unwrap()[0].word_count, 5);
+        refresh_cached_word_count(&conn, &project.id).unwrap();
+        assert_eq!(recent_project_infos(&conn, 10).unwrap()[0].word_count, 7);
+        assert_eq!(
+            recent_project_infos(&conn, 10).unwrap()[0].word_count,
+            calculate_project_word_count(&conn, &project.id, None).unwrap()
+        );
+
+        assert!(recent_project_infos(&conn, 0).unwrap().is_empty());
+    }
+}
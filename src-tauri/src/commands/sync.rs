@@ -8,9 +8,10 @@ use tauri::State;
 use uuid::Uuid;
 
 use crate::db;
-use crate::models::{Beat, Chapter, EditorMode, PlanningStatus, Scene};
+use crate::models::{Beat, Chapter, EditorMode, PlanningStatus, Project, Scene};
 use crate::parsers::{
-    parse_longform_index, parse_markdown_outline, parse_plottr_file, parse_ywriter_file,
+    markdown_inline_to_plain, parse_docx_file, parse_fountain_file, parse_longform_index,
+    parse_markdown_outline, parse_odt_file, parse_plottr_file, parse_ywriter_file, ParsedPlottr,
 };
 
 use super::AppState;
@@ -49,10 +50,126 @@ pub struct SyncChange {
     pub db_id: String, // The database ID to update if accepted
 }
 
+/// How `apply_sync` resolves existing items whose source version changed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncStrategy {
+    /// Apply accepted source changes to the local item (prose is kept)
+    #[default]
+    PreferSource,
+    /// Keep local scene/beat content and only update positions
+    PreferLocal,
+    /// Archive the local version and add the source version as a new item
+    KeepBoth,
+}
+
+/// What a [`SyncStrategy`] will do to a changed item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncConflictAction {
+    UseSource,
+    KeepLocal,
+    ArchiveAndAdd,
+}
+
+impl SyncStrategy {
+    fn conflict_action(self) -> SyncConflictAction {
+        match self {
+            SyncStrategy::PreferSource => SyncConflictAction::UseSource,
+            SyncStrategy::PreferLocal => SyncConflictAction::KeepLocal,
+            SyncStrategy::KeepBoth => SyncConflictAction::ArchiveAndAdd,
+        }
+    }
+}
+
+/// An existing scene or beat whose source version differs from the local one
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SyncConflict {
+    pub db_id: String,
+    pub item_type: String, // "scene", "beat"
+    pub item_title: String,
+    pub action: SyncConflictAction,
+}
+
+/// What `apply_sync` did to one chapter, scene or beat
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", content = "reason", rename_all = "snake_case")]
+pub enum SyncAction {
+    Created,
+    Updated,
+    /// Left untouched, with the reason (e.g. "locked")
+    Skipped(String),
+    /// Archived because it no longer exists in the source
+    ArchivedMissing,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SyncReportItem {
+    /// Database ID, or the parsed ID for additions that were not made
+    pub id: String,
+    pub item_type: String, // "chapter", "scene", "beat"
+    pub title: String,
+    pub action: SyncAction,
+}
+
+/// Change log returned by `apply_sync`: the usual counts plus one entry per item acted on
+#[derive(serde::Serialize)]
+pub struct SyncReport {
+    #[serde(flatten)]
+    pub summary: ReimportSummary,
+    pub items_skipped: i32,
+    pub scenes_archived: i32,
+    pub items: Vec<SyncReportItem>,
+}
+
+impl SyncReport {
+    fn new() -> Self {
+        SyncReport {
+            summary: ReimportSummary {
+                chapters_added: 0,
+                chapters_updated: 0,
+                scenes_added: 0,
+                scenes_updated: 0,
+                beats_added: 0,
+                beats_updated: 0,
+                prose_preserved: 0,
+            },
+            items_skipped: 0,
+            scenes_archived: 0,
+            items: Vec::new(),
+        }
+    }
+
+    /// Log an item's outcome and bump the matching count
+    fn record(&mut self, item_type: &str, id: Uuid, title: &str, action: SyncAction) {
+        let summary = &mut self.summary;
+        match (item_type, &action) {
+            ("chapter", SyncAction::Created) => summary.chapters_added += 1,
+            ("chapter", SyncAction::Updated) => summary.chapters_updated += 1,
+            ("scene", SyncAction::Created) => summary.scenes_added += 1,
+            ("scene", SyncAction::Updated) => summary.scenes_updated += 1,
+            ("beat", SyncAction::Created) => summary.beats_added += 1,
+            ("beat", SyncAction::Updated) => summary.beats_updated += 1,
+            (_, SyncAction::Skipped(_)) => self.items_skipped += 1,
+            (_, SyncAction::ArchivedMissing) => self.scenes_archived += 1,
+            _ => {}
+        }
+        self.items.push(SyncReportItem {
+            id: id.to_string(),
+            item_type: item_type.to_string(),
+            title: title.to_string(),
+            action,
+        });
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct SyncPreview {
     pub additions: Vec<SyncAddition>,
     pub changes: Vec<SyncChange>,
+    /// Strategy the conflict actions were computed for
+    pub strategy: SyncStrategy,
+    pub conflicts: Vec<SyncConflict>,
 }
 
 // ============================================================================
@@ -63,7 +180,7 @@ pub struct SyncPreview {
 pub async fn reimport_project(
     project_id: String,
     state: State<'_, AppState>,
-) -> Result<ReimportSummary, String> {
+) -> Result<SyncReport, String> {
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
@@ -98,6 +215,8 @@ pub async fn reimport_project(
                 locations: yw_parsed.locations,
                 scene_character_refs: yw_parsed.scene_character_refs,
                 scene_location_refs: yw_parsed.scene_location_refs,
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
             }
         }
         crate::models::SourceType::Scrivener => {
@@ -117,6 +236,8 @@ pub async fn reimport_project(
                 locations: lf_parsed.locations,
                 scene_character_refs: lf_parsed.scene_character_refs,
                 scene_location_refs: lf_parsed.scene_location_refs,
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
             }
         }
         crate::models::SourceType::Markdown => {
@@ -130,6 +251,53 @@ pub async fn reimport_project(
                 locations: Vec::new(),
                 scene_character_refs: Vec::new(),
                 scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
+            }
+        }
+        crate::models::SourceType::Fountain => {
+            let ft_parsed = parse_fountain_file(source_path).map_err(|e| e.to_string())?;
+            crate::parsers::ParsedPlottr {
+                project: ft_parsed.project,
+                chapters: ft_parsed.chapters,
+                scenes: ft_parsed.scenes,
+                beats: ft_parsed.beats,
+                characters: ft_parsed.characters,
+                locations: Vec::new(),
+                scene_character_refs: ft_parsed.scene_character_refs,
+                scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
+            }
+        }
+        crate::models::SourceType::Odt => {
+            let odt_parsed = parse_odt_file(source_path).map_err(|e| e.to_string())?;
+            crate::parsers::ParsedPlottr {
+                project: odt_parsed.project,
+                chapters: odt_parsed.chapters,
+                scenes: odt_parsed.scenes,
+                beats: odt_parsed.beats,
+                characters: Vec::new(),
+                locations: Vec::new(),
+                scene_character_refs: Vec::new(),
+                scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
+            }
+        }
+        crate::models::SourceType::Docx => {
+            let docx_parsed = parse_docx_file(source_path).map_err(|e| e.to_string())?;
+            crate::parsers::ParsedPlottr {
+                project: docx_parsed.project,
+                chapters: docx_parsed.chapters,
+                scenes: docx_parsed.scenes,
+                beats: docx_parsed.beats,
+                characters: Vec::new(),
+                locations: Vec::new(),
+                scene_character_refs: Vec::new(),
+                scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
             }
         }
         crate::models::SourceType::Blank => {
@@ -137,15 +305,21 @@ pub async fn reimport_project(
         }
     };
 
-    let mut summary = ReimportSummary {
-        chapters_added: 0,
-        chapters_updated: 0,
-        scenes_added: 0,
-        scenes_updated: 0,
-        beats_added: 0,
-        beats_updated: 0,
-        prose_preserved: 0,
-    };
+    apply_reimport(&conn, &project_uuid, &parsed)
+}
+
+/// Apply a re-parsed source to its project in one transaction
+///
+/// Existing items are updated in place (prose is preserved) and new ones are
+/// inserted. Locked chapters and scenes, and anything new inside them, are
+/// skipped.
+fn apply_reimport(
+    conn: &Connection,
+    project_uuid: &Uuid,
+    parsed: &ParsedPlottr,
+) -> Result<SyncReport, String> {
+    let mut report = SyncReport::new();
+    let locked = || SyncAction::Skipped("locked".to_string());
 
     let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
 
@@ -153,23 +327,27 @@ pub async fn reimport_project(
     for new_chapter in &parsed.chapters {
         if let Some(source_id) = &new_chapter.source_id {
             // Try to find existing chapter by source_id
-            if let Some(existing) = db::find_chapter_by_source_id(&tx, &project_uuid, source_id)
+            if let Some(existing) = db::find_chapter_by_source_id(&tx, project_uuid, source_id)
                 .map_err(|e| e.to_string())?
             {
+                if db::is_chapter_locked(&tx, &existing.id).map_err(|e| e.to_string())? {
+                    report.record("chapter", existing.id, &existing.title, locked());
+                    continue;
+                }
                 // Update existing chapter
-                db::update_chapter(
-                    &conn,
-                    &existing.id,
+                db::update_chapter(&tx, &existing.id, &new_chapter.title, new_chapter.position)
+                    .map_err(|e| e.to_string())?;
+                report.record(
+                    "chapter",
+                    existing.id,
                     &new_chapter.title,
-                    new_chapter.position,
-                )
-                .map_err(|e| e.to_string())?;
-                summary.chapters_updated += 1;
+                    SyncAction::Updated,
+                );
             } else {
                 // Insert new chapter with project's actual UUID
                 let chapter_to_insert = Chapter {
                     id: new_chapter.id,
-                    project_id: project_uuid,
+                    project_id: *project_uuid,
                     title: new_chapter.title.clone(),
                     position: new_chapter.position,
                     source_id: new_chapter.source_id.clone(),
@@ -178,15 +356,22 @@ pub async fn reimport_project(
                     is_part: new_chapter.is_part,
                     synopsis: None,
                     planning_status: PlanningStatus::Fixed,
+                    color: None,
+                    word_target: None,
                 };
                 db::insert_chapter(&tx, &chapter_to_insert).map_err(|e| e.to_string())?;
-                summary.chapters_added += 1;
+                report.record(
+                    "chapter",
+                    new_chapter.id,
+                    &new_chapter.title,
+                    SyncAction::Created,
+                );
             }
         }
     }
 
     // Build a map from parsed chapter source_id to our DB chapter
-    let db_chapters = db::get_chapters(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    let db_chapters = db::get_chapters(&tx, project_uuid).map_err(|e| e.to_string())?;
     let chapter_source_to_db: HashMap<String, &Chapter> = db_chapters
         .iter()
         .filter_map(|c| c.source_id.as_ref().map(|sid| (sid.clone(), c)))
@@ -199,6 +384,9 @@ pub async fn reimport_project(
         .filter_map(|c| c.source_id.as_ref().map(|sid| (c.id, sid.clone())))
         .collect();
 
+    // Source ids of new scenes that were not inserted, with the reason
+    let mut skipped_scenes: HashMap<String, SyncAction> = HashMap::new();
+
     // Process scenes
     for new_scene in &parsed.scenes {
         if let Some(source_id) = &new_scene.source_id {
@@ -215,9 +403,16 @@ pub async fn reimport_project(
             if let Some(existing) = db::find_scene_by_source_id(&tx, &db_chapter.id, source_id)
                 .map_err(|e| e.to_string())?
             {
+                if existing.prose.is_some() {
+                    report.summary.prose_preserved += 1;
+                }
+                if db::is_scene_locked(&tx, &existing.id).map_err(|e| e.to_string())? {
+                    report.record("scene", existing.id, &existing.title, locked());
+                    continue;
+                }
                 // Update existing scene (preserving prose!)
                 db::update_scene(
-                    &conn,
+                    &tx,
                     &existing.id,
                     &new_scene.title,
                     new_scene.synopsis.as_deref(),
@@ -226,10 +421,10 @@ pub async fn reimport_project(
                     &new_scene.scene_status,
                 )
                 .map_err(|e| e.to_string())?;
-                summary.scenes_updated += 1;
-                if existing.prose.is_some() {
-                    summary.prose_preserved += 1;
-                }
+                report.record("scene", existing.id, &new_scene.title, SyncAction::Updated);
+            } else if db_chapter.locked {
+                report.record("scene", new_scene.id, &new_scene.title, locked());
+                skipped_scenes.insert(source_id.clone(), locked());
             } else {
                 // Insert new scene with DB chapter's UUID
                 let scene_to_insert = Scene {
@@ -246,15 +441,22 @@ pub async fn reimport_project(
                     scene_status: new_scene.scene_status,
                     planning_status: PlanningStatus::Fixed,
                     editor_mode: EditorMode::Beat,
+                    compile_position: None,
+                    include_in_compile: true,
+                    color: None,
+                    pov_character_id: None,
+                    notes: None,
+                    word_target: new_scene.word_target,
+                    custom_status: new_scene.custom_status.clone(),
                 };
                 db::insert_scene(&tx, &scene_to_insert).map_err(|e| e.to_string())?;
-                summary.scenes_added += 1;
+                report.record("scene", new_scene.id, &new_scene.title, SyncAction::Created);
             }
         }
     }
 
     // Build scene source_id to DB scene map
-    let db_scenes = db::get_all_project_scenes(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    let db_scenes = db::get_all_project_scenes(&tx, project_uuid).map_err(|e| e.to_string())?;
     let scene_source_to_db: HashMap<String, &Scene> = db_scenes
         .iter()
         .filter_map(|s| s.source_id.as_ref().map(|sid| (sid.clone(), s)))
@@ -274,21 +476,35 @@ pub async fn reimport_project(
             let parsed_scene_source_id = parsed_scene_id_to_source
                 .get(&new_beat.scene_id)
                 .ok_or_else(|| "Beat references unknown scene".to_string())?;
-            let db_scene = scene_source_to_db
-                .get(parsed_scene_source_id)
-                .ok_or_else(|| "Could not find DB scene for beat".to_string())?;
+            let beat_title = truncate_string(&new_beat.content, 50);
+            let Some(db_scene) = scene_source_to_db.get(parsed_scene_source_id) else {
+                // The beat's new scene was skipped, so the beat is too
+                let action = skipped_scenes
+                    .get(parsed_scene_source_id)
+                    .cloned()
+                    .ok_or_else(|| "Could not find DB scene for beat".to_string())?;
+                report.record("beat", new_beat.id, &beat_title, action);
+                continue;
+            };
+            let scene_locked = db::is_scene_locked(&tx, &db_scene.id).map_err(|e| e.to_string())?;
 
             // Try to find existing beat by source_id
             if let Some(existing) = db::find_beat_by_source_id(&tx, &db_scene.id, source_id)
                 .map_err(|e| e.to_string())?
             {
+                if existing.prose.is_some() {
+                    report.summary.prose_preserved += 1;
+                }
+                if scene_locked {
+                    report.record("beat", existing.id, &beat_title, locked());
+                    continue;
+                }
                 // Update existing beat (preserving prose!)
                 db::update_beat(&tx, &existing.id, &new_beat.content, new_beat.position)
                     .map_err(|e| e.to_string())?;
-                summary.beats_updated += 1;
-                if existing.prose.is_some() {
-                    summary.prose_preserved += 1;
-                }
+                report.record("beat", existing.id, &beat_title, SyncAction::Updated);
+            } else if scene_locked {
+                report.record("beat", new_beat.id, &beat_title, locked());
             } else {
                 // Insert new beat with DB scene's UUID
                 let beat_to_insert = Beat {
@@ -300,43 +516,31 @@ pub async fn reimport_project(
                     source_id: new_beat.source_id.clone(),
                 };
                 db::insert_beat(&tx, &beat_to_insert).map_err(|e| e.to_string())?;
-                summary.beats_added += 1;
+                report.record("beat", new_beat.id, &beat_title, SyncAction::Created);
             }
         }
     }
 
-    db::update_project_modified(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    db::update_project_modified(&tx, project_uuid).map_err(|e| e.to_string())?;
     tx.commit().map_err(|e| e.to_string())?;
 
-    Ok(summary)
+    Ok(report)
 }
 
-#[tauri::command]
-pub async fn get_sync_preview(
-    project_id: String,
-    state: State<'_, AppState>,
-) -> Result<SyncPreview, String> {
-    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
-    let conn = state.db.lock().map_err(|e| e.to_string())?;
-
-    // Get the existing project to find source path and type
-    let project = db::get_project(&conn, &project_uuid)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| "Project not found".to_string())?;
-
+/// Re-parse a project's source file for sync, based on its source type
+fn parse_sync_source(project: &Project) -> Result<ParsedPlottr, String> {
     let source_path = project
         .source_path
         .as_ref()
         .ok_or_else(|| "Project has no source path for sync".to_string())?;
 
-    // Re-parse the source file based on source type
     let parsed = match project.source_type {
         crate::models::SourceType::Plottr => {
             parse_plottr_file(source_path).map_err(|e| e.to_string())?
         }
         crate::models::SourceType::YWriter => {
             let yw_parsed = parse_ywriter_file(source_path).map_err(|e| e.to_string())?;
-            crate::parsers::ParsedPlottr {
+            ParsedPlottr {
                 project: yw_parsed.project,
                 chapters: yw_parsed.chapters,
                 scenes: yw_parsed.scenes,
@@ -345,6 +549,8 @@ pub async fn get_sync_preview(
                 locations: yw_parsed.locations,
                 scene_character_refs: yw_parsed.scene_character_refs,
                 scene_location_refs: yw_parsed.scene_location_refs,
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
             }
         }
         crate::models::SourceType::Scrivener => {
@@ -354,7 +560,7 @@ pub async fn get_sync_preview(
         }
         crate::models::SourceType::Longform => {
             let lf_parsed = parse_longform_index(source_path).map_err(|e| e.to_string())?;
-            crate::parsers::ParsedPlottr {
+            ParsedPlottr {
                 project: lf_parsed.project,
                 chapters: lf_parsed.chapters,
                 scenes: lf_parsed.scenes,
@@ -363,11 +569,13 @@ pub async fn get_sync_preview(
                 locations: lf_parsed.locations,
                 scene_character_refs: lf_parsed.scene_character_refs,
                 scene_location_refs: lf_parsed.scene_location_refs,
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
             }
         }
         crate::models::SourceType::Markdown => {
             let md_parsed = parse_markdown_outline(source_path).map_err(|e| e.to_string())?;
-            crate::parsers::ParsedPlottr {
+            ParsedPlottr {
                 project: md_parsed.project,
                 chapters: md_parsed.chapters,
                 scenes: md_parsed.scenes,
@@ -376,6 +584,53 @@ pub async fn get_sync_preview(
                 locations: Vec::new(),
                 scene_character_refs: Vec::new(),
                 scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
+            }
+        }
+        crate::models::SourceType::Fountain => {
+            let ft_parsed = parse_fountain_file(source_path).map_err(|e| e.to_string())?;
+            ParsedPlottr {
+                project: ft_parsed.project,
+                chapters: ft_parsed.chapters,
+                scenes: ft_parsed.scenes,
+                beats: ft_parsed.beats,
+                characters: ft_parsed.characters,
+                locations: Vec::new(),
+                scene_character_refs: ft_parsed.scene_character_refs,
+                scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
+            }
+        }
+        crate::models::SourceType::Odt => {
+            let odt_parsed = parse_odt_file(source_path).map_err(|e| e.to_string())?;
+            ParsedPlottr {
+                project: odt_parsed.project,
+                chapters: odt_parsed.chapters,
+                scenes: odt_parsed.scenes,
+                beats: odt_parsed.beats,
+                characters: Vec::new(),
+                locations: Vec::new(),
+                scene_character_refs: Vec::new(),
+                scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
+            }
+        }
+        crate::models::SourceType::Docx => {
+            let docx_parsed = parse_docx_file(source_path).map_err(|e| e.to_string())?;
+            ParsedPlottr {
+                project: docx_parsed.project,
+                chapters: docx_parsed.chapters,
+                scenes: docx_parsed.scenes,
+                beats: docx_parsed.beats,
+                characters: Vec::new(),
+                locations: Vec::new(),
+                scene_character_refs: Vec::new(),
+                scene_location_refs: Vec::new(),
+                character_attribute_names: Vec::new(),
+                location_attribute_names: Vec::new(),
             }
         }
         crate::models::SourceType::Blank => {
@@ -383,13 +638,43 @@ pub async fn get_sync_preview(
         }
     };
 
+    Ok(parsed)
+}
+
+#[tauri::command]
+pub async fn get_sync_preview(
+    project_id: String,
+    strategy: Option<SyncStrategy>,
+    state: State<'_, AppState>,
+) -> Result<SyncPreview, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    // Get the existing project to find source path and type
+    let project = db::get_project(&conn, &project_uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Project not found".to_string())?;
+
+    let parsed = parse_sync_source(&project)?;
+    build_sync_preview(&conn, &project_uuid, &parsed, strategy.unwrap_or_default())
+}
+
+/// Compare parsed source data with the database and list what a sync would change
+fn build_sync_preview(
+    conn: &Connection,
+    project_uuid: &Uuid,
+    parsed: &ParsedPlottr,
+    strategy: SyncStrategy,
+) -> Result<SyncPreview, String> {
     let mut preview = SyncPreview {
         additions: Vec::new(),
         changes: Vec::new(),
+        strategy,
+        conflicts: Vec::new(),
     };
 
     // Get existing DB data
-    let db_chapters = db::get_chapters(&conn, &project_uuid).map_err(|e| e.to_string())?;
+    let db_chapters = db::get_chapters(conn, project_uuid).map_err(|e| e.to_string())?;
     let chapter_source_to_db: HashMap<String, &Chapter> = db_chapters
         .iter()
         .filter_map(|c| c.source_id.as_ref().map(|sid| (sid.clone(), c)))
@@ -432,7 +717,7 @@ pub async fn get_sync_preview(
     }
 
     // Get all scenes for the project
-    let db_scenes = db::get_all_project_scenes(&conn, &project_uuid).map_err(|e| e.to_string())?;
+    let db_scenes = db::get_all_project_scenes(conn, project_uuid).map_err(|e| e.to_string())?;
     let scene_source_to_db: HashMap<String, &Scene> = db_scenes
         .iter()
         .filter_map(|s| s.source_id.as_ref().map(|sid| (sid.clone(), s)))
@@ -464,17 +749,9 @@ pub async fn get_sync_preview(
 
             if let Some(existing) = scene_source_to_db.get(source_id) {
                 // Skip locked scenes (or scenes in locked chapters)
-                if existing.locked {
+                if db::is_scene_locked(conn, &existing.id).map_err(|e| e.to_string())? {
                     continue;
                 }
-                // Check if parent chapter is locked
-                if let Some(ch_source_id) = parsed_chapter_id_to_source.get(&new_scene.chapter_id) {
-                    if let Some(ch) = chapter_source_to_db.get(ch_source_id) {
-                        if ch.locked {
-                            continue;
-                        }
-                    }
-                }
                 // Check for title changes
                 if existing.title != new_scene.title {
                     preview.changes.push(SyncChange {
@@ -490,6 +767,14 @@ pub async fn get_sync_preview(
                 // Check for synopsis changes
                 let existing_synopsis = existing.synopsis.clone().unwrap_or_default();
                 let new_synopsis = new_scene.synopsis.clone().unwrap_or_default();
+                if existing.title != new_scene.title || existing_synopsis != new_synopsis {
+                    preview.conflicts.push(SyncConflict {
+                        db_id: existing.id.to_string(),
+                        item_type: "scene".to_string(),
+                        item_title: existing.title.clone(),
+                        action: strategy.conflict_action(),
+                    });
+                }
                 if existing_synopsis != new_synopsis {
                     preview.changes.push(SyncChange {
                         id: format!("scene-synopsis-{}", existing.id),
@@ -513,8 +798,35 @@ pub async fn get_sync_preview(
         }
     }
 
+    // Source-linked scenes that were removed from the source can be archived
+    let parsed_scene_sources: HashSet<&String> = parsed
+        .scenes
+        .iter()
+        .filter_map(|s| s.source_id.as_ref())
+        .collect();
+    for existing in &db_scenes {
+        let Some(source_id) = &existing.source_id else {
+            continue;
+        };
+        if existing.archived || parsed_scene_sources.contains(source_id) {
+            continue;
+        }
+        if db::is_scene_locked(conn, &existing.id).map_err(|e| e.to_string())? {
+            continue;
+        }
+        preview.changes.push(SyncChange {
+            id: format!("scene-missing-{}", existing.id),
+            item_type: "scene".to_string(),
+            field: "missing".to_string(),
+            item_title: existing.title.clone(),
+            current_value: existing.title.clone(),
+            new_value: String::new(),
+            db_id: existing.id.to_string(),
+        });
+    }
+
     // Get all beats for the project
-    let db_beats = db::get_all_project_beats(&conn, &project_uuid).map_err(|e| e.to_string())?;
+    let db_beats = db::get_all_project_beats(conn, project_uuid).map_err(|e| e.to_string())?;
     let beat_source_to_db: HashMap<String, &Beat> = db_beats
         .iter()
         .filter_map(|b| b.source_id.as_ref().map(|sid| (sid.clone(), b)))
@@ -541,10 +853,10 @@ pub async fn get_sync_preview(
                         .map(|sc| sc.title.clone())
                 });
 
-            // Check if parent scene is locked
+            // Check if parent scene (or its chapter) is locked
             if let Some(sc_source_id) = parsed_scene_id_to_source.get(&new_beat.scene_id) {
                 if let Some(sc) = scene_source_to_db.get(sc_source_id) {
-                    if sc.locked {
+                    if db::is_scene_locked(conn, &sc.id).map_err(|e| e.to_string())? {
                         continue;
                     }
                 }
@@ -552,7 +864,13 @@ pub async fn get_sync_preview(
 
             if let Some(existing) = beat_source_to_db.get(source_id) {
                 // Check for content changes
-                if existing.content != new_beat.content {
+                if beat_content_changed(&existing.content, &new_beat.content) {
+                    preview.conflicts.push(SyncConflict {
+                        db_id: existing.id.to_string(),
+                        item_type: "beat".to_string(),
+                        item_title: truncate_string(&existing.content, 50),
+                        action: strategy.conflict_action(),
+                    });
                     preview.changes.push(SyncChange {
                         id: format!("beat-content-{}", existing.id),
                         item_type: "beat".to_string(),
@@ -578,6 +896,17 @@ pub async fn get_sync_preview(
     Ok(preview)
 }
 
+/// Whether a beat's source content differs from the stored content
+///
+/// Markdown imports used to keep `*`/`_` emphasis markers in beat content and
+/// now strip them (the emphasis lives in the prose instead). Both sides are
+/// compared with matched markers removed, so re-syncing a project imported
+/// before that change does not flag every emphasized beat. A source edit that
+/// only adds or removes emphasis is not reported as a content change.
+fn beat_content_changed(existing: &str, new: &str) -> bool {
+    existing != new && markdown_inline_to_plain(existing) != markdown_inline_to_plain(new)
+}
+
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -602,6 +931,11 @@ fn ensure_markdown_source_ids(conn: &Connection, project_id: &Uuid) -> Result<()
     let scenes = db::get_all_project_scenes(conn, project_id).map_err(|e| e.to_string())?;
     let mut scene_positions: HashMap<Uuid, (i32, i32)> = HashMap::new();
     for scene in &scenes {
+        // Archived copies (e.g. the local side of a KeepBoth sync) stay detached,
+        // and so do their beats
+        if scene.archived {
+            continue;
+        }
         if let Some(chapter_position) = chapter_positions.get(&scene.chapter_id) {
             if scene.source_id.is_none() {
                 let source_id = markdown_scene_source_id(*chapter_position, scene.position);
@@ -648,8 +982,9 @@ pub async fn apply_sync(
     project_id: String,
     accepted_change_ids: Vec<String>,
     accepted_addition_ids: Vec<String>,
+    strategy: Option<SyncStrategy>,
     state: State<'_, AppState>,
-) -> Result<ReimportSummary, String> {
+) -> Result<SyncReport, String> {
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
     let conn = state.db.lock().map_err(|e| e.to_string())?;
 
@@ -658,82 +993,46 @@ pub async fn apply_sync(
         .map_err(|e| e.to_string())?
         .ok_or_else(|| "Project not found".to_string())?;
 
-    let source_path = project
-        .source_path
-        .as_ref()
-        .ok_or_else(|| "Project has no source path for sync".to_string())?;
-
-    // Re-parse the source file based on source type
-    let parsed = match project.source_type {
-        crate::models::SourceType::Plottr => {
-            parse_plottr_file(source_path).map_err(|e| e.to_string())?
-        }
-        crate::models::SourceType::YWriter => {
-            let yw_parsed = parse_ywriter_file(source_path).map_err(|e| e.to_string())?;
-            crate::parsers::ParsedPlottr {
-                project: yw_parsed.project,
-                chapters: yw_parsed.chapters,
-                scenes: yw_parsed.scenes,
-                beats: yw_parsed.beats,
-                characters: yw_parsed.characters,
-                locations: yw_parsed.locations,
-                scene_character_refs: yw_parsed.scene_character_refs,
-                scene_location_refs: yw_parsed.scene_location_refs,
-            }
-        }
-        crate::models::SourceType::Scrivener => {
-            return Err(
-                "Scrivener import has been deprecated. This project cannot be synced.".to_string(),
-            );
-        }
-        crate::models::SourceType::Longform => {
-            let lf_parsed = parse_longform_index(source_path).map_err(|e| e.to_string())?;
-            crate::parsers::ParsedPlottr {
-                project: lf_parsed.project,
-                chapters: lf_parsed.chapters,
-                scenes: lf_parsed.scenes,
-                beats: lf_parsed.beats,
-                characters: lf_parsed.characters,
-                locations: lf_parsed.locations,
-                scene_character_refs: lf_parsed.scene_character_refs,
-                scene_location_refs: lf_parsed.scene_location_refs,
-            }
-        }
-        crate::models::SourceType::Markdown => {
-            let md_parsed = parse_markdown_outline(source_path).map_err(|e| e.to_string())?;
-            crate::parsers::ParsedPlottr {
-                project: md_parsed.project,
-                chapters: md_parsed.chapters,
-                scenes: md_parsed.scenes,
-                beats: md_parsed.beats,
-                characters: Vec::new(),
-                locations: Vec::new(),
-                scene_character_refs: Vec::new(),
-                scene_location_refs: Vec::new(),
-            }
-        }
-        crate::models::SourceType::Blank => {
-            return Err("Blank projects have no source to reimport".to_string());
-        }
-    };
+    let parsed = parse_sync_source(&project)?;
 
     let accepted_set: HashSet<String> = accepted_change_ids.into_iter().collect();
     let accepted_additions_set: HashSet<String> = accepted_addition_ids.into_iter().collect();
 
-    let mut summary = ReimportSummary {
-        chapters_added: 0,
-        chapters_updated: 0,
-        scenes_added: 0,
-        scenes_updated: 0,
-        beats_added: 0,
-        beats_updated: 0,
-        prose_preserved: 0,
-    };
+    apply_parsed_sync(
+        &conn,
+        &project_uuid,
+        &parsed,
+        &accepted_set,
+        &accepted_additions_set,
+        strategy.unwrap_or_default(),
+    )
+}
+
+/// Apply accepted changes and additions from parsed source data in one transaction
+///
+/// `strategy` decides what happens to existing scenes and beats whose source
+/// version changed. Locked chapters and scenes (and everything inside them)
+/// are never modified; the returned report lists them as skipped.
+fn apply_parsed_sync(
+    conn: &Connection,
+    project_uuid: &Uuid,
+    parsed: &ParsedPlottr,
+    accepted_set: &HashSet<String>,
+    accepted_additions_set: &HashSet<String>,
+    strategy: SyncStrategy,
+) -> Result<SyncReport, String> {
+    let mut report = SyncReport::new();
+    let locked = || SyncAction::Skipped("locked".to_string());
+    let not_accepted = || SyncAction::Skipped("not accepted".to_string());
 
     let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
 
+    // Source ids of new chapters and scenes that were not inserted, with the reason
+    let mut skipped_chapters: HashMap<String, SyncAction> = HashMap::new();
+    let mut skipped_scenes: HashMap<String, SyncAction> = HashMap::new();
+
     // Get existing DB data
-    let db_chapters = db::get_chapters(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    let db_chapters = db::get_chapters(&tx, project_uuid).map_err(|e| e.to_string())?;
     let chapter_source_to_db: HashMap<String, Chapter> = db_chapters
         .into_iter()
         .filter_map(|c| c.source_id.clone().map(|sid| (sid, c)))
@@ -746,14 +1045,18 @@ pub async fn apply_sync(
                 // Check if user accepted the title change
                 let change_id = format!("chapter-title-{}", existing.id);
                 if accepted_set.contains(&change_id) && existing.title != new_chapter.title {
-                    db::update_chapter(
-                        &conn,
-                        &existing.id,
+                    if db::is_chapter_locked(&tx, &existing.id).map_err(|e| e.to_string())? {
+                        report.record("chapter", existing.id, &existing.title, locked());
+                        continue;
+                    }
+                    db::update_chapter(&tx, &existing.id, &new_chapter.title, new_chapter.position)
+                        .map_err(|e| e.to_string())?;
+                    report.record(
+                        "chapter",
+                        existing.id,
                         &new_chapter.title,
-                        new_chapter.position,
-                    )
-                    .map_err(|e| e.to_string())?;
-                    summary.chapters_updated += 1;
+                        SyncAction::Updated,
+                    );
                 }
             } else {
                 // Check if user accepted this addition
@@ -761,7 +1064,7 @@ pub async fn apply_sync(
                 if accepted_additions_set.contains(&addition_id) {
                     let chapter_to_insert = Chapter {
                         id: new_chapter.id,
-                        project_id: project_uuid,
+                        project_id: *project_uuid,
                         title: new_chapter.title.clone(),
                         position: new_chapter.position,
                         source_id: new_chapter.source_id.clone(),
@@ -770,16 +1073,31 @@ pub async fn apply_sync(
                         is_part: new_chapter.is_part,
                         synopsis: None,
                         planning_status: PlanningStatus::Fixed,
+                        color: None,
+                        word_target: None,
                     };
                     db::insert_chapter(&tx, &chapter_to_insert).map_err(|e| e.to_string())?;
-                    summary.chapters_added += 1;
+                    report.record(
+                        "chapter",
+                        new_chapter.id,
+                        &new_chapter.title,
+                        SyncAction::Created,
+                    );
+                } else {
+                    report.record(
+                        "chapter",
+                        new_chapter.id,
+                        &new_chapter.title,
+                        not_accepted(),
+                    );
+                    skipped_chapters.insert(source_id.clone(), not_accepted());
                 }
             }
         }
     }
 
     // Refresh chapter map after inserts
-    let db_chapters = db::get_chapters(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    let db_chapters = db::get_chapters(&tx, project_uuid).map_err(|e| e.to_string())?;
     let chapter_source_to_db: HashMap<String, &Chapter> = db_chapters
         .iter()
         .filter_map(|c| c.source_id.as_ref().map(|sid| (sid.clone(), c)))
@@ -793,12 +1111,15 @@ pub async fn apply_sync(
         .collect();
 
     // Get existing scenes
-    let db_scenes = db::get_all_project_scenes(&tx, &project_uuid).map_err(|e| e.to_string())?;
-    let scene_source_to_db: HashMap<String, Scene> = db_scenes
-        .into_iter()
-        .filter_map(|s| s.source_id.clone().map(|sid| (sid, s)))
+    let db_scenes = db::get_all_project_scenes(&tx, project_uuid).map_err(|e| e.to_string())?;
+    let scene_source_to_db: HashMap<String, &Scene> = db_scenes
+        .iter()
+        .filter_map(|s| s.source_id.as_ref().map(|sid| (sid.clone(), s)))
         .collect();
 
+    // Source ids of scenes re-added under KeepBoth; their beats are added fresh
+    let mut replaced_scene_sources: HashSet<String> = HashSet::new();
+
     // Process scenes
     for new_scene in &parsed.scenes {
         if let Some(source_id) = &new_scene.source_id {
@@ -807,74 +1128,143 @@ pub async fn apply_sync(
                 parsed_chapter_id_to_source
                     .get(&new_scene.chapter_id)
                     .ok_or_else(|| "Scene references unknown chapter".to_string())?;
-            let db_chapter = chapter_source_to_db
-                .get(parsed_chapter_source_id)
-                .ok_or_else(|| "Could not find DB chapter for scene".to_string())?;
+            let Some(db_chapter) = chapter_source_to_db.get(parsed_chapter_source_id) else {
+                // The scene's new chapter was skipped, so the scene and its beats are too
+                let action = skipped_chapters
+                    .get(parsed_chapter_source_id)
+                    .cloned()
+                    .ok_or_else(|| "Could not find DB chapter for scene".to_string())?;
+                report.record("scene", new_scene.id, &new_scene.title, action.clone());
+                skipped_scenes.insert(source_id.clone(), action);
+                continue;
+            };
 
             if let Some(existing) = scene_source_to_db.get(source_id) {
                 // Check which changes user accepted
-                let mut new_title = existing.title.clone();
-                let mut new_synopsis = existing.synopsis.clone();
-                let mut updated = false;
-
                 let title_change_id = format!("scene-title-{}", existing.id);
-                if accepted_set.contains(&title_change_id) && existing.title != new_scene.title {
-                    new_title = new_scene.title.clone();
-                    updated = true;
-                }
+                let title_accepted =
+                    accepted_set.contains(&title_change_id) && existing.title != new_scene.title;
 
                 let synopsis_change_id = format!("scene-synopsis-{}", existing.id);
-                if accepted_set.contains(&synopsis_change_id)
-                    && existing.synopsis != new_scene.synopsis
-                {
-                    new_synopsis = new_scene.synopsis.clone();
-                    updated = true;
-                }
+                let synopsis_accepted = accepted_set.contains(&synopsis_change_id)
+                    && existing.synopsis != new_scene.synopsis;
 
-                if updated {
-                    db::update_scene(
-                        &conn,
-                        &existing.id,
-                        &new_title,
-                        new_synopsis.as_deref(),
-                        new_scene.position,
-                        &new_scene.scene_type,
-                        &new_scene.scene_status,
-                    )
-                    .map_err(|e| e.to_string())?;
-                    summary.scenes_updated += 1;
+                let would_change = match strategy {
+                    SyncStrategy::PreferSource | SyncStrategy::KeepBoth => {
+                        title_accepted || synopsis_accepted
+                    }
+                    SyncStrategy::PreferLocal => existing.position != new_scene.position,
+                };
+
+                // A scene is locked on its own or through its chapter
+                let scene_locked = would_change
+                    && db::is_scene_locked(&tx, &existing.id).map_err(|e| e.to_string())?;
+
+                if scene_locked {
+                    report.record("scene", existing.id, &existing.title, locked());
+                } else if would_change {
+                    match strategy {
+                        SyncStrategy::PreferSource => {
+                            let new_title = if title_accepted {
+                                &new_scene.title
+                            } else {
+                                &existing.title
+                            };
+                            let new_synopsis = if synopsis_accepted {
+                                &new_scene.synopsis
+                            } else {
+                                &existing.synopsis
+                            };
+                            db::update_scene(
+                                &tx,
+                                &existing.id,
+                                new_title,
+                                new_synopsis.as_deref(),
+                                new_scene.position,
+                                &new_scene.scene_type,
+                                &new_scene.scene_status,
+                            )
+                            .map_err(|e| e.to_string())?;
+                            report.record("scene", existing.id, new_title, SyncAction::Updated);
+                        }
+                        SyncStrategy::PreferLocal => {
+                            db::update_scene(
+                                &tx,
+                                &existing.id,
+                                &existing.title,
+                                existing.synopsis.as_deref(),
+                                new_scene.position,
+                                &existing.scene_type,
+                                &existing.scene_status,
+                            )
+                            .map_err(|e| e.to_string())?;
+                            report.record(
+                                "scene",
+                                existing.id,
+                                &existing.title,
+                                SyncAction::Updated,
+                            );
+                        }
+                        SyncStrategy::KeepBoth => {
+                            // Archive the local version; the source version takes over its
+                            // source_id
+                            db::archive_scene(&tx, &existing.id).map_err(|e| e.to_string())?;
+                            db::detach_scene_from_source(&tx, &existing.id)
+                                .map_err(|e| e.to_string())?;
+                            db::insert_scene(&tx, &source_scene(new_scene, db_chapter.id))
+                                .map_err(|e| e.to_string())?;
+                            replaced_scene_sources.insert(source_id.clone());
+                            report.record(
+                                "scene",
+                                new_scene.id,
+                                &new_scene.title,
+                                SyncAction::Created,
+                            );
+                        }
+                    }
                 }
                 if existing.prose.is_some() {
-                    summary.prose_preserved += 1;
+                    report.summary.prose_preserved += 1;
                 }
             } else {
                 // Check if user accepted this addition
                 let addition_id = format!("scene-{}", source_id);
-                if accepted_additions_set.contains(&addition_id) {
-                    let scene_to_insert = Scene {
-                        id: new_scene.id,
-                        chapter_id: db_chapter.id,
-                        title: new_scene.title.clone(),
-                        synopsis: new_scene.synopsis.clone(),
-                        prose: None,
-                        position: new_scene.position,
-                        source_id: new_scene.source_id.clone(),
-                        archived: false,
-                        locked: false,
-                        scene_type: new_scene.scene_type,
-                        scene_status: new_scene.scene_status,
-                        planning_status: PlanningStatus::Fixed,
-                        editor_mode: EditorMode::Beat,
-                    };
-                    db::insert_scene(&tx, &scene_to_insert).map_err(|e| e.to_string())?;
-                    summary.scenes_added += 1;
+                if !accepted_additions_set.contains(&addition_id) {
+                    report.record("scene", new_scene.id, &new_scene.title, not_accepted());
+                    skipped_scenes.insert(source_id.clone(), not_accepted());
+                } else if db_chapter.locked {
+                    report.record("scene", new_scene.id, &new_scene.title, locked());
+                    skipped_scenes.insert(source_id.clone(), locked());
+                } else {
+                    db::insert_scene(&tx, &source_scene(new_scene, db_chapter.id))
+                        .map_err(|e| e.to_string())?;
+                    report.record("scene", new_scene.id, &new_scene.title, SyncAction::Created);
                 }
             }
         }
     }
 
+    // Archive accepted scenes that were removed from the source
+    for existing in &db_scenes {
+        let change_id = format!("scene-missing-{}", existing.id);
+        if !accepted_set.contains(&change_id) || existing.archived {
+            continue;
+        }
+        if db::is_scene_locked(&tx, &existing.id).map_err(|e| e.to_string())? {
+            report.record("scene", existing.id, &existing.title, locked());
+        } else {
+            db::archive_scene(&tx, &existing.id).map_err(|e| e.to_string())?;
+            report.record(
+                "scene",
+                existing.id,
+                &existing.title,
+                SyncAction::ArchivedMissing,
+            );
+        }
+    }
+
     // Refresh scene map after inserts
-    let db_scenes = db::get_all_project_scenes(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    let db_scenes = db::get_all_project_scenes(&tx, project_uuid).map_err(|e| e.to_string())?;
     let scene_source_to_db: HashMap<String, &Scene> = db_scenes
         .iter()
         .filter_map(|s| s.source_id.as_ref().map(|sid| (sid.clone(), s)))
@@ -888,7 +1278,7 @@ pub async fn apply_sync(
         .collect();
 
     // Get existing beats
-    let db_beats = db::get_all_project_beats(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    let db_beats = db::get_all_project_beats(&tx, project_uuid).map_err(|e| e.to_string())?;
     let beat_source_to_db: HashMap<String, Beat> = db_beats
         .into_iter()
         .filter_map(|b| b.source_id.clone().map(|sid| (sid, b)))
@@ -901,49 +1291,134 @@ pub async fn apply_sync(
             let parsed_scene_source_id = parsed_scene_id_to_source
                 .get(&new_beat.scene_id)
                 .ok_or_else(|| "Beat references unknown scene".to_string())?;
-            let db_scene = scene_source_to_db
-                .get(parsed_scene_source_id)
-                .ok_or_else(|| "Could not find DB scene for beat".to_string())?;
-
-            if let Some(existing) = beat_source_to_db.get(source_id) {
+            let beat_title = truncate_string(&new_beat.content, 50);
+            let Some(db_scene) = scene_source_to_db.get(parsed_scene_source_id) else {
+                // The beat's new scene was skipped, so the beat is too
+                let action = skipped_scenes
+                    .get(parsed_scene_source_id)
+                    .cloned()
+                    .ok_or_else(|| "Could not find DB scene for beat".to_string())?;
+                report.record("beat", new_beat.id, &beat_title, action);
+                continue;
+            };
+            let scene_locked = db::is_scene_locked(&tx, &db_scene.id).map_err(|e| e.to_string())?;
+
+            if replaced_scene_sources.contains(parsed_scene_source_id) {
+                db::insert_beat(&tx, &source_beat(new_beat, db_scene.id))
+                    .map_err(|e| e.to_string())?;
+                report.record("beat", new_beat.id, &beat_title, SyncAction::Created);
+            } else if let Some(existing) = beat_source_to_db.get(source_id) {
                 // Check if user accepted the content change
                 let change_id = format!("beat-content-{}", existing.id);
-                if accepted_set.contains(&change_id) && existing.content != new_beat.content {
-                    db::update_beat(&tx, &existing.id, &new_beat.content, new_beat.position)
-                        .map_err(|e| e.to_string())?;
-                    summary.beats_updated += 1;
+                let content_accepted = accepted_set.contains(&change_id)
+                    && beat_content_changed(&existing.content, &new_beat.content);
+
+                let would_change = match strategy {
+                    SyncStrategy::PreferSource | SyncStrategy::KeepBoth => content_accepted,
+                    SyncStrategy::PreferLocal => existing.position != new_beat.position,
+                };
+
+                if would_change && scene_locked {
+                    report.record("beat", existing.id, &beat_title, locked());
+                } else if would_change {
+                    match strategy {
+                        SyncStrategy::PreferSource => {
+                            db::update_beat(
+                                &tx,
+                                &existing.id,
+                                &new_beat.content,
+                                new_beat.position,
+                            )
+                            .map_err(|e| e.to_string())?;
+                            report.record("beat", existing.id, &beat_title, SyncAction::Updated);
+                        }
+                        SyncStrategy::PreferLocal => {
+                            db::update_beat(
+                                &tx,
+                                &existing.id,
+                                &existing.content,
+                                new_beat.position,
+                            )
+                            .map_err(|e| e.to_string())?;
+                            report.record("beat", existing.id, &beat_title, SyncAction::Updated);
+                        }
+                        SyncStrategy::KeepBoth => {
+                            // Beats have no archive flag, so the local beat stays alongside
+                            db::clear_beat_source_id(&tx, &existing.id)
+                                .map_err(|e| e.to_string())?;
+                            db::insert_beat(&tx, &source_beat(new_beat, db_scene.id))
+                                .map_err(|e| e.to_string())?;
+                            report.record("beat", new_beat.id, &beat_title, SyncAction::Created);
+                        }
+                    }
                 }
                 if existing.prose.is_some() {
-                    summary.prose_preserved += 1;
+                    report.summary.prose_preserved += 1;
                 }
             } else {
                 // Check if user accepted this addition
                 let addition_id = format!("beat-{}", source_id);
-                if accepted_additions_set.contains(&addition_id) {
-                    let beat_to_insert = Beat {
-                        id: new_beat.id,
-                        scene_id: db_scene.id,
-                        content: new_beat.content.clone(),
-                        prose: None,
-                        position: new_beat.position,
-                        source_id: new_beat.source_id.clone(),
-                    };
-                    db::insert_beat(&tx, &beat_to_insert).map_err(|e| e.to_string())?;
-                    summary.beats_added += 1;
+                if !accepted_additions_set.contains(&addition_id) {
+                    report.record("beat", new_beat.id, &beat_title, not_accepted());
+                } else if scene_locked {
+                    report.record("beat", new_beat.id, &beat_title, locked());
+                } else {
+                    db::insert_beat(&tx, &source_beat(new_beat, db_scene.id))
+                        .map_err(|e| e.to_string())?;
+                    report.record("beat", new_beat.id, &beat_title, SyncAction::Created);
                 }
             }
         }
     }
 
-    db::update_project_modified(&tx, &project_uuid).map_err(|e| e.to_string())?;
+    db::update_project_modified(&tx, project_uuid).map_err(|e| e.to_string())?;
     tx.commit().map_err(|e| e.to_string())?;
 
-    Ok(summary)
+    Ok(report)
+}
+
+/// A new database scene built from a parsed source scene
+fn source_scene(parsed: &Scene, chapter_id: Uuid) -> Scene {
+    Scene {
+        id: parsed.id,
+        chapter_id,
+        title: parsed.title.clone(),
+        synopsis: parsed.synopsis.clone(),
+        prose: None,
+        position: parsed.position,
+        source_id: parsed.source_id.clone(),
+        archived: false,
+        locked: false,
+        scene_type: parsed.scene_type,
+        scene_status: parsed.scene_status,
+        planning_status: PlanningStatus::Fixed,
+        editor_mode: EditorMode::Beat,
+        compile_position: None,
+        include_in_compile: true,
+        color: None,
+        pov_character_id: None,
+        notes: None,
+        word_target: parsed.word_target,
+        custom_status: parsed.custom_status.clone(),
+    }
+}
+
+/// A new database beat built from a parsed source beat
+fn source_beat(parsed: &Beat, scene_id: Uuid) -> Beat {
+    Beat {
+        id: parsed.id,
+        scene_id,
+        content: parsed.content.clone(),
+        prose: None,
+        position: parsed.position,
+        source_id: parsed.source_id.clone(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::truncate_string;
+    use super::*;
+    use crate::models::{Project, SourceType};
 
     #[test]
     fn test_truncate_string_shorter_than_limit() {
@@ -962,4 +1437,441 @@ mod tests {
         let input = "This is a longer string";
         assert_eq!(truncate_string(input, 4), "This...");
     }
+
+    /// A synced project with one scene the author has written prose in, plus a
+    /// parsed source where that scene was renamed and moved
+    fn renamed_scene_fixture() -> (Connection, Project, Scene, Beat, ParsedPlottr) {
+        let conn = Connection::open_in_memory().unwrap();
+        db::initialize_schema(&conn).unwrap();
+
+        let project = Project::new("Sync".to_string(), SourceType::Plottr, None);
+        db::insert_project(&conn, &project).unwrap();
+        let mut chapter = Chapter::new(project.id, "One".to_string(), 0);
+        chapter.source_id = Some("ch-1".to_string());
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let mut scene = Scene::new(chapter.id, "Old Title".to_string(), None, 0);
+        scene.source_id = Some("sc-1".to_string());
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut beat = Beat::new(scene.id, "The heist".to_string(), 0);
+        beat.source_id = Some("b-1".to_string());
+        beat.prose = Some("<p>Local prose</p>".to_string());
+        db::insert_beat(&conn, &beat).unwrap();
+
+        let mut parsed_chapter = Chapter::new(Uuid::new_v4(), "One".to_string(), 0);
+        parsed_chapter.source_id = Some("ch-1".to_string());
+        let mut parsed_scene = Scene::new(parsed_chapter.id, "New Title".to_string(), None, 2);
+        parsed_scene.source_id = Some("sc-1".to_string());
+        let mut parsed_beat = Beat::new(parsed_scene.id, "The heist".to_string(), 0);
+        parsed_beat.source_id = Some("b-1".to_string());
+        let parsed = ParsedPlottr {
+            project: project.clone(),
+            chapters: vec![parsed_chapter],
+            scenes: vec![parsed_scene],
+            beats: vec![parsed_beat],
+            characters: Vec::new(),
+            locations: Vec::new(),
+            scene_character_refs: Vec::new(),
+            scene_location_refs: Vec::new(),
+            character_attribute_names: Vec::new(),
+            location_attribute_names: Vec::new(),
+        };
+
+        (conn, project, scene, beat, parsed)
+    }
+
+    fn apply_with(strategy: SyncStrategy) -> (Connection, Project, Scene, Beat, SyncReport) {
+        let (conn, project, scene, beat, parsed) = renamed_scene_fixture();
+        let accepted: HashSet<String> = [format!("scene-title-{}", scene.id)].into();
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &accepted,
+            &HashSet::new(),
+            strategy,
+        )
+        .unwrap();
+        (conn, project, scene, beat, report)
+    }
+
+    #[test]
+    fn test_resync_ignores_emphasis_markers_in_stored_beat_content() {
+        let (conn, project, scene, beat, mut parsed) = renamed_scene_fixture();
+        // Imported before emphasis markers were stripped from beat content
+        db::update_beat(&conn, &beat.id, "The **heist** goes _wrong_", 0).unwrap();
+        parsed.scenes[0].title = scene.title.clone();
+        parsed.scenes[0].position = scene.position;
+        parsed.beats[0].content = markdown_inline_to_plain("The **heist** goes _wrong_");
+        assert_eq!(parsed.beats[0].content, "The heist goes wrong");
+
+        let preview =
+            build_sync_preview(&conn, &project.id, &parsed, SyncStrategy::PreferSource).unwrap();
+        assert!(preview.changes.is_empty());
+        assert!(preview.conflicts.is_empty());
+        assert!(preview.additions.is_empty());
+
+        let accepted: HashSet<String> = [format!("beat-content-{}", beat.id)].into();
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &accepted,
+            &HashSet::new(),
+            SyncStrategy::PreferSource,
+        )
+        .unwrap();
+        assert!(!report
+            .items
+            .iter()
+            .any(|item| item.action == SyncAction::Updated));
+
+        // A real wording change is still reported
+        parsed.beats[0].content = "The heist goes right".to_string();
+        let preview =
+            build_sync_preview(&conn, &project.id, &parsed, SyncStrategy::PreferSource).unwrap();
+        assert_eq!(preview.changes.len(), 1);
+        assert_eq!(preview.changes[0].field, "content");
+    }
+
+    #[test]
+    fn test_sync_preview_reports_conflict_action() {
+        let (conn, project, scene, _, parsed) = renamed_scene_fixture();
+
+        let preview =
+            build_sync_preview(&conn, &project.id, &parsed, SyncStrategy::KeepBoth).unwrap();
+        assert_eq!(preview.strategy, SyncStrategy::KeepBoth);
+        assert_eq!(preview.changes.len(), 1);
+        assert_eq!(preview.conflicts.len(), 1);
+        assert_eq!(preview.conflicts[0].db_id, scene.id.to_string());
+        assert_eq!(
+            preview.conflicts[0].action,
+            SyncConflictAction::ArchiveAndAdd
+        );
+
+        let preview =
+            build_sync_preview(&conn, &project.id, &parsed, SyncStrategy::PreferLocal).unwrap();
+        assert_eq!(preview.conflicts[0].action, SyncConflictAction::KeepLocal);
+    }
+
+    #[test]
+    fn test_apply_sync_prefer_source_renames_and_keeps_prose() {
+        let (conn, _, scene, beat, report) = apply_with(SyncStrategy::PreferSource);
+
+        let updated = db::get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(updated.title, "New Title");
+        assert_eq!(report.summary.scenes_updated, 1);
+        let beats = db::get_beats(&conn, &scene.id).unwrap();
+        assert_eq!(beats.len(), 1);
+        assert_eq!(beats[0].prose, beat.prose);
+    }
+
+    #[test]
+    fn test_apply_sync_prefer_local_only_moves_scene() {
+        let (conn, _, scene, beat, report) = apply_with(SyncStrategy::PreferLocal);
+
+        let updated = db::get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(updated.title, "Old Title");
+        assert_eq!(updated.position, 2);
+        assert_eq!(report.summary.scenes_updated, 1);
+        assert_eq!(
+            db::get_beats(&conn, &scene.id).unwrap()[0].prose,
+            beat.prose
+        );
+    }
+
+    #[test]
+    fn test_reordered_beats_still_match_on_sync() {
+        let (conn, project, scene, beat, mut parsed) = renamed_scene_fixture();
+        let mut second = Beat::new(scene.id, "The getaway".to_string(), 1);
+        second.source_id = Some("b-2".to_string());
+        second.prose = Some("<p>More prose</p>".to_string());
+        db::insert_beat(&conn, &second).unwrap();
+        let mut parsed_second = Beat::new(parsed.scenes[0].id, "The getaway".to_string(), 1);
+        parsed_second.source_id = Some("b-2".to_string());
+        parsed.beats.push(parsed_second);
+
+        db::reorder_beats(&conn, &scene.id, &[second.id, beat.id]).unwrap();
+        let reordered = db::get_beats(&conn, &scene.id).unwrap();
+        let ids: Vec<(Uuid, Option<&str>)> = reordered
+            .iter()
+            .map(|b| (b.id, b.source_id.as_deref()))
+            .collect();
+        assert_eq!(ids, vec![(second.id, Some("b-2")), (beat.id, Some("b-1"))]);
+        assert_eq!(reordered[1].prose, beat.prose);
+
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &HashSet::new(),
+            &HashSet::new(),
+            SyncStrategy::PreferSource,
+        )
+        .unwrap();
+
+        // Both beats matched by source_id: nothing re-added, local order and prose kept
+        assert_eq!(report.summary.beats_added, 0);
+        assert_eq!(report.summary.prose_preserved, 2);
+        let synced = db::get_beats(&conn, &scene.id).unwrap();
+        let ids: Vec<Uuid> = synced.iter().map(|b| b.id).collect();
+        assert_eq!(ids, vec![second.id, beat.id]);
+        assert_eq!(synced[0].prose.as_deref(), Some("<p>More prose</p>"));
+    }
+
+    #[test]
+    fn test_apply_sync_keep_both_archives_local_scene() {
+        let (conn, project, scene, beat, report) = apply_with(SyncStrategy::KeepBoth);
+
+        let local = db::get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert!(local.archived);
+        assert_eq!(local.title, "Old Title");
+        assert_eq!(local.source_id, None);
+        let local_beats = db::get_beats(&conn, &scene.id).unwrap();
+        assert_eq!(local_beats[0].prose, beat.prose);
+        assert_eq!(local_beats[0].source_id, None);
+
+        let scenes = db::get_all_project_scenes(&conn, &project.id).unwrap();
+        let added = scenes.iter().find(|s| s.id != scene.id).unwrap();
+        assert_eq!(added.title, "New Title");
+        assert_eq!(added.source_id.as_deref(), Some("sc-1"));
+        assert!(!added.archived);
+        let added_beats = db::get_beats(&conn, &added.id).unwrap();
+        assert_eq!(added_beats.len(), 1);
+        assert_eq!(added_beats[0].prose, None);
+        assert_eq!(report.summary.scenes_added, 1);
+        assert_eq!(report.summary.beats_added, 1);
+
+        // Backfilling Markdown source ids on reimport leaves the archived copy detached
+        ensure_markdown_source_ids(&conn, &project.id).unwrap();
+        let local = db::get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(local.source_id, None);
+        let local_beats = db::get_beats(&conn, &scene.id).unwrap();
+        assert_eq!(local_beats[0].source_id, None);
+    }
+
+    #[test]
+    fn test_apply_sync_reports_new_updated_locked_and_missing() {
+        let (conn, project, scene, _, mut parsed) = renamed_scene_fixture();
+        let chapter_id = scene.chapter_id;
+        let parsed_chapter_id = parsed.chapters[0].id;
+
+        // A locked scene whose source title changed
+        let mut finished = Scene::new(chapter_id, "Finished".to_string(), None, 1);
+        finished.source_id = Some("sc-2".to_string());
+        db::insert_scene(&conn, &finished).unwrap();
+        db::lock_scene(&conn, &finished.id).unwrap();
+        let mut parsed_finished = Scene::new(parsed_chapter_id, "Retitled".to_string(), None, 1);
+        parsed_finished.source_id = Some("sc-2".to_string());
+        parsed.scenes.push(parsed_finished);
+
+        // A scene that no longer exists in the source
+        let mut cut = Scene::new(chapter_id, "Cut Scene".to_string(), None, 3);
+        cut.source_id = Some("sc-4".to_string());
+        db::insert_scene(&conn, &cut).unwrap();
+
+        // A brand new source scene that is accepted
+        let mut fresh = Scene::new(parsed_chapter_id, "Fresh".to_string(), None, 4);
+        fresh.source_id = Some("sc-3".to_string());
+        parsed.scenes.push(fresh.clone());
+
+        // A new scene with a beat, and a new chapter with a scene, both declined
+        let mut declined = Scene::new(parsed_chapter_id, "Declined".to_string(), None, 5);
+        declined.source_id = Some("sc-5".to_string());
+        let mut declined_beat = Beat::new(declined.id, "Never added".to_string(), 0);
+        declined_beat.source_id = Some("b-5".to_string());
+        let mut later_chapter = Chapter::new(project.id, "Two".to_string(), 1);
+        later_chapter.source_id = Some("ch-2".to_string());
+        let mut later_scene = Scene::new(later_chapter.id, "Later".to_string(), None, 0);
+        later_scene.source_id = Some("sc-6".to_string());
+        let mut later_beat = Beat::new(later_scene.id, "Much later".to_string(), 0);
+        later_beat.source_id = Some("b-6".to_string());
+        parsed.scenes.push(declined.clone());
+        parsed.beats.push(declined_beat.clone());
+        parsed.chapters.push(later_chapter.clone());
+        parsed.scenes.push(later_scene.clone());
+        parsed.beats.push(later_beat.clone());
+
+        let preview =
+            build_sync_preview(&conn, &project.id, &parsed, SyncStrategy::PreferSource).unwrap();
+        assert!(preview
+            .changes
+            .iter()
+            .any(|c| c.id == format!("scene-missing-{}", cut.id)));
+
+        let accepted: HashSet<String> = [
+            format!("scene-title-{}", scene.id),
+            format!("scene-title-{}", finished.id),
+            format!("scene-missing-{}", cut.id),
+        ]
+        .into();
+        let additions: HashSet<String> = ["scene-sc-3".to_string()].into();
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &accepted,
+            &additions,
+            SyncStrategy::PreferSource,
+        )
+        .unwrap();
+
+        let action_for = |id: Uuid| {
+            report
+                .items
+                .iter()
+                .find(|item| item.id == id.to_string())
+                .map(|item| item.action.clone())
+        };
+        assert_eq!(action_for(scene.id), Some(SyncAction::Updated));
+        assert_eq!(action_for(fresh.id), Some(SyncAction::Created));
+        assert_eq!(
+            action_for(finished.id),
+            Some(SyncAction::Skipped("locked".to_string()))
+        );
+        assert_eq!(action_for(cut.id), Some(SyncAction::ArchivedMissing));
+        let not_accepted = Some(SyncAction::Skipped("not accepted".to_string()));
+        for id in [
+            declined.id,
+            declined_beat.id,
+            later_chapter.id,
+            later_scene.id,
+            later_beat.id,
+        ] {
+            assert_eq!(action_for(id), not_accepted);
+        }
+        assert_eq!(report.summary.scenes_updated, 1);
+        assert_eq!(report.summary.scenes_added, 1);
+        assert_eq!(report.summary.chapters_added, 0);
+        assert_eq!(report.summary.beats_added, 0);
+        assert_eq!(report.items_skipped, 6);
+        assert_eq!(report.scenes_archived, 1);
+
+        let kept = db::get_scene_by_id(&conn, &finished.id).unwrap().unwrap();
+        assert_eq!(kept.title, "Finished");
+        assert!(
+            db::get_scene_by_id(&conn, &cut.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+    }
+
+    #[test]
+    fn test_apply_sync_keeps_locked_scene_title() {
+        let (conn, project, scene, _, parsed) = renamed_scene_fixture();
+        db::lock_scene(&conn, &scene.id).unwrap();
+
+        let accepted: HashSet<String> = [format!("scene-title-{}", scene.id)].into();
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &accepted,
+            &HashSet::new(),
+            SyncStrategy::PreferSource,
+        )
+        .unwrap();
+
+        let kept = db::get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(kept.title, "Old Title");
+        assert_eq!(kept.position, 0);
+        assert_eq!(report.summary.scenes_updated, 0);
+        assert_eq!(report.items_skipped, 1);
+        assert_eq!(
+            report.items[0].action,
+            SyncAction::Skipped("locked".to_string())
+        );
+    }
+
+    #[test]
+    fn test_apply_sync_skips_everything_in_locked_chapter() {
+        let (conn, project, scene, beat, mut parsed) = renamed_scene_fixture();
+        db::lock_chapter(&conn, &scene.chapter_id).unwrap();
+        parsed.chapters[0].title = "Renamed Chapter".to_string();
+        parsed.beats[0].content = "A different heist".to_string();
+        let mut extra = Beat::new(parsed.scenes[0].id, "Aftermath".to_string(), 1);
+        extra.source_id = Some("b-2".to_string());
+        parsed.beats.push(extra);
+
+        // Nothing in the locked chapter is offered in the preview
+        let preview =
+            build_sync_preview(&conn, &project.id, &parsed, SyncStrategy::PreferSource).unwrap();
+        assert!(preview.changes.is_empty());
+
+        let accepted: HashSet<String> = [
+            format!("chapter-title-{}", scene.chapter_id),
+            format!("scene-title-{}", scene.id),
+            format!("beat-content-{}", beat.id),
+        ]
+        .into();
+        let additions: HashSet<String> = ["beat-b-2".to_string()].into();
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &accepted,
+            &additions,
+            SyncStrategy::PreferSource,
+        )
+        .unwrap();
+
+        let chapter = db::get_chapter_by_id(&conn, &scene.chapter_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(chapter.title, "One");
+        assert_eq!(
+            db::get_scene_by_id(&conn, &scene.id)
+                .unwrap()
+                .unwrap()
+                .title,
+            "Old Title"
+        );
+        let beats = db::get_beats(&conn, &scene.id).unwrap();
+        assert_eq!(beats.len(), 1);
+        assert_eq!(beats[0].content, "The heist");
+        assert_eq!(report.items_skipped, 4);
+        assert!(report
+            .items
+            .iter()
+            .all(|item| item.action == SyncAction::Skipped("locked".to_string())));
+    }
+
+    #[test]
+    fn test_new_scene_with_beats_in_locked_chapter_is_skipped() {
+        let (conn, project, scene, _, mut parsed) = renamed_scene_fixture();
+        db::lock_chapter(&conn, &scene.chapter_id).unwrap();
+        let mut added = Scene::new(parsed.chapters[0].id, "Added".to_string(), None, 1);
+        added.source_id = Some("sc-2".to_string());
+        let mut added_beat = Beat::new(added.id, "Escape".to_string(), 0);
+        added_beat.source_id = Some("b-2".to_string());
+        parsed.scenes.push(added.clone());
+        parsed.beats.push(added_beat.clone());
+
+        let report = apply_reimport(&conn, &project.id, &parsed).unwrap();
+        let locked = Some(SyncAction::Skipped("locked".to_string()));
+        let action_for = |report: &SyncReport, id: Uuid| {
+            report
+                .items
+                .iter()
+                .find(|item| item.id == id.to_string())
+                .map(|item| item.action.clone())
+        };
+        assert_eq!(action_for(&report, added.id), locked);
+        assert_eq!(action_for(&report, added_beat.id), locked);
+        assert_eq!(db::get_scenes(&conn, &scene.chapter_id).unwrap().len(), 1);
+
+        let additions: HashSet<String> = ["scene-sc-2".to_string(), "beat-b-2".to_string()].into();
+        let report = apply_parsed_sync(
+            &conn,
+            &project.id,
+            &parsed,
+            &HashSet::new(),
+            &additions,
+            SyncStrategy::PreferSource,
+        )
+        .unwrap();
+        assert_eq!(action_for(&report, added.id), locked);
+        assert_eq!(action_for(&report, added_beat.id), locked);
+        assert_eq!(db::get_scenes(&conn, &scene.chapter_id).unwrap().len(), 1);
+    }
 }
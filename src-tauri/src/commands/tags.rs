@@ -175,6 +175,74 @@ pub async fn get_all_entity_tags(
     db::get_all_entity_tags_for_project(&conn, &uuid).map_err(|e| e.to_string())
 }
 
+// ============================================================================
+// Scene Tags
+// ============================================================================
+
+/// Add a free-text tag to a scene and return the scene's tags
+#[tauri::command]
+pub async fn add_scene_tag(
+    scene_id: String,
+    tag: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    if tag.trim().is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::get_scene_by_id(&conn, &uuid)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Scene not found".to_string())?;
+
+    db::add_scene_tag(&conn, &uuid, &tag).map_err(|e| e.to_string())?;
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+    db::get_scene_tags(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+/// Remove a tag (matched case-insensitively) and return the scene's tags
+#[tauri::command]
+pub async fn remove_scene_tag(
+    scene_id: String,
+    tag: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+
+    db::remove_scene_tag(&conn, &uuid, &tag).map_err(|e| e.to_string())?;
+    if let Some(project_id) = db::get_scene_project_id(&conn, &uuid).map_err(|e| e.to_string())? {
+        let _ = db::update_project_modified(&conn, &project_id);
+    }
+    db::get_scene_tags(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_scene_tags(
+    scene_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&scene_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    db::get_scene_tags(&conn, &uuid).map_err(|e| e.to_string())
+}
+
+/// Ids of the project's scenes carrying `tag`, for filtering the binder
+#[tauri::command]
+pub async fn get_scenes_by_tag(
+    project_id: String,
+    tag: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let conn = state.db.lock().map_err(|e| e.to_string())?;
+    let ids = db::get_scenes_by_tag(&conn, &uuid, &tag).map_err(|e| e.to_string())?;
+    Ok(ids.iter().map(|id| id.to_string()).collect())
+}
+
 // ============================================================================
 // Filtering
 // ============================================================================
@@ -119,6 +119,7 @@ pub async fn apply_template(
             "scene_location_refs",
             "scene_reference_item_refs",
             "scene_reference_state",
+            "scene_tags",
             "discovery_notes",
             "dismissed_suggestions",
         ] {
@@ -160,6 +161,8 @@ pub async fn apply_template(
                 is_part: true,
                 synopsis: None,
                 planning_status: PlanningStatus::Flexible,
+                color: None,
+                word_target: None,
             },
         )
         .map_err(|e| e.to_string())?;
@@ -180,6 +183,8 @@ pub async fn apply_template(
                     is_part: false,
                     synopsis: chapter.synopsis.clone(),
                     planning_status: PlanningStatus::Flexible,
+                    color: None,
+                    word_target: None,
                 },
             )
             .map_err(|e| e.to_string())?;
@@ -202,6 +207,13 @@ pub async fn apply_template(
                         scene_status: SceneStatus::Draft,
                         planning_status: PlanningStatus::Flexible,
                         editor_mode: EditorMode::Beat,
+                        compile_position: None,
+                        include_in_compile: true,
+                        color: None,
+                        pov_character_id: None,
+                        notes: None,
+                        word_target: None,
+                        custom_status: None,
                     },
                 )
                 .map_err(|e| e.to_string())?;
@@ -419,6 +431,8 @@ mod tests {
                     is_part: true,
                     synopsis: None,
                     planning_status: PlanningStatus::Flexible,
+                    color: None,
+                    word_target: None,
                 },
             )
             .unwrap();
@@ -439,6 +453,8 @@ mod tests {
                         is_part: false,
                         synopsis: ch.synopsis.clone(),
                         planning_status: PlanningStatus::Flexible,
+                        color: None,
+                        word_target: None,
                     },
                 )
                 .unwrap();
@@ -461,6 +477,13 @@ mod tests {
                             scene_status: SceneStatus::Draft,
                             planning_status: PlanningStatus::Flexible,
                             editor_mode: EditorMode::Beat,
+                            compile_position: None,
+                            include_in_compile: true,
+                            color: None,
+                            pov_character_id: None,
+                            notes: None,
+                            word_target: None,
+                            custom_status: None,
                         },
                     )
                     .unwrap();
@@ -1,7 +1,10 @@
 use crate::models::{FieldDefinition, FieldValue};
 use rusqlite::{params, Connection, Result};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use super::queries::parse_uuid;
+
 // ============================================================================
 // Field Definitions
 // ============================================================================
@@ -22,8 +25,8 @@ pub fn get_field_definitions(
     let defs = stmt
         .query_map(params![project_id.to_string(), entity_type], |row| {
             Ok(FieldDefinition {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                project_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
                 entity_type: row.get(2)?,
                 name: row.get(3)?,
                 field_type: row.get(4)?,
@@ -35,8 +38,7 @@ pub fn get_field_definitions(
                 created_at: row.get(10)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(defs)
 }
@@ -56,8 +58,8 @@ pub fn get_all_field_definitions(
     let defs = stmt
         .query_map(params![project_id.to_string()], |row| {
             Ok(FieldDefinition {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                project_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
                 entity_type: row.get(2)?,
                 name: row.get(3)?,
                 field_type: row.get(4)?,
@@ -69,8 +71,7 @@ pub fn get_all_field_definitions(
                 created_at: row.get(10)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(defs)
 }
@@ -159,14 +160,13 @@ pub fn get_field_values(conn: &Connection, entity_id: &Uuid) -> Result<Vec<Field
     let values = stmt
         .query_map(params![entity_id.to_string()], |row| {
             Ok(FieldValue {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                field_definition_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
-                entity_id: Uuid::parse_str(&row.get::<_, String>(2)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                field_definition_id: parse_uuid(&row.get::<_, String>(1)?)?,
+                entity_id: parse_uuid(&row.get::<_, String>(2)?)?,
                 value: row.get(3)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(values)
 }
@@ -198,14 +198,13 @@ pub fn get_field_values_bulk(conn: &Connection, entity_ids: &[Uuid]) -> Result<V
     let values = stmt
         .query_map(param_refs.as_slice(), |row| {
             Ok(FieldValue {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                field_definition_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
-                entity_id: Uuid::parse_str(&row.get::<_, String>(2)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                field_definition_id: parse_uuid(&row.get::<_, String>(1)?)?,
+                entity_id: parse_uuid(&row.get::<_, String>(2)?)?,
                 value: row.get(3)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(values)
 }
@@ -244,6 +243,49 @@ pub fn clear_field_value(
     Ok(())
 }
 
+/// Define imported attribute `names` as text fields of `entity_type`, in the
+/// given order after any existing fields, and fill in each entity's values.
+///
+/// Importers still write the legacy attribute tables; doing this at import time
+/// keeps the source's field order, which the startup migration can't know, and
+/// `notes` stays out since it is shown separately.
+pub fn import_attribute_fields<'a>(
+    conn: &Connection,
+    project_id: &Uuid,
+    entity_type: &str,
+    names: &[String],
+    entities: impl IntoIterator<Item = (&'a Uuid, &'a HashMap<String, String>)>,
+) -> Result<()> {
+    let mut defs = get_field_definitions(conn, project_id, entity_type)?;
+    let mut next_position = defs.iter().map(|d| d.position + 1).max().unwrap_or(0);
+
+    for name in names.iter().filter(|name| name.as_str() != "notes") {
+        if defs.iter().any(|d| d.name == *name) {
+            continue;
+        }
+        let def = FieldDefinition::new(
+            *project_id,
+            entity_type.to_string(),
+            name.clone(),
+            "text".to_string(),
+            next_position,
+        );
+        create_field_definition(conn, &def)?;
+        defs.push(def);
+        next_position += 1;
+    }
+
+    for (entity_id, attributes) in entities {
+        for def in &defs {
+            if let Some(value) = attributes.get(&def.name) {
+                set_field_value(conn, &def.id, entity_id, Some(value))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
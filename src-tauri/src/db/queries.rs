@@ -3,9 +3,11 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::models::{
-    Beat, Chapter, Character, DiscoveryNote, EditorMode, Location, PlanningStatus, Project,
-    ReferenceItem, Scene, SceneCharacterRef, SceneLocationRef, SceneReferenceItemRef,
-    SceneReferenceState, SceneStatus, SceneType, SnapshotMetadata, SnapshotTrigger, SourceType,
+    Beat, BeatRevision, BinderItemFlags, Chapter, Character, CharacterAppearance, DiscoveryNote,
+    EditorMode, Location, OrphanedRef, PlanningStatus, Project, ReferenceItem, ReplacePreview,
+    Scene, SceneCharacterRef, SceneLocationRef, SceneReferenceItemRef, SceneReferenceState,
+    SceneStatus, SceneType, SearchField, SearchHit, SnapshotCompression, SnapshotMetadata,
+    SnapshotTrigger, SourceType, WritingSession,
 };
 
 pub(crate) fn parse_uuid(s: &str) -> rusqlite::Result<Uuid> {
@@ -20,9 +22,11 @@ pub(crate) fn parse_uuid(s: &str) -> rusqlite::Result<Uuid> {
 pub fn insert_project(conn: &Connection, project: &Project) -> Result<()> {
     let reference_types_json =
         serde_json::to_string(&project.reference_types).unwrap_or_else(|_| "[]".to_string());
+    let scene_statuses_json =
+        serde_json::to_string(&project.scene_statuses).unwrap_or_else(|_| "[]".to_string());
     conn.execute(
-        "INSERT INTO projects (id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        "INSERT INTO projects (id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count, scene_statuses, publisher, isbn, series_name, series_index)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
         params![
             project.id.to_string(),
             project.name,
@@ -37,11 +41,22 @@ pub fn insert_project(conn: &Connection, project: &Project) -> Result<()> {
             reference_types_json,
             project.project_type,
             project.target_page_count,
+            scene_statuses_json,
+            project.publisher,
+            project.isbn,
+            project.series_name,
+            project.series_index,
         ],
     )?;
     Ok(())
 }
 
+/// Custom scene statuses stored as JSON; missing or malformed values mean none
+fn parse_scene_statuses(raw: Option<String>) -> Vec<String> {
+    raw.and_then(|value| serde_json::from_str::<Vec<String>>(&value).ok())
+        .unwrap_or_default()
+}
+
 fn parse_reference_types(raw: Option<String>) -> Vec<String> {
     match raw {
         Some(value) => serde_json::from_str::<Vec<String>>(&value)
@@ -51,7 +66,7 @@ fn parse_reference_types(raw: Option<String>) -> Vec<String> {
 }
 
 /// Build a Project from a row selected with columns:
-/// id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count
+/// id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count, scene_statuses, publisher, isbn, series_name, series_index
 fn project_from_row(row: &rusqlite::Row) -> rusqlite::Result<Project> {
     Ok(Project {
         id: parse_uuid(&row.get::<_, String>(0)?)?,
@@ -69,12 +84,17 @@ fn project_from_row(row: &rusqlite::Row) -> rusqlite::Result<Project> {
             .get::<_, String>(11)
             .unwrap_or_else(|_| Project::default_project_type()),
         target_page_count: row.get(12)?,
+        scene_statuses: parse_scene_statuses(row.get(13).unwrap_or(None)),
+        publisher: row.get(14).unwrap_or(None),
+        isbn: row.get(15).unwrap_or(None),
+        series_name: row.get(16).unwrap_or(None),
+        series_index: row.get(17).unwrap_or(None),
     })
 }
 
 pub fn get_project(conn: &Connection, id: &Uuid) -> Result<Option<Project>> {
     let mut stmt = conn.prepare(
-        "SELECT id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count
+        "SELECT id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count, scene_statuses, publisher, isbn, series_name, series_index
          FROM projects WHERE id = ?1",
     )?;
 
@@ -89,7 +109,7 @@ pub fn get_project(conn: &Connection, id: &Uuid) -> Result<Option<Project>> {
 
 pub fn get_recent_projects(conn: &Connection, limit: usize) -> Result<Vec<Project>> {
     let mut stmt = conn.prepare(
-        "SELECT id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count
+        "SELECT id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count, scene_statuses, publisher, isbn, series_name, series_index
          FROM projects ORDER BY modified_at DESC LIMIT ?1",
     )?;
 
@@ -102,7 +122,7 @@ pub fn get_recent_projects(conn: &Connection, limit: usize) -> Result<Vec<Projec
 
 pub fn get_all_projects(conn: &Connection) -> Result<Vec<Project>> {
     let mut stmt = conn.prepare(
-        "SELECT id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count
+        "SELECT id, name, source_type, source_path, created_at, modified_at, author_pen_name, genre, description, word_target, reference_types, project_type, target_page_count, scene_statuses, publisher, isbn, series_name, series_index
          FROM projects ORDER BY modified_at DESC",
     )?;
 
@@ -113,10 +133,58 @@ pub fn get_all_projects(conn: &Connection) -> Result<Vec<Project>> {
     Ok(projects)
 }
 
+/// Get the project's cached word count; None until it has first been computed
+pub fn get_cached_word_count(conn: &Connection, project_id: &Uuid) -> Result<Option<i64>> {
+    let cached = conn
+        .query_row(
+            "SELECT cached_word_count FROM projects WHERE id = ?1",
+            params![project_id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(cached.flatten())
+}
+
+pub fn set_cached_word_count(conn: &Connection, project_id: &Uuid, word_count: i64) -> Result<()> {
+    conn.execute(
+        "UPDATE projects SET cached_word_count = ?1 WHERE id = ?2",
+        params![word_count, project_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Whether a scene's beats count towards the project word count: it and its chapter
+/// are neither archived nor trashed, and the scene is included in compile
+pub fn scene_counts_toward_word_count(conn: &Connection, scene_id: &Uuid) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM scenes s
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE s.id = ?1 AND s.include_in_compile = 1
+           AND s.archived = 0 AND s.trashed = 0 AND c.archived = 0 AND c.trashed = 0",
+        params![scene_id.to_string()],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// Count a project's scenes, leaving out archived and trashed scenes and chapters
+pub fn count_project_scenes(conn: &Connection, project_id: &Uuid) -> Result<usize> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM scenes s
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1
+           AND s.archived = 0 AND s.trashed = 0 AND c.archived = 0 AND c.trashed = 0",
+        params![project_id.to_string()],
+        |row| row.get(0),
+    )?;
+    Ok(count as usize)
+}
+
+/// Mark a project modified, clearing its cached word count since any change may affect it
 pub fn update_project_modified(conn: &Connection, id: &Uuid) -> Result<()> {
     let now = chrono::Utc::now().to_rfc3339();
     conn.execute(
-        "UPDATE projects SET modified_at = ?1 WHERE id = ?2",
+        "UPDATE projects SET modified_at = ?1, cached_word_count = NULL WHERE id = ?2",
         params![now, id.to_string()],
     )?;
     Ok(())
@@ -136,7 +204,8 @@ pub fn delete_project(conn: &Connection, id: &Uuid) -> Result<()> {
 // ============================================================================
 
 /// Build a Chapter from a row selected with columns:
-/// id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status
+/// id, project_id, title, position, source_id, archived, locked, is_part, synopsis,
+/// planning_status, color, word_target
 fn chapter_from_row(row: &rusqlite::Row) -> rusqlite::Result<Chapter> {
     Ok(Chapter {
         id: parse_uuid(&row.get::<_, String>(0)?)?,
@@ -152,12 +221,18 @@ fn chapter_from_row(row: &rusqlite::Row) -> rusqlite::Result<Chapter> {
             .get::<_, String>(9)
             .map(|s| PlanningStatus::parse(&s))
             .unwrap_or_default(),
+        color: row.get(10).unwrap_or(None),
+        word_target: row.get(11).unwrap_or(None),
     })
 }
 
 /// Build a Scene from a row selected with columns:
-/// id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode
+/// id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type,
+/// scene_status, planning_status, editor_mode, compile_position, include_in_compile, color,
+/// pov_character_id, notes, word_target
 fn scene_from_row(row: &rusqlite::Row) -> rusqlite::Result<Scene> {
+    let (scene_status, custom_status) =
+        SceneStatus::parse_stored(&row.get::<_, String>(10).unwrap_or_default());
     Ok(Scene {
         id: parse_uuid(&row.get::<_, String>(0)?)?,
         chapter_id: parse_uuid(&row.get::<_, String>(1)?)?,
@@ -169,7 +244,7 @@ fn scene_from_row(row: &rusqlite::Row) -> rusqlite::Result<Scene> {
         archived: row.get::<_, i32>(7).unwrap_or(0) != 0,
         locked: row.get::<_, i32>(8).unwrap_or(0) != 0,
         scene_type: SceneType::parse(&row.get::<_, String>(9).unwrap_or_default()),
-        scene_status: SceneStatus::parse(&row.get::<_, String>(10).unwrap_or_default()),
+        scene_status,
         planning_status: row
             .get::<_, String>(11)
             .map(|s| PlanningStatus::parse(&s))
@@ -178,26 +253,39 @@ fn scene_from_row(row: &rusqlite::Row) -> rusqlite::Result<Scene> {
             .get::<_, String>(12)
             .map(|s| EditorMode::parse(&s))
             .unwrap_or_default(),
+        compile_position: row.get(13).unwrap_or(None),
+        include_in_compile: row.get::<_, i32>(14).unwrap_or(1) != 0,
+        color: row.get(15).unwrap_or(None),
+        pov_character_id: row
+            .get::<_, Option<String>>(16)
+            .unwrap_or(None)
+            .map(|s| parse_uuid(&s))
+            .transpose()?,
+        notes: row.get(17).unwrap_or(None),
+        word_target: row.get(18).unwrap_or(None),
+        custom_status,
     })
 }
 
 pub fn insert_chapter(conn: &Connection, chapter: &Chapter) -> Result<()> {
-    conn.execute(
-        "INSERT INTO chapters (id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
-        params![
-            chapter.id.to_string(),
-            chapter.project_id.to_string(),
-            chapter.title,
-            chapter.position,
-            chapter.source_id,
-            chapter.archived as i32,
-            chapter.locked as i32,
-            chapter.is_part as i32,
-            chapter.synopsis,
-            chapter.planning_status.as_str(),
-        ],
-    )?;
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO chapters (id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+    )?;
+    stmt.execute(params![
+        chapter.id.to_string(),
+        chapter.project_id.to_string(),
+        chapter.title,
+        chapter.position,
+        chapter.source_id,
+        chapter.archived as i32,
+        chapter.locked as i32,
+        chapter.is_part as i32,
+        chapter.synopsis,
+        chapter.planning_status.as_str(),
+        chapter.color,
+        chapter.word_target,
+    ])?;
     Ok(())
 }
 
@@ -208,14 +296,61 @@ pub fn get_max_chapter_position(conn: &Connection, project_id: &Uuid) -> Result<
     Ok(max)
 }
 
+/// Renumber chapters in the given order.
+///
+/// Runs in one transaction: an id that is not a chapter of this project, or
+/// any failed update, rolls every position back to where it was.
 pub fn reorder_chapters(conn: &Connection, project_id: &Uuid, chapter_ids: &[Uuid]) -> Result<()> {
     let tx = conn.unchecked_transaction()?;
     for (idx, id) in chapter_ids.iter().enumerate() {
-        tx.execute(
+        let updated = tx.execute(
             "UPDATE chapters SET position = ?1 WHERE id = ?2 AND project_id = ?3",
             params![idx as i32, id.to_string(), project_id.to_string()],
         )?;
+        if updated == 0 {
+            return Err(rusqlite::Error::QueryReturnedNoRows);
+        }
+    }
+    tx.commit()
+}
+
+/// Move one chapter to `new_position`, shifting the chapters in between by one.
+///
+/// Unlike `reorder_chapters` this needs no client-side ordering, so a stale list
+/// can't scramble the project. Positions past the end move the chapter last.
+pub fn move_chapter_to_position(
+    conn: &Connection,
+    project_id: &Uuid,
+    chapter_id: &Uuid,
+    new_position: i32,
+) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    let old_position: i32 = tx.query_row(
+        "SELECT position FROM chapters WHERE id = ?1 AND project_id = ?2",
+        params![chapter_id.to_string(), project_id.to_string()],
+        |row| row.get(0),
+    )?;
+    let new_position = new_position.clamp(0, get_max_chapter_position(&tx, project_id)?.max(0));
+
+    if new_position < old_position {
+        tx.execute(
+            "UPDATE chapters SET position = position + 1
+             WHERE project_id = ?1 AND position >= ?2 AND position < ?3",
+            params![project_id.to_string(), new_position, old_position],
+        )?;
+    } else if new_position > old_position {
+        tx.execute(
+            "UPDATE chapters SET position = position - 1
+             WHERE project_id = ?1 AND position > ?2 AND position <= ?3",
+            params![project_id.to_string(), old_position, new_position],
+        )?;
     }
+    tx.execute(
+        "UPDATE chapters SET position = ?1 WHERE id = ?2",
+        params![new_position, chapter_id.to_string()],
+    )?;
+
     tx.commit()
 }
 
@@ -234,8 +369,8 @@ pub fn shift_chapters_after_position(
 
 pub fn get_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Chapter>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status
-         FROM chapters WHERE project_id = ?1 AND archived = 0 ORDER BY position",
+        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target
+         FROM chapters WHERE project_id = ?1 AND archived = 0 AND trashed = 0 ORDER BY position",
     )?;
 
     let chapters = stmt
@@ -250,25 +385,34 @@ pub fn get_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Chapter>
 // ============================================================================
 
 pub fn insert_scene(conn: &Connection, scene: &Scene) -> Result<()> {
-    conn.execute(
-        "INSERT INTO scenes (id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
-        params![
-            scene.id.to_string(),
-            scene.chapter_id.to_string(),
-            scene.title,
-            scene.synopsis,
-            scene.prose,
-            scene.position,
-            scene.source_id,
-            scene.archived as i32,
-            scene.locked as i32,
-            scene.scene_type.as_str(),
-            scene.scene_status.as_str(),
-            scene.planning_status.as_str(),
-            scene.editor_mode.as_str(),
-        ],
-    )?;
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO scenes (id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode, compile_position, include_in_compile, color, pov_character_id, notes, word_target)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+    )?;
+    stmt.execute(params![
+        scene.id.to_string(),
+        scene.chapter_id.to_string(),
+        scene.title,
+        scene.synopsis,
+        scene.prose,
+        scene.position,
+        scene.source_id,
+        scene.archived as i32,
+        scene.locked as i32,
+        scene.scene_type.as_str(),
+        scene
+            .custom_status
+            .as_deref()
+            .unwrap_or(scene.scene_status.as_str()),
+        scene.planning_status.as_str(),
+        scene.editor_mode.as_str(),
+        scene.compile_position,
+        scene.include_in_compile as i32,
+        scene.color,
+        scene.pov_character_id.map(|id| id.to_string()),
+        scene.notes,
+        scene.word_target,
+    ])?;
     Ok(())
 }
 
@@ -279,6 +423,19 @@ pub fn get_max_scene_position(conn: &Connection, chapter_id: &Uuid) -> Result<i3
     Ok(max)
 }
 
+/// Shift all scenes at or after the given position up by 1 to make room for insertion
+pub fn shift_scenes_after_position(
+    conn: &Connection,
+    chapter_id: &Uuid,
+    position: i32,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET position = position + 1 WHERE chapter_id = ?1 AND position >= ?2",
+        params![chapter_id.to_string(), position],
+    )?;
+    Ok(())
+}
+
 pub fn get_chapter_project_id(conn: &Connection, chapter_id: &Uuid) -> Result<Option<Uuid>> {
     let mut stmt = conn.prepare("SELECT project_id FROM chapters WHERE id = ?1")?;
     let mut rows = stmt.query(params![chapter_id.to_string()])?;
@@ -305,13 +462,17 @@ pub fn get_scene_project_id(conn: &Connection, scene_id: &Uuid) -> Result<Option
     }
 }
 
+/// Renumber scenes in the given order, all-or-nothing like `reorder_chapters`.
 pub fn reorder_scenes(conn: &Connection, chapter_id: &Uuid, scene_ids: &[Uuid]) -> Result<()> {
     let tx = conn.unchecked_transaction()?;
     for (idx, id) in scene_ids.iter().enumerate() {
-        tx.execute(
+        let updated = tx.execute(
             "UPDATE scenes SET position = ?1 WHERE id = ?2 AND chapter_id = ?3",
             params![idx as i32, id.to_string(), chapter_id.to_string()],
         )?;
+        if updated == 0 {
+            return Err(rusqlite::Error::QueryReturnedNoRows);
+        }
     }
     tx.commit()
 }
@@ -333,10 +494,97 @@ pub fn move_scene_to_chapter(
     Ok(())
 }
 
+/// Every scene in a chapter (including archived and trashed) in position order,
+/// as `(id, visible)` where visible means neither archived nor trashed
+fn chapter_scene_ids_by_position(
+    conn: &Connection,
+    chapter_id: &Uuid,
+) -> Result<Vec<(Uuid, bool)>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, archived = 0 AND trashed = 0 FROM scenes WHERE chapter_id = ?1 ORDER BY position",
+    )?;
+    let ids = stmt
+        .query_map(params![chapter_id.to_string()], |row| {
+            Ok((parse_uuid(&row.get::<_, String>(0)?)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ids)
+}
+
+/// Move a scene to `target_position` in `target_chapter_id` in a single transaction.
+///
+/// `target_position` is an index among the target chapter's visible scenes, as
+/// the binder shows them: the scene lands just before the visible scene now at
+/// that index, or after the last visible scene. Archived and trashed scenes keep
+/// their place relative to their neighbours, and the positions of both the
+/// source and target chapters are renumbered to 0..n.
+pub fn move_and_reorder_scene(
+    conn: &Connection,
+    scene_id: &Uuid,
+    target_chapter_id: &Uuid,
+    target_position: i32,
+) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    let source_chapter_id = get_scene_by_id(&tx, scene_id)?
+        .ok_or(rusqlite::Error::QueryReturnedNoRows)?
+        .chapter_id;
+
+    let mut source_ids = chapter_scene_ids_by_position(&tx, &source_chapter_id)?;
+    source_ids.retain(|(id, _)| id != scene_id);
+
+    let mut target_ids = if source_chapter_id == *target_chapter_id {
+        std::mem::take(&mut source_ids)
+    } else {
+        chapter_scene_ids_by_position(&tx, target_chapter_id)?
+    };
+    let visible: Vec<usize> = target_ids
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, visible))| *visible)
+        .map(|(index, _)| index)
+        .collect();
+    let index = match visible.get(target_position.max(0) as usize) {
+        Some(&index) => index,
+        None => visible.last().map_or(target_ids.len(), |&last| last + 1),
+    };
+    target_ids.insert(index, (*scene_id, true));
+
+    for (chapter_id, ids) in [
+        (&source_chapter_id, &source_ids),
+        (target_chapter_id, &target_ids),
+    ] {
+        for (position, (id, _)) in ids.iter().enumerate() {
+            tx.execute(
+                "UPDATE scenes SET chapter_id = ?1, position = ?2 WHERE id = ?3",
+                params![chapter_id.to_string(), position as i32, id.to_string()],
+            )?;
+        }
+    }
+
+    tx.commit()
+}
+
 pub fn get_scenes(conn: &Connection, chapter_id: &Uuid) -> Result<Vec<Scene>> {
     let mut stmt = conn.prepare(
-        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode
-         FROM scenes WHERE chapter_id = ?1 AND archived = 0 ORDER BY position",
+        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode, compile_position, include_in_compile, color, pov_character_id, notes, word_target
+         FROM scenes WHERE chapter_id = ?1 AND archived = 0 AND trashed = 0 ORDER BY position",
+    )?;
+
+    let scenes = stmt
+        .query_map(params![chapter_id.to_string()], scene_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(scenes)
+}
+
+/// Like `get_scenes`, but in manuscript order: by `compile_position` where
+/// set, falling back to `position`. Binder order breaks ties.
+pub fn get_scenes_in_compile_order(conn: &Connection, chapter_id: &Uuid) -> Result<Vec<Scene>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode, compile_position, include_in_compile, color, pov_character_id, notes, word_target
+         FROM scenes WHERE chapter_id = ?1 AND archived = 0 AND trashed = 0
+         ORDER BY COALESCE(compile_position, position), position",
     )?;
 
     let scenes = stmt
@@ -346,6 +594,32 @@ pub fn get_scenes(conn: &Connection, chapter_id: &Uuid) -> Result<Vec<Scene>> {
     Ok(scenes)
 }
 
+/// Set or clear (`None`) a scene's compile position
+pub fn update_scene_compile_position(
+    conn: &Connection,
+    scene_id: &Uuid,
+    compile_position: Option<i32>,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET compile_position = ?1 WHERE id = ?2",
+        params![compile_position, scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Include a scene in, or exclude it from, exports and manuscript word counts
+pub fn update_scene_compile_inclusion(
+    conn: &Connection,
+    scene_id: &Uuid,
+    include_in_compile: bool,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET include_in_compile = ?1 WHERE id = ?2",
+        params![include_in_compile as i32, scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
 pub fn update_scene_prose(conn: &Connection, scene_id: &Uuid, prose: &str) -> Result<()> {
     conn.execute(
         "UPDATE scenes SET prose = ?1 WHERE id = ?2",
@@ -411,6 +685,38 @@ pub fn switch_scene_editor_mode(conn: &Connection, scene_id: &Uuid, mode: &str)
     get_scene_by_id(conn, scene_id)?.ok_or_else(|| rusqlite::Error::QueryReturnedNoRows)
 }
 
+/// Fold every beat's prose into the scene's own prose, in beat order.
+///
+/// Beats without prose are skipped and the rest are joined with `\n` so each
+/// beat's paragraphs stay separate. With `clear_beats` the beats are deleted
+/// afterwards, leaving the scene as flat prose.
+pub fn consolidate_scene_prose(
+    conn: &Connection,
+    scene_id: &Uuid,
+    clear_beats: bool,
+) -> Result<Scene> {
+    let tx = conn.unchecked_transaction()?;
+
+    let beats = get_beats(&tx, scene_id)?;
+    let prose = beats
+        .iter()
+        .filter_map(|b| b.prose.as_deref().filter(|p| !p.trim().is_empty()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    update_scene_prose(&tx, scene_id, &prose)?;
+
+    if clear_beats {
+        tx.execute(
+            "DELETE FROM beats WHERE scene_id = ?1",
+            params![scene_id.to_string()],
+        )?;
+    }
+
+    tx.commit()?;
+
+    get_scene_by_id(conn, scene_id)?.ok_or_else(|| rusqlite::Error::QueryReturnedNoRows)
+}
+
 pub fn save_scene_page_prose(conn: &Connection, scene_id: &Uuid, prose: &str) -> Result<()> {
     conn.execute(
         "UPDATE scenes SET prose = ?1 WHERE id = ?2",
@@ -424,18 +730,18 @@ pub fn save_scene_page_prose(conn: &Connection, scene_id: &Uuid, prose: &str) ->
 // ============================================================================
 
 pub fn insert_beat(conn: &Connection, beat: &Beat) -> Result<()> {
-    conn.execute(
+    let mut stmt = conn.prepare_cached(
         "INSERT INTO beats (id, scene_id, content, prose, position, source_id)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-        params![
-            beat.id.to_string(),
-            beat.scene_id.to_string(),
-            beat.content,
-            beat.prose,
-            beat.position,
-            beat.source_id,
-        ],
     )?;
+    stmt.execute(params![
+        beat.id.to_string(),
+        beat.scene_id.to_string(),
+        beat.content,
+        beat.prose,
+        beat.position,
+        beat.source_id,
+    ])?;
     Ok(())
 }
 
@@ -476,6 +782,99 @@ pub fn get_max_beat_position(conn: &Connection, scene_id: &Uuid) -> Result<i32>
     Ok(max)
 }
 
+/// Number of prose revisions kept per beat; older ones are pruned
+pub const MAX_BEAT_REVISIONS: usize = 20;
+
+/// Replace a beat's prose, first keeping the old prose as a revision when it changes
+pub fn update_beat_prose_with_revision(
+    conn: &Connection,
+    beat_id: &Uuid,
+    prose: &str,
+) -> Result<()> {
+    let previous: Option<String> = conn
+        .query_row(
+            "SELECT prose FROM beats WHERE id = ?1",
+            params![beat_id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?
+        .flatten();
+    if let Some(previous) = previous.filter(|p| !p.is_empty() && p != prose) {
+        insert_beat_revision(conn, &BeatRevision::new(*beat_id, previous))?;
+    }
+    update_beat_prose(conn, beat_id, prose)
+}
+
+/// Store a revision and prune the beat's oldest revisions beyond the cap
+pub fn insert_beat_revision(conn: &Connection, revision: &BeatRevision) -> Result<()> {
+    conn.execute(
+        "INSERT INTO beat_revisions (id, beat_id, prose, created_at) VALUES (?1, ?2, ?3, ?4)",
+        params![
+            revision.id.to_string(),
+            revision.beat_id.to_string(),
+            revision.prose,
+            revision.created_at,
+        ],
+    )?;
+    conn.execute(
+        "DELETE FROM beat_revisions WHERE beat_id = ?1 AND id NOT IN (
+             SELECT id FROM beat_revisions WHERE beat_id = ?1
+             ORDER BY rowid DESC LIMIT ?2
+         )",
+        params![revision.beat_id.to_string(), MAX_BEAT_REVISIONS as i64],
+    )?;
+    Ok(())
+}
+
+fn beat_revision_from_row(row: &rusqlite::Row) -> rusqlite::Result<BeatRevision> {
+    Ok(BeatRevision {
+        id: parse_uuid(&row.get::<_, String>(0)?)?,
+        beat_id: parse_uuid(&row.get::<_, String>(1)?)?,
+        prose: row.get(2)?,
+        created_at: row.get(3)?,
+    })
+}
+
+/// Get a beat's revisions, newest first
+pub fn get_beat_revisions(conn: &Connection, beat_id: &Uuid) -> Result<Vec<BeatRevision>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, beat_id, prose, created_at FROM beat_revisions
+         WHERE beat_id = ?1 ORDER BY rowid DESC",
+    )?;
+    let revisions = stmt
+        .query_map(params![beat_id.to_string()], beat_revision_from_row)?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(revisions)
+}
+
+/// Get every beat revision in a project, oldest first
+pub fn get_project_beat_revisions(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Vec<BeatRevision>> {
+    let mut stmt = conn.prepare(
+        "SELECT r.id, r.beat_id, r.prose, r.created_at FROM beat_revisions r
+         JOIN beats b ON b.id = r.beat_id
+         JOIN scenes s ON s.id = b.scene_id
+         JOIN chapters c ON c.id = s.chapter_id
+         WHERE c.project_id = ?1
+         ORDER BY r.rowid",
+    )?;
+    let revisions = stmt
+        .query_map(params![project_id.to_string()], beat_revision_from_row)?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(revisions)
+}
+
+pub fn get_beat_revision(conn: &Connection, revision_id: &Uuid) -> Result<Option<BeatRevision>> {
+    conn.query_row(
+        "SELECT id, beat_id, prose, created_at FROM beat_revisions WHERE id = ?1",
+        params![revision_id.to_string()],
+        beat_revision_from_row,
+    )
+    .optional()
+}
+
 pub fn get_beat(conn: &Connection, beat_id: &Uuid) -> Result<Option<Beat>> {
     let mut stmt = conn.prepare(
         "SELECT id, scene_id, content, prose, position, source_id
@@ -512,6 +911,22 @@ pub fn update_beat_position(conn: &Connection, beat_id: &Uuid, position: i32) ->
     Ok(())
 }
 
+/// Renumber a scene's beats in the given order. Only `position` changes, so
+/// content, prose and the `source_id` that reimport matches on stay intact.
+pub fn reorder_beats(conn: &Connection, scene_id: &Uuid, beat_ids: &[Uuid]) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    for (idx, id) in beat_ids.iter().enumerate() {
+        let updated = tx.execute(
+            "UPDATE beats SET position = ?1 WHERE id = ?2 AND scene_id = ?3",
+            params![idx as i32, id.to_string(), scene_id.to_string()],
+        )?;
+        if updated == 0 {
+            return Err(rusqlite::Error::QueryReturnedNoRows);
+        }
+    }
+    tx.commit()
+}
+
 /// Shift all beats at position >= from_position by +1 (for insert before)
 pub fn shift_beat_positions(conn: &Connection, scene_id: &Uuid, from_position: i32) -> Result<()> {
     conn.execute(
@@ -533,6 +948,14 @@ pub fn update_scene_synopsis(
     Ok(())
 }
 
+pub fn update_scene_notes(conn: &Connection, scene_id: &Uuid, notes: Option<&str>) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET notes = ?1 WHERE id = ?2",
+        params![notes, scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
 // ============================================================================
 // Discovery Note Queries
 // ============================================================================
@@ -694,6 +1117,41 @@ pub fn get_characters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Charac
     Ok(characters)
 }
 
+/// Get characters in a project that have an attribute matching `key` and `value`.
+///
+/// Both the key and value are compared exactly but case-insensitively.
+pub fn get_characters_with_attribute(
+    conn: &Connection,
+    project_id: &Uuid,
+    key: &str,
+    value: &str,
+) -> Result<Vec<Character>> {
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT c.id, c.name
+         FROM characters c
+         JOIN character_attributes ca ON ca.character_id = c.id
+         WHERE c.project_id = ?1
+           AND ca.key = ?2 COLLATE NOCASE
+           AND ca.value = ?3 COLLATE NOCASE
+         ORDER BY c.name",
+    )?;
+
+    let ids = stmt
+        .query_map(params![project_id.to_string(), key, value], |row| {
+            parse_uuid(&row.get::<_, String>(0)?)
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut characters = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(character) = get_character_by_id(conn, &id)? {
+            characters.push(character);
+        }
+    }
+
+    Ok(characters)
+}
+
 pub fn get_character_project_id(conn: &Connection, character_id: &Uuid) -> Result<Option<Uuid>> {
     let mut stmt = conn.prepare("SELECT project_id FROM characters WHERE id = ?1")?;
     let mut rows = stmt.query(params![character_id.to_string()])?;
@@ -768,7 +1226,9 @@ pub fn update_character(
     Ok(())
 }
 
+/// Delete a character and its scene references; callers wrap this in a transaction
 pub fn delete_character(conn: &Connection, character_id: &Uuid) -> Result<()> {
+    delete_scene_character_refs_for_character(conn, character_id)?;
     conn.execute(
         "DELETE FROM characters WHERE id = ?1",
         params![character_id.to_string()],
@@ -776,6 +1236,55 @@ pub fn delete_character(conn: &Connection, character_id: &Uuid) -> Result<()> {
     Ok(())
 }
 
+pub fn get_character_aliases(conn: &Connection, character_id: &Uuid) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT alias FROM character_aliases WHERE character_id = ?1 ORDER BY alias COLLATE NOCASE",
+    )?;
+
+    let aliases = stmt
+        .query_map(params![character_id.to_string()], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+
+    Ok(aliases)
+}
+
+/// Get every character alias in a project as (character_id, alias) pairs
+pub fn get_project_character_aliases(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Vec<(Uuid, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT a.character_id, a.alias FROM character_aliases a
+         JOIN characters c ON c.id = a.character_id
+         WHERE c.project_id = ?1
+         ORDER BY a.alias COLLATE NOCASE",
+    )?;
+
+    let aliases = stmt
+        .query_map(params![project_id.to_string()], |row| {
+            Ok((parse_uuid(&row.get::<_, String>(0)?)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(aliases)
+}
+
+pub fn add_character_alias(conn: &Connection, character_id: &Uuid, alias: &str) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO character_aliases (character_id, alias) VALUES (?1, ?2)",
+        params![character_id.to_string(), alias],
+    )?;
+    Ok(())
+}
+
+pub fn remove_character_alias(conn: &Connection, character_id: &Uuid, alias: &str) -> Result<()> {
+    conn.execute(
+        "DELETE FROM character_aliases WHERE character_id = ?1 AND alias = ?2",
+        params![character_id.to_string(), alias],
+    )?;
+    Ok(())
+}
+
 // ============================================================================
 // Location Queries
 // ============================================================================
@@ -918,7 +1427,9 @@ pub fn update_location(
     Ok(())
 }
 
+/// Delete a location and its scene references; callers wrap this in a transaction
 pub fn delete_location(conn: &Connection, location_id: &Uuid) -> Result<()> {
+    delete_scene_location_refs_for_location(conn, location_id)?;
     conn.execute(
         "DELETE FROM locations WHERE id = ?1",
         params![location_id.to_string()],
@@ -926,6 +1437,55 @@ pub fn delete_location(conn: &Connection, location_id: &Uuid) -> Result<()> {
     Ok(())
 }
 
+pub fn get_location_aliases(conn: &Connection, location_id: &Uuid) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT alias FROM location_aliases WHERE location_id = ?1 ORDER BY alias COLLATE NOCASE",
+    )?;
+
+    let aliases = stmt
+        .query_map(params![location_id.to_string()], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+
+    Ok(aliases)
+}
+
+/// Get every location alias in a project as (location_id, alias) pairs
+pub fn get_project_location_aliases(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Vec<(Uuid, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT a.location_id, a.alias FROM location_aliases a
+         JOIN locations o ON o.id = a.location_id
+         WHERE o.project_id = ?1
+         ORDER BY a.alias COLLATE NOCASE",
+    )?;
+
+    let aliases = stmt
+        .query_map(params![project_id.to_string()], |row| {
+            Ok((parse_uuid(&row.get::<_, String>(0)?)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(aliases)
+}
+
+pub fn add_location_alias(conn: &Connection, location_id: &Uuid, alias: &str) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO location_aliases (location_id, alias) VALUES (?1, ?2)",
+        params![location_id.to_string(), alias],
+    )?;
+    Ok(())
+}
+
+pub fn remove_location_alias(conn: &Connection, location_id: &Uuid, alias: &str) -> Result<()> {
+    conn.execute(
+        "DELETE FROM location_aliases WHERE location_id = ?1 AND alias = ?2",
+        params![location_id.to_string(), alias],
+    )?;
+    Ok(())
+}
+
 // ============================================================================
 // Reference Item Queries
 // ============================================================================
@@ -1141,8 +1701,68 @@ pub fn delete_reference_item(conn: &Connection, reference_item_id: &Uuid) -> Res
     Ok(())
 }
 
-// ============================================================================
-// Scene References
+pub fn get_reference_item_aliases(
+    conn: &Connection,
+    reference_item_id: &Uuid,
+) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT alias FROM reference_item_aliases WHERE reference_item_id = ?1 ORDER BY alias COLLATE NOCASE",
+    )?;
+
+    let aliases = stmt
+        .query_map(params![reference_item_id.to_string()], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+
+    Ok(aliases)
+}
+
+/// Get every reference item alias in a project as (reference_item_id, alias) pairs
+pub fn get_project_reference_item_aliases(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Vec<(Uuid, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT a.reference_item_id, a.alias FROM reference_item_aliases a
+         JOIN reference_items o ON o.id = a.reference_item_id
+         WHERE o.project_id = ?1
+         ORDER BY a.alias COLLATE NOCASE",
+    )?;
+
+    let aliases = stmt
+        .query_map(params![project_id.to_string()], |row| {
+            Ok((parse_uuid(&row.get::<_, String>(0)?)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(aliases)
+}
+
+pub fn add_reference_item_alias(
+    conn: &Connection,
+    reference_item_id: &Uuid,
+    alias: &str,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO reference_item_aliases (reference_item_id, alias) VALUES (?1, ?2)",
+        params![reference_item_id.to_string(), alias],
+    )?;
+    Ok(())
+}
+
+pub fn remove_reference_item_alias(
+    conn: &Connection,
+    reference_item_id: &Uuid,
+    alias: &str,
+) -> Result<()> {
+    conn.execute(
+        "DELETE FROM reference_item_aliases WHERE reference_item_id = ?1 AND alias = ?2",
+        params![reference_item_id.to_string(), alias],
+    )?;
+    Ok(())
+}
+
+// ============================================================================
+// Scene References
 // ============================================================================
 
 pub fn add_scene_character_ref(
@@ -1195,6 +1815,34 @@ pub fn get_scene_ids_for_character(conn: &Connection, character_id: &Uuid) -> Re
     Ok(ids)
 }
 
+/// Get the scenes a character is referenced in, in manuscript order, leaving out
+/// archived and trashed scenes and chapters
+pub fn get_character_appearances(
+    conn: &Connection,
+    character_id: &Uuid,
+) -> Result<Vec<CharacterAppearance>> {
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.title, c.id, c.title
+         FROM scene_character_refs scr
+         JOIN scenes s ON scr.scene_id = s.id
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE scr.character_id = ?1 AND s.archived = 0 AND c.archived = 0
+           AND s.trashed = 0 AND c.trashed = 0
+         ORDER BY c.position, s.position",
+    )?;
+    let appearances = stmt
+        .query_map(params![character_id.to_string()], |row| {
+            Ok(CharacterAppearance {
+                scene_id: parse_uuid(&row.get::<_, String>(0)?)?,
+                scene_title: row.get(1)?,
+                chapter_id: parse_uuid(&row.get::<_, String>(2)?)?,
+                chapter_title: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(appearances)
+}
+
 pub fn get_scene_ids_for_location(conn: &Connection, location_id: &Uuid) -> Result<Vec<Uuid>> {
     let mut stmt =
         conn.prepare("SELECT scene_id FROM scene_location_refs WHERE location_id = ?1")?;
@@ -1246,6 +1894,20 @@ pub fn get_scene_locations(conn: &Connection, scene_id: &Uuid) -> Result<Vec<Uui
     Ok(ids)
 }
 
+/// Ids of every reference item linked to a scene, regardless of reference type
+pub fn get_scene_reference_item_ids(conn: &Connection, scene_id: &Uuid) -> Result<Vec<Uuid>> {
+    let mut stmt = conn
+        .prepare("SELECT reference_item_id FROM scene_reference_item_refs WHERE scene_id = ?1")?;
+
+    let ids = stmt
+        .query_map(params![scene_id.to_string()], |row| {
+            parse_uuid(&row.get::<_, String>(0)?)
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ids)
+}
+
 pub fn get_scene_reference_states(
     conn: &Connection,
     scene_id: &Uuid,
@@ -1532,6 +2194,52 @@ pub fn delete_scene_reference_item_refs_for_item(
     Ok(())
 }
 
+/// Find scene references in a project that point at deleted characters, locations or items
+pub fn find_orphaned_refs(conn: &Connection, project_id: &Uuid) -> Result<Vec<OrphanedRef>> {
+    let mut orphans = Vec::new();
+    for (reference_type, table, column, entity_table) in [
+        (
+            "characters",
+            "scene_character_refs",
+            "character_id",
+            "characters",
+        ),
+        (
+            "locations",
+            "scene_location_refs",
+            "location_id",
+            "locations",
+        ),
+        (
+            "reference_items",
+            "scene_reference_item_refs",
+            "reference_item_id",
+            "reference_items",
+        ),
+    ] {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT r.scene_id, r.{column}
+             FROM {table} r
+             JOIN scenes s ON r.scene_id = s.id
+             JOIN chapters c ON s.chapter_id = c.id
+             WHERE c.project_id = ?1
+               AND r.{column} NOT IN (SELECT id FROM {entity_table})
+             ORDER BY c.position, s.position"
+        ))?;
+        let rows = stmt
+            .query_map(params![project_id.to_string()], |row| {
+                Ok(OrphanedRef {
+                    scene_id: parse_uuid(&row.get::<_, String>(0)?)?,
+                    reference_type: reference_type.to_string(),
+                    reference_id: parse_uuid(&row.get::<_, String>(1)?)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        orphans.extend(rows);
+    }
+    Ok(orphans)
+}
+
 pub fn get_scene_reference_items(
     conn: &Connection,
     scene_id: &Uuid,
@@ -1629,6 +2337,10 @@ pub fn delete_chapter(conn: &Connection, chapter_id: &Uuid) -> Result<()> {
         "DELETE FROM scene_reference_state WHERE scene_id IN (SELECT id FROM scenes WHERE chapter_id = ?1)",
         params![chapter_id.to_string()],
     )?;
+    tx.execute(
+        "DELETE FROM scene_tags WHERE scene_id IN (SELECT id FROM scenes WHERE chapter_id = ?1)",
+        params![chapter_id.to_string()],
+    )?;
 
     tx.execute(
         "DELETE FROM beats WHERE scene_id IN (SELECT id FROM scenes WHERE chapter_id = ?1)",
@@ -1648,7 +2360,7 @@ pub fn delete_chapter(conn: &Connection, chapter_id: &Uuid) -> Result<()> {
     tx.commit()
 }
 
-/// Delete a scene and all its beats and references
+/// Delete a scene and all its beats, references and tags
 pub fn delete_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
     let tx = conn.unchecked_transaction()?;
 
@@ -1668,6 +2380,10 @@ pub fn delete_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
         "DELETE FROM scene_reference_state WHERE scene_id = ?1",
         params![scene_id.to_string()],
     )?;
+    tx.execute(
+        "DELETE FROM scene_tags WHERE scene_id = ?1",
+        params![scene_id.to_string()],
+    )?;
 
     tx.execute(
         "DELETE FROM beats WHERE scene_id = ?1",
@@ -1682,6 +2398,118 @@ pub fn delete_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
     tx.commit()
 }
 
+/// Separator placed between the two synopses (and notes) when scenes are merged
+pub const MERGED_SYNOPSIS_SEPARATOR: &str = "\n\n";
+
+/// Merge `second_id` into `first_id`: beats and discovery notes are appended after
+/// the first scene's own, synopses, notes (and page-mode prose) are concatenated,
+/// custom field values the first scene leaves empty are taken from the second, and
+/// character, location and reference item links are carried over before the second
+/// scene is deleted. Callers are responsible for checking locks and that both scenes
+/// share a chapter.
+pub fn merge_scenes(conn: &Connection, first_id: &Uuid, second_id: &Uuid) -> Result<Scene> {
+    let tx = conn.unchecked_transaction()?;
+
+    let first =
+        get_scene_by_id(&tx, first_id)?.ok_or_else(|| rusqlite::Error::QueryReturnedNoRows)?;
+    let second =
+        get_scene_by_id(&tx, second_id)?.ok_or_else(|| rusqlite::Error::QueryReturnedNoRows)?;
+
+    let base_position = get_max_beat_position(&tx, first_id)? + 1;
+    for (offset, beat) in get_beats(&tx, second_id)?.iter().enumerate() {
+        tx.execute(
+            "UPDATE beats SET scene_id = ?1, position = ?2 WHERE id = ?3",
+            params![
+                first_id.to_string(),
+                base_position + offset as i32,
+                beat.id.to_string()
+            ],
+        )?;
+    }
+
+    let join = |a: Option<String>, b: Option<String>, sep: &str| match (a, b) {
+        (Some(a), Some(b)) if !a.trim().is_empty() && !b.trim().is_empty() => {
+            Some(format!("{}{}{}", a, sep, b))
+        }
+        (Some(a), _) if !a.trim().is_empty() => Some(a),
+        (_, b) => b,
+    };
+    let synopsis = join(first.synopsis, second.synopsis, MERGED_SYNOPSIS_SEPARATOR);
+    let prose = join(first.prose, second.prose, "<hr>");
+    let notes = join(first.notes, second.notes, MERGED_SYNOPSIS_SEPARATOR);
+    tx.execute(
+        "UPDATE scenes SET synopsis = ?1, prose = ?2, notes = ?3 WHERE id = ?4",
+        params![synopsis, prose, notes, first_id.to_string()],
+    )?;
+
+    tx.execute(
+        "UPDATE discovery_notes
+         SET scene_id = ?1,
+             position = position + 1 + (SELECT COALESCE(MAX(position), -1)
+                                        FROM discovery_notes WHERE scene_id = ?1)
+         WHERE scene_id = ?2",
+        params![first_id.to_string(), second_id.to_string()],
+    )?;
+
+    let first_values = super::fields::get_field_values(&tx, first_id)?;
+    for value in super::fields::get_field_values(&tx, second_id)? {
+        let first_has_value = first_values.iter().any(|v| {
+            v.field_definition_id == value.field_definition_id
+                && v.value.as_deref().is_some_and(|s| !s.trim().is_empty())
+        });
+        if !first_has_value {
+            super::fields::set_field_value(
+                &tx,
+                &value.field_definition_id,
+                first_id,
+                value.value.as_deref(),
+            )?;
+        }
+    }
+    tx.execute(
+        "DELETE FROM field_values WHERE entity_id = ?1",
+        params![second_id.to_string()],
+    )?;
+
+    for character_id in get_scene_characters(&tx, second_id)? {
+        add_scene_character_ref(&tx, first_id, &character_id)?;
+    }
+    for location_id in get_scene_locations(&tx, second_id)? {
+        add_scene_location_ref(&tx, first_id, &location_id)?;
+    }
+    for item_id in get_scene_reference_item_ids(&tx, second_id)? {
+        add_scene_reference_item_ref(&tx, first_id, &item_id)?;
+    }
+    for tag in super::tags::get_scene_tags(&tx, second_id)? {
+        super::tags::add_scene_tag(&tx, first_id, &tag)?;
+    }
+
+    for table in [
+        "scene_character_refs",
+        "scene_location_refs",
+        "scene_reference_item_refs",
+        "scene_reference_state",
+        "scene_tags",
+    ] {
+        tx.execute(
+            &format!("DELETE FROM {} WHERE scene_id = ?1", table),
+            params![second_id.to_string()],
+        )?;
+    }
+    tx.execute(
+        "DELETE FROM scenes WHERE id = ?1",
+        params![second_id.to_string()],
+    )?;
+    tx.execute(
+        "UPDATE scenes SET position = position - 1 WHERE chapter_id = ?1 AND position > ?2",
+        params![second.chapter_id.to_string(), second.position],
+    )?;
+
+    tx.commit()?;
+
+    get_scene_by_id(conn, first_id)?.ok_or_else(|| rusqlite::Error::QueryReturnedNoRows)
+}
+
 // ============================================================================
 // Re-import / Merge Queries
 // ============================================================================
@@ -1693,7 +2521,7 @@ pub fn find_chapter_by_source_id(
     source_id: &str,
 ) -> Result<Option<Chapter>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status
+        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target
          FROM chapters WHERE project_id = ?1 AND source_id = ?2",
     )?;
 
@@ -1714,6 +2542,8 @@ pub fn find_chapter_by_source_id(
                 .get::<_, String>(9)
                 .map(|s| PlanningStatus::parse(&s))
                 .unwrap_or_default(),
+            color: row.get(10).unwrap_or(None),
+            word_target: row.get(11).unwrap_or(None),
         }))
     } else {
         Ok(None)
@@ -1727,7 +2557,7 @@ pub fn find_scene_by_source_id(
     source_id: &str,
 ) -> Result<Option<Scene>> {
     let mut stmt = conn.prepare(
-        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode
+        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode, compile_position, include_in_compile, color, pov_character_id, notes, word_target
          FROM scenes WHERE chapter_id = ?1 AND source_id = ?2",
     )?;
 
@@ -1822,17 +2652,20 @@ pub fn update_scene_source_id(conn: &Connection, scene_id: &Uuid, source_id: &st
     Ok(())
 }
 
+/// Update a scene's type and status; a custom status label is stored in place
+/// of the built-in status
 pub fn update_scene_metadata(
     conn: &Connection,
     scene_id: &Uuid,
     scene_type: &SceneType,
     scene_status: &SceneStatus,
+    custom_status: Option<&str>,
 ) -> Result<()> {
     conn.execute(
         "UPDATE scenes SET scene_type = ?1, scene_status = ?2 WHERE id = ?3",
         params![
             scene_type.as_str(),
-            scene_status.as_str(),
+            custom_status.unwrap_or(scene_status.as_str()),
             scene_id.to_string()
         ],
     )?;
@@ -1863,6 +2696,50 @@ pub fn update_chapter_planning_status(
     Ok(())
 }
 
+/// Set or clear a chapter's color label. Callers validate the value first.
+pub fn set_chapter_color(conn: &Connection, chapter_id: &Uuid, color: Option<&str>) -> Result<()> {
+    conn.execute(
+        "UPDATE chapters SET color = ?1 WHERE id = ?2",
+        params![color, chapter_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Set or clear a chapter's word-count goal
+pub fn set_chapter_word_target(
+    conn: &Connection,
+    chapter_id: &Uuid,
+    word_target: Option<i32>,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE chapters SET word_target = ?1 WHERE id = ?2",
+        params![word_target, chapter_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Set or clear a scene's color label. Callers validate the value first.
+pub fn set_scene_color(conn: &Connection, scene_id: &Uuid, color: Option<&str>) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET color = ?1 WHERE id = ?2",
+        params![color, scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Set or clear a scene's point-of-view character
+pub fn set_scene_pov(
+    conn: &Connection,
+    scene_id: &Uuid,
+    character_id: Option<&Uuid>,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET pov_character_id = ?1 WHERE id = ?2",
+        params![character_id.map(|id| id.to_string()), scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
 pub fn update_chapter_synopsis(
     conn: &Connection,
     chapter_id: &Uuid,
@@ -1892,6 +2769,28 @@ pub fn update_beat_source_id(conn: &Connection, beat_id: &Uuid, source_id: &str)
     Ok(())
 }
 
+/// Unlink a beat from its source item so a reimport no longer matches it
+pub fn clear_beat_source_id(conn: &Connection, beat_id: &Uuid) -> Result<()> {
+    conn.execute(
+        "UPDATE beats SET source_id = NULL WHERE id = ?1",
+        params![beat_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Unlink a scene and its beats from their source items
+pub fn detach_scene_from_source(conn: &Connection, scene_id: &Uuid) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET source_id = NULL WHERE id = ?1",
+        params![scene_id.to_string()],
+    )?;
+    conn.execute(
+        "UPDATE beats SET source_id = NULL WHERE scene_id = ?1",
+        params![scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
 /// Get all chapters for a project (for reimport)
 pub fn get_all_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Chapter>> {
     get_chapters(conn, project_id)
@@ -1900,7 +2799,7 @@ pub fn get_all_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Chap
 /// Get all scenes for a project across all chapters (for reimport stats)
 pub fn get_all_project_scenes(conn: &Connection, project_id: &Uuid) -> Result<Vec<Scene>> {
     let mut stmt = conn.prepare(
-        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode
+        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode, s.compile_position, s.include_in_compile, s.color, s.pov_character_id, s.notes, s.word_target
          FROM scenes s
          JOIN chapters c ON s.chapter_id = c.id
          WHERE c.project_id = ?1
@@ -1961,9 +2860,44 @@ pub fn restore_chapter(conn: &Connection, chapter_id: &Uuid) -> Result<()> {
     Ok(())
 }
 
+/// Archive a chapter and its scenes. Scenes that weren't already archived are
+/// marked as archived by the chapter, so restoring it brings back only those.
+/// Returns the number of scenes archived.
+pub fn archive_chapter_cascade(conn: &Connection, chapter_id: &Uuid) -> Result<usize> {
+    let tx = conn.unchecked_transaction()?;
+    let scenes = tx.execute(
+        "UPDATE scenes SET archived = 1, archived_by_parent = 1
+         WHERE chapter_id = ?1 AND archived = 0",
+        params![chapter_id.to_string()],
+    )?;
+    tx.execute(
+        "UPDATE chapters SET archived = 1 WHERE id = ?1",
+        params![chapter_id.to_string()],
+    )?;
+    tx.commit()?;
+    Ok(scenes)
+}
+
+/// Restore a chapter and the scenes `archive_chapter_cascade` archived with it;
+/// scenes archived on their own stay archived. Returns the number of scenes restored.
+pub fn restore_chapter_cascade(conn: &Connection, chapter_id: &Uuid) -> Result<usize> {
+    let tx = conn.unchecked_transaction()?;
+    let scenes = tx.execute(
+        "UPDATE scenes SET archived = 0, archived_by_parent = 0
+         WHERE chapter_id = ?1 AND archived_by_parent = 1",
+        params![chapter_id.to_string()],
+    )?;
+    tx.execute(
+        "UPDATE chapters SET archived = 0 WHERE id = ?1",
+        params![chapter_id.to_string()],
+    )?;
+    tx.commit()?;
+    Ok(scenes)
+}
+
 pub fn archive_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
     conn.execute(
-        "UPDATE scenes SET archived = 1 WHERE id = ?1",
+        "UPDATE scenes SET archived = 1, archived_by_parent = 0 WHERE id = ?1",
         params![scene_id.to_string()],
     )?;
     Ok(())
@@ -1971,7 +2905,7 @@ pub fn archive_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
 
 pub fn restore_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
     conn.execute(
-        "UPDATE scenes SET archived = 0 WHERE id = ?1",
+        "UPDATE scenes SET archived = 0, archived_by_parent = 0 WHERE id = ?1",
         params![scene_id.to_string()],
     )?;
     Ok(())
@@ -1979,8 +2913,8 @@ pub fn restore_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
 
 pub fn get_archived_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Chapter>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status
-         FROM chapters WHERE project_id = ?1 AND archived = 1 ORDER BY position",
+        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target
+         FROM chapters WHERE project_id = ?1 AND archived = 1 AND trashed = 0 ORDER BY position",
     )?;
 
     let chapters = stmt
@@ -1992,10 +2926,10 @@ pub fn get_archived_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec
 
 pub fn get_archived_scenes(conn: &Connection, project_id: &Uuid) -> Result<Vec<Scene>> {
     let mut stmt = conn.prepare(
-        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode
+        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode, s.compile_position, s.include_in_compile, s.color, s.pov_character_id, s.notes, s.word_target
          FROM scenes s
          JOIN chapters c ON s.chapter_id = c.id
-         WHERE c.project_id = ?1 AND s.archived = 1
+         WHERE c.project_id = ?1 AND s.archived = 1 AND s.trashed = 0
          ORDER BY c.position, s.position",
     )?;
 
@@ -2006,6 +2940,145 @@ pub fn get_archived_scenes(conn: &Connection, project_id: &Uuid) -> Result<Vec<S
     Ok(scenes)
 }
 
+// ============================================================================
+// Trash Operations
+// ============================================================================
+
+pub fn trash_chapter(conn: &Connection, chapter_id: &Uuid) -> Result<()> {
+    conn.execute(
+        "UPDATE chapters SET trashed = 1, trashed_at = ?1 WHERE id = ?2",
+        params![chrono::Utc::now().to_rfc3339(), chapter_id.to_string()],
+    )?;
+    Ok(())
+}
+
+pub fn restore_from_trash_chapter(conn: &Connection, chapter_id: &Uuid) -> Result<()> {
+    conn.execute(
+        "UPDATE chapters SET trashed = 0, trashed_at = NULL WHERE id = ?1",
+        params![chapter_id.to_string()],
+    )?;
+    Ok(())
+}
+
+pub fn trash_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET trashed = 1, trashed_at = ?1 WHERE id = ?2",
+        params![chrono::Utc::now().to_rfc3339(), scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
+pub fn restore_from_trash_scene(conn: &Connection, scene_id: &Uuid) -> Result<()> {
+    conn.execute(
+        "UPDATE scenes SET trashed = 0, trashed_at = NULL WHERE id = ?1",
+        params![scene_id.to_string()],
+    )?;
+    Ok(())
+}
+
+/// Trash and cascade-archive flags of a project's chapters and scenes (for snapshots)
+pub fn get_binder_item_flags(conn: &Connection, project_id: &Uuid) -> Result<Vec<BinderItemFlags>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, trashed, trashed_at, 0 FROM chapters
+         WHERE project_id = ?1 AND trashed = 1
+         UNION ALL
+         SELECT s.id, s.trashed, s.trashed_at, s.archived_by_parent FROM scenes s
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1 AND (s.trashed = 1 OR s.archived_by_parent = 1)",
+    )?;
+    let flags = stmt
+        .query_map(params![project_id.to_string()], |row| {
+            Ok(BinderItemFlags {
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                trashed: row.get(1)?,
+                trashed_at: row.get(2)?,
+                archived_by_parent: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(flags)
+}
+
+/// Reapply flags read by [`get_binder_item_flags`] to the chapter or scene with `flags.id`
+pub fn set_binder_item_flags(conn: &Connection, flags: &BinderItemFlags) -> Result<()> {
+    conn.execute(
+        "UPDATE chapters SET trashed = ?1, trashed_at = ?2 WHERE id = ?3",
+        params![flags.trashed, flags.trashed_at, flags.id.to_string()],
+    )?;
+    conn.execute(
+        "UPDATE scenes SET trashed = ?1, trashed_at = ?2, archived_by_parent = ?3 WHERE id = ?4",
+        params![
+            flags.trashed,
+            flags.trashed_at,
+            flags.archived_by_parent,
+            flags.id.to_string()
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn get_trashed_chapters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Chapter>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target
+         FROM chapters WHERE project_id = ?1 AND trashed = 1 ORDER BY trashed_at DESC",
+    )?;
+
+    let chapters = stmt
+        .query_map(params![project_id.to_string()], chapter_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(chapters)
+}
+
+/// Get scenes trashed on their own (scenes inside a trashed chapter are not listed)
+pub fn get_trashed_scenes(conn: &Connection, project_id: &Uuid) -> Result<Vec<Scene>> {
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode, s.compile_position, s.include_in_compile, s.color, s.pov_character_id, s.notes, s.word_target
+         FROM scenes s
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1 AND s.trashed = 1
+         ORDER BY s.trashed_at DESC",
+    )?;
+
+    let scenes = stmt
+        .query_map(params![project_id.to_string()], scene_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(scenes)
+}
+
+/// Permanently delete chapters and scenes trashed at or before `cutoff` (RFC 3339).
+/// Returns the number of (chapters, scenes) deleted.
+pub fn delete_trash_older_than(
+    conn: &Connection,
+    project_id: &Uuid,
+    cutoff: &str,
+) -> Result<(usize, usize)> {
+    let chapter_ids: Vec<String> = conn
+        .prepare(
+            "SELECT id FROM chapters WHERE project_id = ?1 AND trashed = 1 AND trashed_at <= ?2",
+        )?
+        .query_map(params![project_id.to_string(), cutoff], |row| row.get(0))?
+        .collect::<Result<Vec<_>>>()?;
+    let scene_ids: Vec<String> = conn
+        .prepare(
+            "SELECT s.id FROM scenes s
+             JOIN chapters c ON s.chapter_id = c.id
+             WHERE c.project_id = ?1 AND s.trashed = 1 AND s.trashed_at <= ?2",
+        )?
+        .query_map(params![project_id.to_string(), cutoff], |row| row.get(0))?
+        .collect::<Result<Vec<_>>>()?;
+
+    for id in &scene_ids {
+        delete_scene(conn, &parse_uuid(id)?)?;
+    }
+    for id in &chapter_ids {
+        delete_chapter(conn, &parse_uuid(id)?)?;
+    }
+
+    Ok((chapter_ids.len(), scene_ids.len()))
+}
+
 // ============================================================================
 // Lock Operations
 // ============================================================================
@@ -2069,6 +3142,33 @@ pub fn is_chapter_locked(conn: &Connection, chapter_id: &Uuid) -> Result<bool> {
     Ok(locked != 0)
 }
 
+/// Whether a chapter is in the trash (false if it does not exist)
+pub fn is_chapter_trashed(conn: &Connection, chapter_id: &Uuid) -> Result<bool> {
+    let trashed: Option<i32> = conn
+        .query_row(
+            "SELECT trashed FROM chapters WHERE id = ?1",
+            params![chapter_id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(trashed.unwrap_or(0) != 0)
+}
+
+/// Whether a scene is in the trash, on its own or along with its chapter
+/// (false if it does not exist)
+pub fn is_scene_trashed(conn: &Connection, scene_id: &Uuid) -> Result<bool> {
+    let trashed: Option<(i32, i32)> = conn
+        .query_row(
+            "SELECT s.trashed, c.trashed FROM scenes s
+             JOIN chapters c ON s.chapter_id = c.id
+             WHERE s.id = ?1",
+            params![scene_id.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    Ok(trashed.is_some_and(|(scene, chapter)| scene != 0 || chapter != 0))
+}
+
 // ============================================================================
 // Rename Operations
 // ============================================================================
@@ -2095,7 +3195,7 @@ pub fn rename_scene(conn: &Connection, scene_id: &Uuid, title: &str) -> Result<(
 
 pub fn get_chapter_by_id(conn: &Connection, chapter_id: &Uuid) -> Result<Option<Chapter>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status
+        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target
          FROM chapters WHERE id = ?1",
     )?;
 
@@ -2108,7 +3208,7 @@ pub fn get_chapter_by_id(conn: &Connection, chapter_id: &Uuid) -> Result<Option<
 
 pub fn get_scene_by_id(conn: &Connection, scene_id: &Uuid) -> Result<Option<Scene>> {
     let mut stmt = conn.prepare(
-        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode
+        "SELECT id, chapter_id, title, synopsis, prose, position, source_id, archived, locked, scene_type, scene_status, planning_status, editor_mode, compile_position, include_in_compile, color, pov_character_id, notes, word_target
          FROM scenes WHERE id = ?1",
     )?;
 
@@ -2123,12 +3223,34 @@ pub fn get_scene_by_id(conn: &Connection, scene_id: &Uuid) -> Result<Option<Scen
 // Snapshot Queries
 // ============================================================================
 
-pub fn insert_snapshot_metadata(conn: &Connection, snapshot: &SnapshotMetadata) -> Result<()> {
-    conn.execute(
-        "INSERT INTO snapshots (id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
-        params![
-            snapshot.id.to_string(),
+fn snapshot_from_row(row: &rusqlite::Row) -> Result<SnapshotMetadata> {
+    Ok(SnapshotMetadata {
+        id: parse_uuid(&row.get::<_, String>(0)?)?,
+        project_id: parse_uuid(&row.get::<_, String>(1)?)?,
+        name: row.get(2)?,
+        description: row.get(3)?,
+        trigger_type: SnapshotTrigger::parse(&row.get::<_, String>(4)?)
+            .unwrap_or(SnapshotTrigger::Manual),
+        created_at: row.get(5)?,
+        file_path: row.get(6)?,
+        file_size: row.get(7)?,
+        uncompressed_size: row.get(8)?,
+        chapter_count: row.get(9)?,
+        scene_count: row.get(10)?,
+        beat_count: row.get(11)?,
+        word_count: row.get(12)?,
+        schema_version: row.get(13)?,
+        compression: SnapshotCompression::parse(&row.get::<_, String>(14)?).unwrap_or_default(),
+        checksum: row.get(15)?,
+    })
+}
+
+pub fn insert_snapshot_metadata(conn: &Connection, snapshot: &SnapshotMetadata) -> Result<()> {
+    conn.execute(
+        "INSERT INTO snapshots (id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version, compression, checksum)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+        params![
+            snapshot.id.to_string(),
             snapshot.project_id.to_string(),
             snapshot.name,
             snapshot.description,
@@ -2142,6 +3264,8 @@ pub fn insert_snapshot_metadata(conn: &Connection, snapshot: &SnapshotMetadata)
             snapshot.beat_count,
             snapshot.word_count,
             snapshot.schema_version,
+            snapshot.compression.as_str(),
+            snapshot.checksum,
         ],
     )?;
     Ok(())
@@ -2152,30 +3276,12 @@ pub fn get_snapshots_for_project(
     project_id: &Uuid,
 ) -> Result<Vec<SnapshotMetadata>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version
+        "SELECT id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version, compression, checksum
          FROM snapshots WHERE project_id = ?1 ORDER BY created_at DESC",
     )?;
 
     let snapshots = stmt
-        .query_map(params![project_id.to_string()], |row| {
-            Ok(SnapshotMetadata {
-                id: parse_uuid(&row.get::<_, String>(0)?)?,
-                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
-                name: row.get(2)?,
-                description: row.get(3)?,
-                trigger_type: SnapshotTrigger::parse(&row.get::<_, String>(4)?)
-                    .unwrap_or(SnapshotTrigger::Manual),
-                created_at: row.get(5)?,
-                file_path: row.get(6)?,
-                file_size: row.get(7)?,
-                uncompressed_size: row.get(8)?,
-                chapter_count: row.get(9)?,
-                scene_count: row.get(10)?,
-                beat_count: row.get(11)?,
-                word_count: row.get(12)?,
-                schema_version: row.get(13)?,
-            })
-        })?
+        .query_map(params![project_id.to_string()], snapshot_from_row)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(snapshots)
@@ -2186,35 +3292,63 @@ pub fn get_snapshot_by_id(
     snapshot_id: &Uuid,
 ) -> Result<Option<SnapshotMetadata>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version
+        "SELECT id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version, compression, checksum
          FROM snapshots WHERE id = ?1",
     )?;
 
     let snapshot = stmt
-        .query_row(params![snapshot_id.to_string()], |row| {
-            Ok(SnapshotMetadata {
-                id: parse_uuid(&row.get::<_, String>(0)?)?,
-                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
-                name: row.get(2)?,
-                description: row.get(3)?,
-                trigger_type: SnapshotTrigger::parse(&row.get::<_, String>(4)?)
-                    .unwrap_or(SnapshotTrigger::Manual),
-                created_at: row.get(5)?,
-                file_path: row.get(6)?,
-                file_size: row.get(7)?,
-                uncompressed_size: row.get(8)?,
-                chapter_count: row.get(9)?,
-                scene_count: row.get(10)?,
-                beat_count: row.get(11)?,
-                word_count: row.get(12)?,
-                schema_version: row.get(13)?,
-            })
-        })
+        .query_row(params![snapshot_id.to_string()], snapshot_from_row)
         .optional()?;
 
     Ok(snapshot)
 }
 
+/// Most recent snapshot for a project, of any trigger type
+pub fn get_latest_snapshot(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Option<SnapshotMetadata>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version, compression, checksum
+         FROM snapshots WHERE project_id = ?1 ORDER BY created_at DESC LIMIT 1",
+    )?;
+
+    stmt.query_row(params![project_id.to_string()], snapshot_from_row)
+        .optional()
+}
+
+/// Non-manual snapshots outside a retention policy: not among the `keep_last_n`
+/// most recent snapshots (of any trigger) and, when `cutoff` is given, created
+/// before it. Manual snapshots are never returned.
+pub fn get_snapshots_outside_retention(
+    conn: &Connection,
+    project_id: &Uuid,
+    keep_last_n: usize,
+    cutoff: Option<&str>,
+) -> Result<Vec<SnapshotMetadata>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, name, description, trigger_type, created_at, file_path, file_size, uncompressed_size, chapter_count, scene_count, beat_count, word_count, schema_version, compression, checksum
+         FROM snapshots
+         WHERE project_id = ?1
+           AND trigger_type != 'manual'
+           AND id NOT IN (
+               SELECT id FROM snapshots WHERE project_id = ?1
+               ORDER BY created_at DESC LIMIT ?2
+           )
+           AND (?3 IS NULL OR created_at < ?3)
+         ORDER BY created_at",
+    )?;
+
+    let snapshots = stmt
+        .query_map(
+            params![project_id.to_string(), keep_last_n as i64, cutoff],
+            snapshot_from_row,
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(snapshots)
+}
+
 pub fn delete_snapshot_metadata(conn: &Connection, snapshot_id: &Uuid) -> Result<()> {
     conn.execute(
         "DELETE FROM snapshots WHERE id = ?1",
@@ -2362,7 +3496,7 @@ pub fn get_all_chapters_including_archived(
     project_id: &Uuid,
 ) -> Result<Vec<Chapter>> {
     let mut stmt = conn.prepare(
-        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status
+        "SELECT id, project_id, title, position, source_id, archived, locked, is_part, synopsis, planning_status, color, word_target
          FROM chapters WHERE project_id = ?1 ORDER BY position",
     )?;
 
@@ -2379,7 +3513,7 @@ pub fn get_all_scenes_including_archived(
     project_id: &Uuid,
 ) -> Result<Vec<Scene>> {
     let mut stmt = conn.prepare(
-        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode
+        "SELECT s.id, s.chapter_id, s.title, s.synopsis, s.prose, s.position, s.source_id, s.archived, s.locked, s.scene_type, s.scene_status, s.planning_status, s.editor_mode, s.compile_position, s.include_in_compile, s.color, s.pov_character_id, s.notes, s.word_target
          FROM scenes s
          JOIN chapters c ON s.chapter_id = c.id
          WHERE c.project_id = ?1
@@ -2421,6 +3555,9 @@ pub fn get_all_beats_for_project(conn: &Connection, project_id: &Uuid) -> Result
 }
 
 /// Delete all project content (for restore)
+///
+/// This issues many deletes and opens no transaction of its own, so callers must
+/// run it inside one; snapshot restore does so it can roll back with the reinsert.
 pub fn delete_all_project_content(conn: &Connection, project_id: &Uuid) -> Result<()> {
     // Delete scene references first
     conn.execute(
@@ -2455,6 +3592,14 @@ pub fn delete_all_project_content(conn: &Connection, project_id: &Uuid) -> Resul
         )",
         params![project_id.to_string()],
     )?;
+    conn.execute(
+        "DELETE FROM scene_tags WHERE scene_id IN (
+            SELECT s.id FROM scenes s
+            JOIN chapters c ON s.chapter_id = c.id
+            WHERE c.project_id = ?1
+        )",
+        params![project_id.to_string()],
+    )?;
 
     // Delete beats
     conn.execute(
@@ -2523,8 +3668,10 @@ pub fn delete_all_project_content(conn: &Connection, project_id: &Uuid) -> Resul
 pub fn update_project(conn: &Connection, project: &Project) -> Result<()> {
     let reference_types_json =
         serde_json::to_string(&project.reference_types).unwrap_or_else(|_| "[]".to_string());
+    let scene_statuses_json =
+        serde_json::to_string(&project.scene_statuses).unwrap_or_else(|_| "[]".to_string());
     conn.execute(
-        "UPDATE projects SET name = ?1, source_type = ?2, source_path = ?3, modified_at = ?4, author_pen_name = ?5, genre = ?6, description = ?7, word_target = ?8, reference_types = ?9, project_type = ?10, target_page_count = ?11 WHERE id = ?12",
+        "UPDATE projects SET name = ?1, source_type = ?2, source_path = ?3, modified_at = ?4, author_pen_name = ?5, genre = ?6, description = ?7, word_target = ?8, reference_types = ?9, project_type = ?10, target_page_count = ?11, scene_statuses = ?12, publisher = ?13, isbn = ?14, series_name = ?15, series_index = ?16 WHERE id = ?17",
         params![
             project.name,
             project.source_type.as_str(),
@@ -2537,12 +3684,614 @@ pub fn update_project(conn: &Connection, project: &Project) -> Result<()> {
             reference_types_json,
             project.project_type,
             project.target_page_count,
+            scene_statuses_json,
+            project.publisher,
+            project.isbn,
+            project.series_name,
+            project.series_index,
             project.id.to_string(),
         ],
     )?;
     Ok(())
 }
 
+// ============================================================================
+// Writing Session Queries
+// ============================================================================
+
+fn writing_session_from_row(row: &rusqlite::Row) -> rusqlite::Result<WritingSession> {
+    Ok(WritingSession {
+        id: parse_uuid(&row.get::<_, String>(0)?)?,
+        project_id: parse_uuid(&row.get::<_, String>(1)?)?,
+        date: row.get(2)?,
+        start_word_count: row.get(3)?,
+        end_word_count: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
+
+/// Insert a writing session, or update its end word count if it already exists
+pub fn record_session_snapshot(conn: &Connection, session: &WritingSession) -> Result<()> {
+    conn.execute(
+        "INSERT INTO writing_sessions (id, project_id, date, start_word_count, end_word_count, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(id) DO UPDATE SET end_word_count = excluded.end_word_count",
+        params![
+            session.id.to_string(),
+            session.project_id.to_string(),
+            session.date,
+            session.start_word_count,
+            session.end_word_count,
+            session.created_at,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Get the most recent writing session for a project that has not been ended
+pub fn get_open_writing_session(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Option<WritingSession>> {
+    conn.query_row(
+        "SELECT id, project_id, date, start_word_count, end_word_count, created_at
+         FROM writing_sessions WHERE project_id = ?1 AND end_word_count IS NULL
+         ORDER BY created_at DESC LIMIT 1",
+        params![project_id.to_string()],
+        writing_session_from_row,
+    )
+    .optional()
+}
+
+/// Get a project's writing sessions between two dates (YYYY-MM-DD, inclusive)
+pub fn get_sessions_for_range(
+    conn: &Connection,
+    project_id: &Uuid,
+    start_date: &str,
+    end_date: &str,
+) -> Result<Vec<WritingSession>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, date, start_word_count, end_word_count, created_at
+         FROM writing_sessions WHERE project_id = ?1 AND date >= ?2 AND date <= ?3
+         ORDER BY date, created_at",
+    )?;
+    let sessions = stmt
+        .query_map(
+            params![project_id.to_string(), start_date, end_date],
+            writing_session_from_row,
+        )?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(sessions)
+}
+
+// ============================================================================
+// Search Queries
+// ============================================================================
+
+/// Number of characters of context shown on each side of a search match
+const SEARCH_SNIPPET_CONTEXT: usize = 40;
+
+/// Case-insensitive search over scene titles, synopses, scene prose and beat prose.
+///
+/// SQLite `LIKE` narrows the candidate rows; HTML is then stripped and the match
+/// is located in the plain text so snippets and offsets never include markup.
+/// Archived chapters and scenes are skipped. When `chapter_id` is set, only
+/// scenes in that chapter are searched. Each matching field yields one hit.
+pub fn search_text(
+    conn: &Connection,
+    project_id: &Uuid,
+    query: &str,
+    chapter_id: Option<&Uuid>,
+) -> Result<Vec<SearchHit>> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pattern = format!(
+        "%{}%",
+        query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    );
+    let chapter_filter = chapter_id.map(|id| id.to_string());
+
+    // (chapter position, scene position, field rank, beat position) for ordering
+    let mut ranked: Vec<((i32, i32, u8, i32), SearchHit)> = Vec::new();
+
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.chapter_id, c.position, s.position, s.title, s.synopsis, s.prose
+         FROM scenes s
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1 AND c.archived = 0 AND s.archived = 0
+           AND c.trashed = 0 AND s.trashed = 0
+           AND (?2 IS NULL OR c.id = ?2)
+           AND (s.title LIKE ?3 ESCAPE '\\' OR s.synopsis LIKE ?3 ESCAPE '\\'
+                OR s.prose LIKE ?3 ESCAPE '\\')",
+    )?;
+    let scene_rows = stmt.query_map(
+        params![project_id.to_string(), chapter_filter, pattern],
+        |row| {
+            Ok((
+                parse_uuid(&row.get::<_, String>(0)?)?,
+                parse_uuid(&row.get::<_, String>(1)?)?,
+                row.get::<_, i32>(2)?,
+                row.get::<_, i32>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+            ))
+        },
+    )?;
+
+    for row in scene_rows {
+        let (scene_id, chapter_id, chapter_pos, scene_pos, title, synopsis, prose) = row?;
+        let fields = [
+            (SearchField::Title, Some(title)),
+            (SearchField::Synopsis, synopsis),
+            (SearchField::Prose, prose),
+        ];
+        for (rank, (field, text)) in fields.into_iter().enumerate() {
+            let Some(text) = text else { continue };
+            if let Some((snippet, match_offset)) = search_snippet(&text, query) {
+                ranked.push((
+                    (chapter_pos, scene_pos, rank as u8, 0),
+                    SearchHit {
+                        chapter_id,
+                        scene_id,
+                        beat_id: None,
+                        field,
+                        snippet,
+                        match_offset,
+                    },
+                ));
+            }
+        }
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT b.id, b.scene_id, s.chapter_id, c.position, s.position, b.position, b.prose
+         FROM beats b
+         JOIN scenes s ON b.scene_id = s.id
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1 AND c.archived = 0 AND s.archived = 0
+           AND c.trashed = 0 AND s.trashed = 0
+           AND (?2 IS NULL OR c.id = ?2)
+           AND b.prose LIKE ?3 ESCAPE '\\'",
+    )?;
+    let beat_rows = stmt.query_map(
+        params![project_id.to_string(), chapter_filter, pattern],
+        |row| {
+            Ok((
+                parse_uuid(&row.get::<_, String>(0)?)?,
+                parse_uuid(&row.get::<_, String>(1)?)?,
+                parse_uuid(&row.get::<_, String>(2)?)?,
+                row.get::<_, i32>(3)?,
+                row.get::<_, i32>(4)?,
+                row.get::<_, i32>(5)?,
+                row.get::<_, String>(6)?,
+            ))
+        },
+    )?;
+
+    for row in beat_rows {
+        let (beat_id, scene_id, chapter_id, chapter_pos, scene_pos, beat_pos, prose) = row?;
+        if let Some((snippet, match_offset)) = search_snippet(&prose, query) {
+            ranked.push((
+                (chapter_pos, scene_pos, 3, beat_pos),
+                SearchHit {
+                    chapter_id,
+                    scene_id,
+                    beat_id: Some(beat_id),
+                    field: SearchField::Prose,
+                    snippet,
+                    match_offset,
+                },
+            ));
+        }
+    }
+
+    ranked.sort_by_key(|(key, _)| *key);
+    Ok(ranked.into_iter().map(|(_, hit)| hit).collect())
+}
+
+/// Locate `query` in the plain text of `html` and return a snippet plus the
+/// character offset of the match, or `None` if it only matched inside markup.
+fn search_snippet(html: &str, query: &str) -> Option<(String, usize)> {
+    let plain: Vec<char> = crate::detect::strip_html(html)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+    let needle: Vec<char> = query.chars().collect();
+    if needle.is_empty() || needle.len() > plain.len() {
+        return None;
+    }
+
+    let offset = (0..=plain.len() - needle.len()).find(|&start| {
+        needle
+            .iter()
+            .zip(&plain[start..])
+            .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+    })?;
+
+    let start = offset.saturating_sub(SEARCH_SNIPPET_CONTEXT);
+    let end = (offset + needle.len() + SEARCH_SNIPPET_CONTEXT).min(plain.len());
+    let mut snippet: String = plain[start..end].iter().collect();
+    if start > 0 {
+        snippet.insert(0, '\u{2026}');
+    }
+    if end < plain.len() {
+        snippet.push('\u{2026}');
+    }
+
+    Some((snippet, offset))
+}
+
+// ============================================================================
+// Find and Replace
+// ============================================================================
+
+/// Prose of one scene or beat that find-and-replace may rewrite
+struct ProseField {
+    scene_id: Uuid,
+    beat_id: Option<Uuid>,
+    html: String,
+}
+
+/// Scene and beat prose in reading order, skipping anything locked, archived
+/// or trashed (on the scene or its chapter).
+fn editable_prose_fields(conn: &Connection, project_id: &Uuid) -> Result<Vec<ProseField>> {
+    let mut stmt = conn.prepare(
+        "SELECT s.id, NULL, s.prose, c.position, s.position, -1
+         FROM scenes s
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1 AND s.prose IS NOT NULL
+           AND c.locked = 0 AND s.locked = 0
+           AND c.archived = 0 AND s.archived = 0 AND c.trashed = 0 AND s.trashed = 0
+         UNION ALL
+         SELECT s.id, b.id, b.prose, c.position, s.position, b.position
+         FROM beats b
+         JOIN scenes s ON b.scene_id = s.id
+         JOIN chapters c ON s.chapter_id = c.id
+         WHERE c.project_id = ?1 AND b.prose IS NOT NULL
+           AND c.locked = 0 AND s.locked = 0
+           AND c.archived = 0 AND s.archived = 0 AND c.trashed = 0 AND s.trashed = 0
+         ORDER BY 4, 5, 6",
+    )?;
+    let fields = stmt
+        .query_map(params![project_id.to_string()], |row| {
+            Ok(ProseField {
+                scene_id: parse_uuid(&row.get::<_, String>(0)?)?,
+                beat_id: row
+                    .get::<_, Option<String>>(1)?
+                    .map(|id| parse_uuid(&id))
+                    .transpose()?,
+                html: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(fields)
+}
+
+/// Split HTML into `(is_text, run)` pieces with the same rule `strip_html`
+/// uses: everything from `<` to the next `>` is markup.
+fn html_runs(html: &str) -> Vec<(bool, &str)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut in_tag = false;
+
+    for (i, ch) in html.char_indices() {
+        match ch {
+            '<' if !in_tag => {
+                if i > start {
+                    runs.push((true, &html[start..i]));
+                }
+                start = i;
+                in_tag = true;
+            }
+            '>' if in_tag => {
+                runs.push((false, &html[start..=i]));
+                start = i + 1;
+                in_tag = false;
+            }
+            _ => {}
+        }
+    }
+    if start < html.len() {
+        runs.push((!in_tag, &html[start..]));
+    }
+
+    runs
+}
+
+/// One character of stored prose text: what it reads as and the raw bytes it
+/// spans. An entity such as `&amp;` is a single unit, so a match can never
+/// start, end or land inside one. Unknown entities read as `None` and never match.
+struct TextUnit {
+    ch: Option<char>,
+    start: usize,
+    end: usize,
+}
+
+/// Longest entity name recognised between `&` and `;`, e.g. `#x1F600`
+const MAX_ENTITY_NAME_LEN: usize = 10;
+
+/// Decode an entity name (the part between `&` and `;`)
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let value = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(value)
+        }
+    }
+}
+
+/// Split a text run of stored HTML into characters, decoding entities
+fn text_units(text: &str) -> Vec<TextUnit> {
+    let mut units = Vec::new();
+    let mut i = 0;
+    while let Some(ch) = text[i..].chars().next() {
+        let entity_len = (ch == '&')
+            .then(|| text[i + 1..].find(';'))
+            .flatten()
+            .filter(|len| (1..=MAX_ENTITY_NAME_LEN).contains(len))
+            .filter(|len| {
+                text[i + 1..i + 1 + len]
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '#')
+            });
+        let unit = match entity_len {
+            Some(len) => TextUnit {
+                ch: decode_entity(&text[i + 1..i + 1 + len]),
+                start: i,
+                end: i + len + 2,
+            },
+            None => TextUnit {
+                ch: Some(ch),
+                start: i,
+                end: i + ch.len_utf8(),
+            },
+        };
+        i = unit.end;
+        units.push(unit);
+    }
+    units
+}
+
+/// Unit index ranges of the non-overlapping matches of the plain-text `needle`
+fn find_text_matches(
+    units: &[TextUnit],
+    needle: &str,
+    case_sensitive: bool,
+) -> Vec<(usize, usize)> {
+    let needle: Vec<char> = needle.chars().collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let same = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= units.len() {
+        let hit = needle
+            .iter()
+            .zip(&units[i..])
+            .all(|(a, unit)| unit.ch.is_some_and(|b| same(*a, b)));
+        if hit {
+            matches.push((i, i + needle.len()));
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+/// Escape text the way the editor stores it between tags
+fn escape_html_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Replace `find` in the text of `html`, leaving tags and their attributes
+/// alone. Text is matched as it reads, so entities match the character they
+/// stand for and are never split. A match never spans a tag. Returns the new
+/// HTML and the count.
+pub fn replace_in_html(
+    html: &str,
+    find: &str,
+    replace: &str,
+    case_sensitive: bool,
+) -> (String, usize) {
+    let replace = escape_html_text(replace);
+    let mut out = String::with_capacity(html.len());
+    let mut count = 0;
+
+    for (is_text, run) in html_runs(html) {
+        if !is_text {
+            out.push_str(run);
+            continue;
+        }
+        let units = text_units(run);
+        let mut last = 0;
+        for (start, end) in find_text_matches(&units, find, case_sensitive) {
+            out.push_str(&run[last..units[start].start]);
+            out.push_str(&replace);
+            last = units[end - 1].end;
+            count += 1;
+        }
+        out.push_str(&run[last..]);
+    }
+
+    (out, count)
+}
+
+/// Replace `find` with `replace` across the project's scene and beat prose.
+///
+/// Locked, archived and trashed scenes and chapters are skipped. Runs in one
+/// transaction and returns the number of replacements made.
+pub fn replace_in_prose(
+    conn: &Connection,
+    project_id: &Uuid,
+    find: &str,
+    replace: &str,
+    case_sensitive: bool,
+) -> Result<usize> {
+    if find.is_empty() {
+        return Ok(0);
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    let mut total = 0;
+
+    for field in editable_prose_fields(&tx, project_id)? {
+        let (updated, count) = replace_in_html(&field.html, find, replace, case_sensitive);
+        if count == 0 {
+            continue;
+        }
+        match field.beat_id {
+            Some(beat_id) => {
+                tx.execute(
+                    "UPDATE beats SET prose = ?1 WHERE id = ?2",
+                    params![updated, beat_id.to_string()],
+                )?;
+            }
+            None => update_scene_prose(&tx, &field.scene_id, &updated)?,
+        }
+        total += count;
+    }
+
+    tx.commit()?;
+    Ok(total)
+}
+
+/// Collapse whitespace so an excerpt of decoded prose reads as plain text.
+/// Edge whitespace is kept as a single space.
+fn snippet_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if !out.ends_with(' ') {
+                out.push(' ');
+            }
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Count what `replace_in_html` would replace in `html` and build an excerpt
+/// around the first match. Returns `None` when nothing matches.
+fn preview_html(
+    html: &str,
+    find: &str,
+    case_sensitive: bool,
+) -> Option<(String, usize, usize, usize)> {
+    let mut plain = String::with_capacity(html.len());
+    let mut first: Option<(usize, usize)> = None;
+    let mut count = 0;
+
+    for (is_text, run) in html_runs(html) {
+        if !is_text {
+            plain.push(' ');
+            continue;
+        }
+        let units = text_units(run);
+        // Offset in `plain` where each unit's decoded text starts, plus the end
+        let mut offsets = Vec::with_capacity(units.len() + 1);
+        for unit in &units {
+            offsets.push(plain.len());
+            match unit.ch {
+                Some(ch) => plain.push(ch),
+                None => plain.push_str(&run[unit.start..unit.end]),
+            }
+        }
+        offsets.push(plain.len());
+
+        let matches = find_text_matches(&units, find, case_sensitive);
+        if first.is_none() {
+            first = matches
+                .first()
+                .map(|&(start, end)| (offsets[start], offsets[end]));
+        }
+        count += matches.len();
+    }
+
+    let (start, end) = first?;
+    let before: Vec<char> = plain[..start].chars().collect();
+    let after: Vec<char> = plain[end..].chars().collect();
+    let before_start = before.len().saturating_sub(SEARCH_SNIPPET_CONTEXT);
+    let after_end = after.len().min(SEARCH_SNIPPET_CONTEXT);
+
+    let mut snippet = String::new();
+    if before_start > 0 {
+        snippet.push('\u{2026}');
+    }
+    snippet.push_str(snippet_text(&before[before_start..].iter().collect::<String>()).trim_start());
+    let highlight_start = snippet.chars().count();
+    let matched = snippet_text(&plain[start..end]);
+    let highlight_length = matched.chars().count();
+    snippet.push_str(&matched);
+    snippet.push_str(snippet_text(&after[..after_end].iter().collect::<String>()).trim_end());
+    if after_end < after.len() {
+        snippet.push('\u{2026}');
+    }
+
+    Some((snippet, highlight_start, highlight_length, count))
+}
+
+/// Preview `replace_in_prose` without writing anything: one entry per scene
+/// or beat that would change, in reading order, with its match count and an
+/// excerpt around the first match.
+pub fn preview_replace_in_prose(
+    conn: &Connection,
+    project_id: &Uuid,
+    find: &str,
+    case_sensitive: bool,
+) -> Result<Vec<ReplacePreview>> {
+    if find.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let previews = editable_prose_fields(conn, project_id)?
+        .into_iter()
+        .filter_map(|field| {
+            let (context_snippet, highlight_start, highlight_length, match_count) =
+                preview_html(&field.html, find, case_sensitive)?;
+            Some(ReplacePreview {
+                scene_id: field.scene_id,
+                beat_id: field.beat_id,
+                context_snippet,
+                highlight_start,
+                highlight_length,
+                match_count,
+            })
+        })
+        .collect();
+    Ok(previews)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2578,6 +4327,8 @@ mod tests {
             is_part: false,
             synopsis: None,
             planning_status: PlanningStatus::Fixed,
+            color: None,
+            word_target: None,
         };
         insert_chapter(conn, &chapter).unwrap();
         chapter
@@ -2598,6 +4349,13 @@ mod tests {
             scene_status: SceneStatus::Draft,
             planning_status: PlanningStatus::Fixed,
             editor_mode: EditorMode::Beat,
+            compile_position: None,
+            include_in_compile: true,
+            color: None,
+            pov_character_id: None,
+            notes: None,
+            word_target: None,
+            custom_status: None,
         };
         insert_scene(conn, &scene).unwrap();
         scene
@@ -2695,6 +4453,8 @@ mod tests {
             is_part: false,
             synopsis: None,
             planning_status: PlanningStatus::Fixed,
+            color: None,
+            word_target: None,
         };
         let ch2 = Chapter {
             id: Uuid::new_v4(),
@@ -2707,6 +4467,8 @@ mod tests {
             is_part: false,
             synopsis: None,
             planning_status: PlanningStatus::Fixed,
+            color: None,
+            word_target: None,
         };
         insert_chapter(&conn, &ch1).unwrap();
         insert_chapter(&conn, &ch2).unwrap();
@@ -2721,6 +4483,48 @@ mod tests {
         assert_eq!(chapters[1].position, 1);
     }
 
+    #[test]
+    fn test_reorder_chapters_rolls_back_on_failure() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let ch1 = Chapter::new(project.id, "Chapter 1".to_string(), 0);
+        let ch2 = Chapter::new(project.id, "Chapter 2".to_string(), 1);
+        let ch3 = Chapter::new(project.id, "Chapter 3".to_string(), 2);
+        for chapter in [&ch1, &ch2, &ch3] {
+            insert_chapter(&conn, chapter).unwrap();
+        }
+
+        let positions = |conn: &Connection| -> Vec<(Uuid, i32)> {
+            get_chapters(conn, &project.id)
+                .unwrap()
+                .into_iter()
+                .map(|c| (c.id, c.position))
+                .collect()
+        };
+        let original = positions(&conn);
+
+        // An id from nowhere fails after the first update has already run
+        let result = reorder_chapters(&conn, &project.id, &[ch3.id, Uuid::new_v4(), ch1.id]);
+        assert!(result.is_err());
+        assert_eq!(positions(&conn), original);
+        assert!(conn.is_autocommit());
+
+        // A failing UPDATE part-way through rolls back the earlier ones too
+        conn.execute(
+            &format!(
+                "CREATE TRIGGER fail_reorder BEFORE UPDATE OF position ON chapters
+                 WHEN NEW.id = '{}' BEGIN SELECT RAISE(ABORT, 'injected'); END",
+                ch1.id
+            ),
+            [],
+        )
+        .unwrap();
+        let result = reorder_chapters(&conn, &project.id, &[ch3.id, ch2.id, ch1.id]);
+        assert!(result.is_err());
+        assert_eq!(positions(&conn), original);
+        assert!(conn.is_autocommit());
+    }
+
     #[test]
     fn test_rename_chapter() {
         let conn = setup_test_db();
@@ -2787,6 +4591,84 @@ mod tests {
         assert_eq!(updated.synopsis, Some("Updated synopsis".to_string()));
     }
 
+    #[test]
+    fn test_custom_scene_status_round_trips() {
+        let conn = setup_test_db();
+        let mut project = create_test_project(&conn);
+        project.scene_statuses = vec!["Zero Draft".to_string(), "Line Edit".to_string()];
+        update_project(&conn, &project).unwrap();
+
+        let retrieved = get_project(&conn, &project.id).unwrap().unwrap();
+        assert_eq!(retrieved.scene_statuses, vec!["Zero Draft", "Line Edit"]);
+
+        let chapter = create_test_chapter(&conn, project.id);
+        let mut scene = Scene::new(chapter.id, "Custom".to_string(), None, 0);
+        scene.custom_status = Some("Zero Draft".to_string());
+        insert_scene(&conn, &scene).unwrap();
+
+        let stored = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(stored.custom_status.as_deref(), Some("Zero Draft"));
+        assert_eq!(stored.scene_status, SceneStatus::Draft);
+
+        update_scene_metadata(
+            &conn,
+            &scene.id,
+            &SceneType::Normal,
+            &SceneStatus::Draft,
+            Some("Line Edit"),
+        )
+        .unwrap();
+        let stored = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(stored.custom_status.as_deref(), Some("Line Edit"));
+
+        update_scene_metadata(
+            &conn,
+            &scene.id,
+            &SceneType::Normal,
+            &SceneStatus::Final,
+            None,
+        )
+        .unwrap();
+        let stored = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(stored.custom_status, None);
+        assert_eq!(stored.scene_status, SceneStatus::Final);
+    }
+
+    #[test]
+    fn test_publication_metadata_round_trips() {
+        let conn = setup_test_db();
+        let mut project = create_test_project(&conn);
+        project.publisher = Some("Ember & Oak".to_string());
+        project.isbn = Some("978-0-00-000000-2".to_string());
+        project.series_name = Some("The Long Watch".to_string());
+        project.series_index = Some(2);
+        update_project(&conn, &project).unwrap();
+
+        let retrieved = get_project(&conn, &project.id).unwrap().unwrap();
+        assert_eq!(retrieved.publisher.as_deref(), Some("Ember & Oak"));
+        assert_eq!(retrieved.isbn.as_deref(), Some("978-0-00-000000-2"));
+        assert_eq!(retrieved.series_name.as_deref(), Some("The Long Watch"));
+        assert_eq!(retrieved.series_index, Some(2));
+    }
+
+    #[test]
+    fn test_update_scene_notes() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        assert_eq!(get_scenes(&conn, &chapter.id).unwrap()[0].notes, None);
+
+        update_scene_notes(&conn, &scene.id, Some("Check the tide tables")).unwrap();
+        let updated = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(updated.notes.as_deref(), Some("Check the tide tables"));
+        assert_eq!(updated.synopsis, scene.synopsis);
+
+        update_scene_notes(&conn, &scene.id, None).unwrap();
+        let updated = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(updated.notes, None);
+    }
+
     #[test]
     fn test_move_scene_to_chapter() {
         let conn = setup_test_db();
@@ -2803,6 +4685,8 @@ mod tests {
             is_part: false,
             synopsis: None,
             planning_status: PlanningStatus::Fixed,
+            color: None,
+            word_target: None,
         };
         insert_chapter(&conn, &chapter2).unwrap();
 
@@ -2814,40 +4698,709 @@ mod tests {
     }
 
     #[test]
-    fn test_delete_scene() {
+    fn test_malformed_uuid_returns_error() {
         let conn = setup_test_db();
         let project = create_test_project(&conn);
         let chapter = create_test_chapter(&conn, project.id);
-        let scene = create_test_scene(&conn, chapter.id);
+        conn.execute(
+            "INSERT INTO chapters (id, project_id, title, position) VALUES ('not-a-uuid', ?1, 'Bad', 1)",
+            params![project.id.to_string()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO scenes (id, chapter_id, title, position) VALUES ('also-bad', ?1, 'Bad', 0)",
+            params![chapter.id.to_string()],
+        )
+        .unwrap();
 
-        delete_scene(&conn, &scene.id).unwrap();
+        assert!(get_chapters(&conn, &project.id).is_err());
+        assert!(get_scenes(&conn, &chapter.id).is_err());
 
-        let scenes = get_scenes(&conn, &chapter.id).unwrap();
-        assert!(scenes.is_empty());
+        conn.execute(
+            "INSERT INTO projects (id, name, source_type, created_at, modified_at)
+             VALUES ('broken', 'Bad', 'Blank', datetime('now'), datetime('now'))",
+            [],
+        )
+        .unwrap();
+        let err = get_recent_projects(&conn, 10).unwrap_err();
+        assert!(matches!(err, rusqlite::Error::FromSqlConversionFailure(..)));
     }
 
-    // ========================================================================
-    // Beat Tests
-    // ========================================================================
-
     #[test]
-    fn test_insert_and_get_beats() {
+    fn test_insert_chapter_at_position() {
         let conn = setup_test_db();
         let project = create_test_project(&conn);
-        let chapter = create_test_chapter(&conn, project.id);
-        let scene = create_test_scene(&conn, chapter.id);
+        for (pos, title) in ["One", "Two", "Three"].into_iter().enumerate() {
+            insert_chapter(
+                &conn,
+                &Chapter::new(project.id, title.to_string(), pos as i32),
+            )
+            .unwrap();
+        }
 
-        let beat = Beat {
-            id: Uuid::new_v4(),
-            scene_id: scene.id,
-            content: "Test beat content".to_string(),
-            prose: None,
-            position: 0,
-            source_id: None,
-        };
-        insert_beat(&conn, &beat).unwrap();
+        shift_chapters_after_position(&conn, &project.id, 1).unwrap();
+        insert_chapter(&conn, &Chapter::new(project.id, "Inserted".to_string(), 1)).unwrap();
 
-        let beats = get_beats(&conn, &scene.id).unwrap();
+        let layout: Vec<(String, i32)> = get_chapters(&conn, &project.id)
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.title, c.position))
+            .collect();
+        assert_eq!(
+            layout,
+            vec![
+                ("One".to_string(), 0),
+                ("Inserted".to_string(), 1),
+                ("Two".to_string(), 2),
+                ("Three".to_string(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_scene_at_position() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let other_chapter = Chapter::new(project.id, "Other".to_string(), 1);
+        insert_chapter(&conn, &other_chapter).unwrap();
+        for (pos, title) in ["Opening", "Middle"].into_iter().enumerate() {
+            insert_scene(
+                &conn,
+                &Scene::new(chapter.id, title.to_string(), None, pos as i32),
+            )
+            .unwrap();
+        }
+        let elsewhere = Scene::new(other_chapter.id, "Elsewhere".to_string(), None, 0);
+        insert_scene(&conn, &elsewhere).unwrap();
+
+        shift_scenes_after_position(&conn, &chapter.id, 0).unwrap();
+        insert_scene(
+            &conn,
+            &Scene::new(chapter.id, "Prologue".to_string(), None, 0),
+        )
+        .unwrap();
+
+        let layout: Vec<(String, i32)> = get_scenes(&conn, &chapter.id)
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.title, s.position))
+            .collect();
+        assert_eq!(
+            layout,
+            vec![
+                ("Prologue".to_string(), 0),
+                ("Opening".to_string(), 1),
+                ("Middle".to_string(), 2),
+            ]
+        );
+        // Scenes in other chapters keep their positions
+        assert_eq!(
+            get_scene_by_id(&conn, &elsewhere.id)
+                .unwrap()
+                .unwrap()
+                .position,
+            0
+        );
+    }
+
+    #[test]
+    fn test_move_chapter_to_position() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapters: Vec<Chapter> = (0..5)
+            .map(|pos| {
+                let chapter = Chapter::new(project.id, format!("Ch{}", pos), pos);
+                insert_chapter(&conn, &chapter).unwrap();
+                chapter
+            })
+            .collect();
+        let layout = || -> Vec<(String, i32)> {
+            get_chapters(&conn, &project.id)
+                .unwrap()
+                .into_iter()
+                .map(|c| (c.title, c.position))
+                .collect()
+        };
+        let expected = |titles: [&str; 5]| -> Vec<(String, i32)> {
+            titles
+                .iter()
+                .enumerate()
+                .map(|(pos, t)| (t.to_string(), pos as i32))
+                .collect()
+        };
+
+        move_chapter_to_position(&conn, &project.id, &chapters[3].id, 0).unwrap();
+        assert_eq!(layout(), expected(["Ch3", "Ch0", "Ch1", "Ch2", "Ch4"]));
+
+        move_chapter_to_position(&conn, &project.id, &chapters[3].id, 2).unwrap();
+        assert_eq!(layout(), expected(["Ch0", "Ch1", "Ch3", "Ch2", "Ch4"]));
+
+        // Past the end moves the chapter last
+        move_chapter_to_position(&conn, &project.id, &chapters[0].id, 99).unwrap();
+        assert_eq!(layout(), expected(["Ch1", "Ch3", "Ch2", "Ch4", "Ch0"]));
+
+        // A chapter from another project is rejected
+        let other = create_test_project(&conn);
+        assert!(move_chapter_to_position(&conn, &other.id, &chapters[1].id, 0).is_err());
+        assert_eq!(layout(), expected(["Ch1", "Ch3", "Ch2", "Ch4", "Ch0"]));
+    }
+
+    #[test]
+    fn test_move_and_reorder_scene_across_chapters() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter_a = create_test_chapter(&conn, project.id);
+        let chapter_b = create_test_chapter(&conn, project.id);
+        let a_scenes: Vec<Scene> = (0..3)
+            .map(|pos| {
+                let scene = Scene::new(chapter_a.id, format!("A{}", pos), None, pos);
+                insert_scene(&conn, &scene).unwrap();
+                scene
+            })
+            .collect();
+        let b_scenes: Vec<Scene> = (0..2)
+            .map(|pos| {
+                let scene = Scene::new(chapter_b.id, format!("B{}", pos), None, pos);
+                insert_scene(&conn, &scene).unwrap();
+                scene
+            })
+            .collect();
+
+        move_and_reorder_scene(&conn, &a_scenes[1].id, &chapter_b.id, 0).unwrap();
+
+        let layout = |chapter_id: &Uuid| -> Vec<(String, i32)> {
+            get_scenes(&conn, chapter_id)
+                .unwrap()
+                .into_iter()
+                .map(|s| (s.title, s.position))
+                .collect()
+        };
+        assert_eq!(
+            layout(&chapter_a.id),
+            vec![("A0".to_string(), 0), ("A2".to_string(), 1)]
+        );
+        assert_eq!(
+            layout(&chapter_b.id),
+            vec![
+                ("A1".to_string(), 0),
+                ("B0".to_string(), 1),
+                ("B1".to_string(), 2)
+            ]
+        );
+
+        // Moving within a chapter and past the end appends
+        move_and_reorder_scene(&conn, &b_scenes[0].id, &chapter_b.id, 99).unwrap();
+        assert_eq!(
+            layout(&chapter_b.id),
+            vec![
+                ("A1".to_string(), 0),
+                ("B1".to_string(), 1),
+                ("B0".to_string(), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_and_reorder_scene_counts_visible_scenes_only() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        // V0, hidden H0, V1, hidden H1, V2
+        let scenes: Vec<Scene> = ["V0", "H0", "V1", "H1", "V2"]
+            .iter()
+            .enumerate()
+            .map(|(pos, title)| {
+                let scene = Scene::new(chapter.id, title.to_string(), None, pos as i32);
+                insert_scene(&conn, &scene).unwrap();
+                scene
+            })
+            .collect();
+        archive_scene(&conn, &scenes[1].id).unwrap();
+        trash_scene(&conn, &scenes[3].id).unwrap();
+
+        let order = || -> Vec<String> {
+            let mut stmt = conn
+                .prepare("SELECT title FROM scenes WHERE chapter_id = ?1 ORDER BY position")
+                .unwrap();
+            stmt.query_map(params![chapter.id.to_string()], |row| row.get(0))
+                .unwrap()
+                .collect::<Result<Vec<_>>>()
+                .unwrap()
+        };
+
+        // Visible index 1 is V1, even though two rows sit before it
+        move_and_reorder_scene(&conn, &scenes[4].id, &chapter.id, 1).unwrap();
+        assert_eq!(order(), vec!["V0", "H0", "V2", "V1", "H1"]);
+        let visible: Vec<String> = get_scenes(&conn, &chapter.id)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.title)
+            .collect();
+        assert_eq!(visible, vec!["V0", "V2", "V1"]);
+
+        // Past the last visible scene lands right after it
+        move_and_reorder_scene(&conn, &scenes[0].id, &chapter.id, 5).unwrap();
+        assert_eq!(order(), vec!["H0", "V2", "V1", "V0", "H1"]);
+    }
+
+    #[test]
+    fn test_delete_scene() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+
+        delete_scene(&conn, &scene.id).unwrap();
+
+        let scenes = get_scenes(&conn, &chapter.id).unwrap();
+        assert!(scenes.is_empty());
+    }
+
+    #[test]
+    fn test_delete_scene_rolls_back_on_failure() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        insert_beat(&conn, &Beat::new(scene.id, "Kept".to_string(), 0)).unwrap();
+
+        // Refs and beats are deleted before the scene row itself fails
+        conn.execute(
+            "CREATE TRIGGER fail_delete BEFORE DELETE ON scenes
+             BEGIN SELECT RAISE(ABORT, 'injected'); END",
+            [],
+        )
+        .unwrap();
+
+        assert!(delete_scene(&conn, &scene.id).is_err());
+        assert!(conn.is_autocommit());
+        assert_eq!(get_scenes(&conn, &chapter.id).unwrap().len(), 1);
+        assert_eq!(get_beats(&conn, &scene.id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_reorder_scenes_rolls_back_on_unknown_id() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let other_chapter = Chapter::new(project.id, "Other".to_string(), 1);
+        insert_chapter(&conn, &other_chapter).unwrap();
+        let first = create_test_scene(&conn, chapter.id);
+        let second = Scene::new(chapter.id, "Second".to_string(), None, 1);
+        insert_scene(&conn, &second).unwrap();
+        let elsewhere = create_test_scene(&conn, other_chapter.id);
+
+        // A scene from another chapter is not part of this ordering
+        let result = reorder_scenes(&conn, &chapter.id, &[second.id, elsewhere.id, first.id]);
+        assert!(result.is_err());
+        assert!(conn.is_autocommit());
+
+        let scenes = get_scenes(&conn, &chapter.id).unwrap();
+        let order: Vec<(Uuid, i32)> = scenes.iter().map(|s| (s.id, s.position)).collect();
+        assert_eq!(order, vec![(first.id, 0), (second.id, 1)]);
+        let elsewhere = get_scene_by_id(&conn, &elsewhere.id).unwrap().unwrap();
+        assert_eq!(elsewhere.chapter_id, other_chapter.id);
+        assert_eq!(elsewhere.position, 0);
+    }
+
+    #[test]
+    fn test_replace_in_html_leaves_markup_alone() {
+        let html =
+            r#"<p class="Anna">Anna met <strong>anna</strong> at <a href="/anna">Anna's</a>.</p>"#;
+
+        let (updated, count) = replace_in_html(html, "Anna", "Beth", true);
+        assert_eq!(count, 2);
+        assert_eq!(
+            updated,
+            r#"<p class="Anna">Beth met <strong>anna</strong> at <a href="/anna">Beth's</a>.</p>"#
+        );
+
+        let (updated, count) = replace_in_html(html, "anna", "Beth", false);
+        assert_eq!(count, 3);
+        assert_eq!(
+            updated,
+            r#"<p class="Anna">Beth met <strong>Beth</strong> at <a href="/anna">Beth's</a>.</p>"#
+        );
+
+        // Special characters are matched and written in their escaped form
+        let (updated, count) = replace_in_html("<p>Tom &amp; Jerry</p>", "&", "and", true);
+        assert_eq!(count, 1);
+        assert_eq!(updated, "<p>Tom and Jerry</p>");
+    }
+
+    #[test]
+    fn test_find_and_replace_never_splits_entities() {
+        let html = "<p>Tom &amp; Jerry: 3 &lt; 4 &#233;t&eacute;</p>";
+
+        // Entity names are not text
+        assert_eq!(replace_in_html(html, "amp", "x", false).1, 0);
+        let (updated, count) = replace_in_html(html, "t", "d", true);
+        assert_eq!(count, 1);
+        assert_eq!(updated, "<p>Tom &amp; Jerry: 3 &lt; 4 &#233;d&eacute;</p>");
+
+        // Entities match the character they stand for, as one unit
+        let (updated, count) = replace_in_html(html, "3 < 4", "a > b", true);
+        assert_eq!(count, 1);
+        assert_eq!(updated, "<p>Tom &amp; Jerry: a &gt; b &#233;t&eacute;</p>");
+        assert_eq!(replace_in_html(html, "\u{e9}t", "x", true).1, 1);
+
+        // The preview counts and excerpts the same way
+        assert!(preview_html(html, "amp", false).is_none());
+        assert!(preview_html(html, "lt", false).is_none());
+        let (snippet, start, length, count) = preview_html(html, "&", true).unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(snippet, "Tom & Jerry: 3 < 4 \u{e9}t&eacute;");
+        assert_eq!(
+            snippet.chars().skip(start).take(length).collect::<String>(),
+            "&"
+        );
+    }
+
+    #[test]
+    fn test_replace_in_prose_skips_locked_scenes() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let open = create_test_scene(&conn, chapter.id);
+        update_scene_prose(&conn, &open.id, "<p><em>Mara</em> ran.</p>").unwrap();
+        let mut beat = Beat::new(open.id, "Beat".to_string(), 0);
+        beat.prose = Some(r#"<p data-name="Mara">Mara, mara.</p>"#.to_string());
+        insert_beat(&conn, &beat).unwrap();
+
+        let mut locked = Scene::new(chapter.id, "Locked".to_string(), None, 1);
+        locked.prose = Some("<p>Mara waited.</p>".to_string());
+        locked.locked = true;
+        insert_scene(&conn, &locked).unwrap();
+
+        let count = replace_in_prose(&conn, &project.id, "Mara", "Nell", true).unwrap();
+        assert_eq!(count, 2);
+
+        let open = get_scene_by_id(&conn, &open.id).unwrap().unwrap();
+        assert_eq!(open.prose.as_deref(), Some("<p><em>Nell</em> ran.</p>"));
+        let beats = get_beats(&conn, &open.id).unwrap();
+        assert_eq!(
+            beats[0].prose.as_deref(),
+            Some(r#"<p data-name="Mara">Nell, mara.</p>"#)
+        );
+        let locked = get_scene_by_id(&conn, &locked.id).unwrap().unwrap();
+        assert_eq!(locked.prose.as_deref(), Some("<p>Mara waited.</p>"));
+    }
+
+    #[test]
+    fn test_preview_replace_matches_replace_without_writing() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let prose = "<p>The storm broke over Harlow &amp; the <em>Storm</em>-wall held \
+                     long after the first thunder had rolled away. storm</p>";
+        update_scene_prose(&conn, &scene.id, prose).unwrap();
+        let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+        beat.prose = Some(r#"<p class="storm">Calm.</p>"#.to_string());
+        insert_beat(&conn, &beat).unwrap();
+
+        let previews = preview_replace_in_prose(&conn, &project.id, "storm", false).unwrap();
+        assert_eq!(previews.len(), 1);
+        let preview = &previews[0];
+        assert_eq!(preview.scene_id, scene.id);
+        assert_eq!(preview.beat_id, None);
+        assert_eq!(preview.match_count, 3);
+        let highlighted: String = preview
+            .context_snippet
+            .chars()
+            .skip(preview.highlight_start)
+            .take(preview.highlight_length)
+            .collect();
+        assert_eq!(highlighted, "storm");
+        assert!(preview
+            .context_snippet
+            .starts_with("The storm broke over Harlow & the"));
+        assert!(preview.context_snippet.ends_with('\u{2026}'));
+
+        // Nothing was written
+        let stored = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(stored.prose.as_deref(), Some(prose));
+        assert!(conn.is_autocommit());
+
+        let total: usize = previews.iter().map(|p| p.match_count).sum();
+        let replaced = replace_in_prose(&conn, &project.id, "storm", "gale", false).unwrap();
+        assert_eq!(replaced, total);
+    }
+
+    #[test]
+    fn test_get_scenes_in_compile_order() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let titles = ["A", "B", "C"];
+        let mut ids = Vec::new();
+        for (pos, title) in titles.into_iter().enumerate() {
+            let scene = Scene::new(chapter.id, title.to_string(), None, pos as i32);
+            insert_scene(&conn, &scene).unwrap();
+            ids.push(scene.id);
+        }
+
+        let order = |conn: &Connection| -> Vec<String> {
+            get_scenes_in_compile_order(conn, &chapter.id)
+                .unwrap()
+                .into_iter()
+                .map(|s| s.title)
+                .collect()
+        };
+
+        // Without compile positions this is binder order
+        assert_eq!(order(&conn), ["A", "B", "C"]);
+
+        // C moves to the front; A and B keep falling back to their positions
+        update_scene_compile_position(&conn, &ids[2], Some(-1)).unwrap();
+        assert_eq!(order(&conn), ["C", "A", "B"]);
+        let scene = get_scene_by_id(&conn, &ids[2]).unwrap().unwrap();
+        assert_eq!(scene.compile_position, Some(-1));
+
+        // Binder order is untouched
+        let binder: Vec<String> = get_scenes(&conn, &chapter.id)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.title)
+            .collect();
+        assert_eq!(binder, ["A", "B", "C"]);
+
+        update_scene_compile_position(&conn, &ids[2], None).unwrap();
+        assert_eq!(order(&conn), ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn test_consolidate_scene_prose() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+
+        // Inserted out of order to check positions drive the result
+        for (position, prose) in [(2, Some("<p>Third</p>")), (0, Some("<p>First</p>"))] {
+            let mut beat = Beat::new(scene.id, format!("Beat {position}"), position);
+            beat.prose = prose.map(String::from);
+            insert_beat(&conn, &beat).unwrap();
+        }
+        insert_beat(&conn, &Beat::new(scene.id, "No prose".to_string(), 1)).unwrap();
+
+        let kept = consolidate_scene_prose(&conn, &scene.id, false).unwrap();
+        assert_eq!(kept.prose.as_deref(), Some("<p>First</p>\n<p>Third</p>"));
+        assert_eq!(get_beats(&conn, &scene.id).unwrap().len(), 3);
+
+        let cleared = consolidate_scene_prose(&conn, &scene.id, true).unwrap();
+        assert_eq!(cleared.prose, kept.prose);
+        assert!(get_beats(&conn, &scene.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_merge_scenes_renumbers_beats() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let first = create_test_scene(&conn, chapter.id);
+        let second = Scene::new(
+            chapter.id,
+            "Second".to_string(),
+            Some("Second synopsis".to_string()),
+            1,
+        );
+        insert_scene(&conn, &second).unwrap();
+        let third = Scene::new(chapter.id, "Third".to_string(), None, 2);
+        insert_scene(&conn, &third).unwrap();
+
+        for (scene_id, contents) in [(first.id, ["A", "B"]), (second.id, ["C", "D"])] {
+            for (i, content) in contents.iter().enumerate() {
+                insert_beat(&conn, &Beat::new(scene_id, content.to_string(), i as i32)).unwrap();
+            }
+        }
+
+        let merged = merge_scenes(&conn, &first.id, &second.id).unwrap();
+        assert_eq!(
+            merged.synopsis.as_deref(),
+            Some("A test synopsis\n\nSecond synopsis")
+        );
+
+        let beats = get_beats(&conn, &first.id).unwrap();
+        let summary: Vec<(&str, i32)> = beats
+            .iter()
+            .map(|b| (b.content.as_str(), b.position))
+            .collect();
+        assert_eq!(summary, vec![("A", 0), ("B", 1), ("C", 2), ("D", 3)]);
+
+        assert!(get_scene_by_id(&conn, &second.id).unwrap().is_none());
+
+        // Later scenes close the gap the merged scene left
+        let positions: Vec<(Uuid, i32)> = get_scenes(&conn, &chapter.id)
+            .unwrap()
+            .iter()
+            .map(|s| (s.id, s.position))
+            .collect();
+        assert_eq!(positions, vec![(first.id, 0), (third.id, 1)]);
+    }
+
+    #[test]
+    fn test_merge_scenes_deduplicates_references() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let first = create_test_scene(&conn, chapter.id);
+        let second = Scene::new(chapter.id, "Second".to_string(), None, 1);
+        insert_scene(&conn, &second).unwrap();
+
+        let shared = Character::new(project.id, "Shared".to_string(), None, None);
+        let only_second = Character::new(project.id, "Only Second".to_string(), None, None);
+        insert_character(&conn, &shared).unwrap();
+        insert_character(&conn, &only_second).unwrap();
+
+        add_scene_character_ref(&conn, &first.id, &shared.id).unwrap();
+        add_scene_character_ref(&conn, &second.id, &shared.id).unwrap();
+        add_scene_character_ref(&conn, &second.id, &only_second.id).unwrap();
+
+        merge_scenes(&conn, &first.id, &second.id).unwrap();
+
+        let mut refs = get_scene_characters(&conn, &first.id).unwrap();
+        refs.sort();
+        let mut expected = vec![shared.id, only_second.id];
+        expected.sort();
+        assert_eq!(refs, expected);
+        assert!(get_scene_characters(&conn, &second.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_merge_scenes_keeps_notes_and_field_values() {
+        use crate::db::fields::{create_field_definition, get_field_values, set_field_value};
+        use crate::models::{DiscoveryNote, FieldDefinition};
+
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let mut first = Scene::new(chapter.id, "First".to_string(), None, 0);
+        first.notes = Some("Check the timeline".to_string());
+        insert_scene(&conn, &first).unwrap();
+        let mut second = Scene::new(chapter.id, "Second".to_string(), None, 1);
+        second.notes = Some("Foreshadow the storm".to_string());
+        insert_scene(&conn, &second).unwrap();
+
+        let discovery = DiscoveryNote {
+            id: Uuid::new_v4(),
+            scene_id: second.id,
+            content: "She lies here".to_string(),
+            tags: vec![],
+            position: 0,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+        insert_discovery_note(&conn, &discovery).unwrap();
+
+        let field = |name: &str, position: i32| {
+            let def = FieldDefinition::new(
+                project.id,
+                "scene".to_string(),
+                name.to_string(),
+                "text".to_string(),
+                position,
+            );
+            create_field_definition(&conn, &def).unwrap();
+            def
+        };
+        let mood = field("Mood", 0);
+        let weather = field("Weather", 1);
+        set_field_value(&conn, &mood.id, &first.id, Some("Tense")).unwrap();
+        set_field_value(&conn, &mood.id, &second.id, Some("Calm")).unwrap();
+        set_field_value(&conn, &weather.id, &second.id, Some("Rain")).unwrap();
+
+        let merged = merge_scenes(&conn, &first.id, &second.id).unwrap();
+        assert_eq!(
+            merged.notes.as_deref(),
+            Some("Check the timeline\n\nForeshadow the storm")
+        );
+
+        let notes = get_discovery_notes(&conn, &first.id).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].content, "She lies here");
+
+        let value_of = |def: &FieldDefinition| {
+            get_field_values(&conn, &first.id)
+                .unwrap()
+                .into_iter()
+                .find(|v| v.field_definition_id == def.id)
+                .and_then(|v| v.value)
+        };
+        assert_eq!(value_of(&mood).as_deref(), Some("Tense"));
+        assert_eq!(value_of(&weather).as_deref(), Some("Rain"));
+        assert!(get_field_values(&conn, &second.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reorder_beats() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let other_scene = Scene::new(chapter.id, "Other".to_string(), None, 1);
+        insert_scene(&conn, &other_scene).unwrap();
+
+        let beats: Vec<Beat> = ["First", "Second", "Third"]
+            .iter()
+            .enumerate()
+            .map(|(i, content)| Beat::new(scene.id, content.to_string(), i as i32))
+            .collect();
+        for beat in &beats {
+            insert_beat(&conn, beat).unwrap();
+        }
+        let foreign = Beat::new(other_scene.id, "Foreign".to_string(), 0);
+        insert_beat(&conn, &foreign).unwrap();
+
+        let reversed: Vec<Uuid> = beats.iter().rev().map(|b| b.id).collect();
+        reorder_beats(&conn, &scene.id, &reversed).unwrap();
+
+        let reordered = get_beats(&conn, &scene.id).unwrap();
+        let contents: Vec<&str> = reordered.iter().map(|b| b.content.as_str()).collect();
+        assert_eq!(contents, vec!["Third", "Second", "First"]);
+        let positions: Vec<i32> = reordered.iter().map(|b| b.position).collect();
+        assert_eq!(positions, vec![0, 1, 2]);
+
+        // A beat from another scene fails the whole reorder and touches nothing
+        let result = reorder_beats(&conn, &scene.id, &[beats[0].id, foreign.id, beats[1].id]);
+        assert!(result.is_err());
+        assert!(conn.is_autocommit());
+        let positions: Vec<(Uuid, i32)> = get_beats(&conn, &scene.id)
+            .unwrap()
+            .iter()
+            .map(|b| (b.id, b.position))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![(beats[2].id, 0), (beats[1].id, 1), (beats[0].id, 2)]
+        );
+        let untouched = get_beat(&conn, &foreign.id).unwrap().unwrap();
+        assert_eq!(untouched.scene_id, other_scene.id);
+        assert_eq!(untouched.position, 0);
+    }
+
+    // ========================================================================
+    // Beat Tests
+    // ========================================================================
+
+    #[test]
+    fn test_insert_and_get_beats() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+
+        let beat = Beat {
+            id: Uuid::new_v4(),
+            scene_id: scene.id,
+            content: "Test beat content".to_string(),
+            prose: None,
+            position: 0,
+            source_id: None,
+        };
+        insert_beat(&conn, &beat).unwrap();
+
+        let beats = get_beats(&conn, &scene.id).unwrap();
         assert_eq!(beats.len(), 1);
         assert_eq!(beats[0].content, "Test beat content");
     }
@@ -2875,6 +5428,72 @@ mod tests {
         assert_eq!(beats[0].prose, Some("Beat prose".to_string()));
     }
 
+    #[test]
+    fn test_update_project_modified_clears_cached_word_count() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        assert_eq!(get_cached_word_count(&conn, &project.id).unwrap(), None);
+
+        set_cached_word_count(&conn, &project.id, 1200).unwrap();
+        assert_eq!(
+            get_cached_word_count(&conn, &project.id).unwrap(),
+            Some(1200)
+        );
+
+        update_project_modified(&conn, &project.id).unwrap();
+        assert_eq!(get_cached_word_count(&conn, &project.id).unwrap(), None);
+    }
+
+    #[test]
+    fn test_beat_prose_revisions() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let beat = Beat::new(scene.id, "Test beat".to_string(), 0);
+        insert_beat(&conn, &beat).unwrap();
+
+        for prose in ["First draft", "Second draft", "Third draft"] {
+            update_beat_prose_with_revision(&conn, &beat.id, prose).unwrap();
+        }
+        // Saving unchanged prose does not add a revision
+        update_beat_prose_with_revision(&conn, &beat.id, "Third draft").unwrap();
+
+        // The initial empty prose is not kept; newest revision comes first
+        let revisions = get_beat_revisions(&conn, &beat.id).unwrap();
+        let kept: Vec<&str> = revisions.iter().map(|r| r.prose.as_str()).collect();
+        assert_eq!(kept, vec!["Second draft", "First draft"]);
+
+        let first = get_beat_revision(&conn, &revisions[1].id).unwrap().unwrap();
+        update_beat_prose_with_revision(&conn, &first.beat_id, &first.prose).unwrap();
+        let restored = get_beat(&conn, &beat.id).unwrap().unwrap();
+        assert_eq!(restored.prose.as_deref(), Some("First draft"));
+        assert_eq!(
+            get_beat_revisions(&conn, &beat.id).unwrap()[0].prose,
+            "Third draft"
+        );
+    }
+
+    #[test]
+    fn test_beat_revisions_pruned_beyond_cap() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let beat = Beat::new(scene.id, "Test beat".to_string(), 0);
+        insert_beat(&conn, &beat).unwrap();
+
+        for i in 0..MAX_BEAT_REVISIONS + 5 {
+            update_beat_prose_with_revision(&conn, &beat.id, &format!("Draft {}", i)).unwrap();
+        }
+
+        let revisions = get_beat_revisions(&conn, &beat.id).unwrap();
+        assert_eq!(revisions.len(), MAX_BEAT_REVISIONS);
+        let newest = format!("Draft {}", MAX_BEAT_REVISIONS + 3);
+        assert_eq!(revisions[0].prose, newest);
+        assert_eq!(revisions.last().unwrap().prose, "Draft 4");
+    }
+
     // ========================================================================
     // Lock Tests
     // ========================================================================
@@ -2949,6 +5568,219 @@ mod tests {
         assert_eq!(characters[0].name, "Hero");
     }
 
+    #[test]
+    fn test_get_characters_with_attribute() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+
+        let mut hero = Character::new(project.id, "Hero".to_string(), None, None);
+        hero.attributes
+            .insert("Role".to_string(), "Protagonist".to_string());
+        insert_character(&conn, &hero).unwrap();
+
+        let mut rival = Character::new(project.id, "Rival".to_string(), None, None);
+        rival
+            .attributes
+            .insert("role".to_string(), "protagonist".to_string());
+        insert_character(&conn, &rival).unwrap();
+
+        let mut villain = Character::new(project.id, "Villain".to_string(), None, None);
+        villain
+            .attributes
+            .insert("Role".to_string(), "Antagonist".to_string());
+        insert_character(&conn, &villain).unwrap();
+
+        let bystander = Character::new(project.id, "Bystander".to_string(), None, None);
+        insert_character(&conn, &bystander).unwrap();
+
+        let matches =
+            get_characters_with_attribute(&conn, &project.id, "ROLE", "Protagonist").unwrap();
+        let names: Vec<&str> = matches.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Hero", "Rival"]);
+        assert_eq!(
+            matches[0].attributes.get("Role").map(String::as_str),
+            Some("Protagonist")
+        );
+
+        // Partial values do not match
+        assert!(
+            get_characters_with_attribute(&conn, &project.id, "Role", "Protag")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_character_alias() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+
+        let character = Character::new(project.id, "Elizabeth Bennet".to_string(), None, None);
+        insert_character(&conn, &character).unwrap();
+
+        add_character_alias(&conn, &character.id, "Lizzy").unwrap();
+        add_character_alias(&conn, &character.id, "Lizzy").unwrap();
+        add_character_alias(&conn, &character.id, "Eliza").unwrap();
+
+        let aliases = get_character_aliases(&conn, &character.id).unwrap();
+        assert_eq!(aliases, vec!["Eliza".to_string(), "Lizzy".to_string()]);
+
+        let project_aliases = get_project_character_aliases(&conn, &project.id).unwrap();
+        assert_eq!(project_aliases.len(), 2);
+        assert!(project_aliases.iter().all(|(id, _)| *id == character.id));
+
+        remove_character_alias(&conn, &character.id, "Eliza").unwrap();
+        let aliases = get_character_aliases(&conn, &character.id).unwrap();
+        assert_eq!(aliases, vec!["Lizzy".to_string()]);
+    }
+
+    #[test]
+    fn test_location_and_reference_item_aliases() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+
+        let location = Location::new(project.id, "Castle".to_string(), None, None);
+        insert_location(&conn, &location).unwrap();
+        let item = ReferenceItem::new(
+            project.id,
+            "items".to_string(),
+            "Sword".to_string(),
+            None,
+            None,
+        );
+        insert_reference_item(&conn, &item).unwrap();
+
+        add_location_alias(&conn, &location.id, "The Keep").unwrap();
+        add_location_alias(&conn, &location.id, "Fort").unwrap();
+        add_reference_item_alias(&conn, &item.id, "Blade").unwrap();
+
+        assert_eq!(
+            get_location_aliases(&conn, &location.id).unwrap(),
+            vec!["Fort".to_string(), "The Keep".to_string()]
+        );
+        assert_eq!(
+            get_project_reference_item_aliases(&conn, &project.id).unwrap(),
+            vec![(item.id, "Blade".to_string())]
+        );
+
+        remove_location_alias(&conn, &location.id, "Fort").unwrap();
+        assert_eq!(
+            get_project_location_aliases(&conn, &project.id)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Aliases go with their owner
+        delete_location(&conn, &location.id).unwrap();
+        delete_reference_item(&conn, &item.id).unwrap();
+        assert!(get_project_location_aliases(&conn, &project.id)
+            .unwrap()
+            .is_empty());
+        assert!(get_reference_item_aliases(&conn, &item.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_character_appearances_follow_manuscript_order() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter_two = Chapter::new(project.id, "Chapter Two".to_string(), 1);
+        let chapter_one = Chapter::new(project.id, "Chapter One".to_string(), 0);
+        insert_chapter(&conn, &chapter_two).unwrap();
+        insert_chapter(&conn, &chapter_one).unwrap();
+        let late = Scene::new(chapter_two.id, "Confrontation".to_string(), None, 0);
+        let early = Scene::new(chapter_one.id, "Meeting".to_string(), None, 1);
+        let absent = Scene::new(chapter_one.id, "Elsewhere".to_string(), None, 0);
+        for scene in [&late, &early, &absent] {
+            insert_scene(&conn, scene).unwrap();
+        }
+
+        let character = Character::new(project.id, "Elizabeth".to_string(), None, None);
+        insert_character(&conn, &character).unwrap();
+        add_scene_character_ref(&conn, &late.id, &character.id).unwrap();
+        add_scene_character_ref(&conn, &early.id, &character.id).unwrap();
+
+        let appearances = get_character_appearances(&conn, &character.id).unwrap();
+        let titles: Vec<(&str, &str)> = appearances
+            .iter()
+            .map(|a| (a.chapter_title.as_str(), a.scene_title.as_str()))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![("Chapter One", "Meeting"), ("Chapter Two", "Confrontation")]
+        );
+        assert_eq!(appearances[0].scene_id, early.id);
+        assert_eq!(appearances[1].chapter_id, chapter_two.id);
+
+        // Trashed scenes and chapters are not appearances
+        trash_scene(&conn, &early.id).unwrap();
+        let appearances = get_character_appearances(&conn, &character.id).unwrap();
+        assert_eq!(appearances.len(), 1);
+        assert_eq!(appearances[0].scene_id, late.id);
+        trash_chapter(&conn, &chapter_two.id).unwrap();
+        assert!(get_character_appearances(&conn, &character.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_delete_character_and_location_remove_scene_refs() {
+        let conn = setup_test_db();
+        // Without enforcement, as in connections that never enabled foreign keys
+        conn.execute_batch("PRAGMA foreign_keys = OFF;").unwrap();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let character = Character::new(project.id, "Mara".to_string(), None, None);
+        insert_character(&conn, &character).unwrap();
+        let location = Location {
+            id: Uuid::new_v4(),
+            project_id: project.id,
+            name: "Harbor".to_string(),
+            description: None,
+            attributes: HashMap::new(),
+            source_id: None,
+        };
+        insert_location(&conn, &location).unwrap();
+        add_scene_character_ref(&conn, &scene.id, &character.id).unwrap();
+        add_scene_location_ref(&conn, &scene.id, &location.id).unwrap();
+
+        delete_character(&conn, &character.id).unwrap();
+        delete_location(&conn, &location.id).unwrap();
+
+        assert!(get_scene_characters(&conn, &scene.id).unwrap().is_empty());
+        assert!(get_scene_locations(&conn, &scene.id).unwrap().is_empty());
+        assert!(find_orphaned_refs(&conn, &project.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_find_orphaned_refs() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let character = Character::new(project.id, "Mara".to_string(), None, None);
+        insert_character(&conn, &character).unwrap();
+        add_scene_character_ref(&conn, &scene.id, &character.id).unwrap();
+
+        // A reference left behind by a delete that skipped the join table
+        conn.execute_batch("PRAGMA foreign_keys = OFF;").unwrap();
+        let missing = Uuid::new_v4();
+        add_scene_location_ref(&conn, &scene.id, &missing).unwrap();
+
+        let orphans = find_orphaned_refs(&conn, &project.id).unwrap();
+        assert_eq!(
+            orphans,
+            vec![OrphanedRef {
+                scene_id: scene.id,
+                reference_type: "locations".to_string(),
+                reference_id: missing,
+            }]
+        );
+    }
+
     #[test]
     fn test_insert_and_get_locations() {
         let conn = setup_test_db();
@@ -3004,6 +5836,128 @@ mod tests {
         assert!(!restored.archived);
     }
 
+    #[test]
+    fn test_archive_chapter_cascade_restores_only_its_scenes() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let visible = Scene::new(chapter.id, "Visible".to_string(), None, 0);
+        let hidden = Scene::new(chapter.id, "Hidden".to_string(), None, 1);
+        insert_scene(&conn, &visible).unwrap();
+        insert_scene(&conn, &hidden).unwrap();
+        archive_scene(&conn, &hidden.id).unwrap();
+
+        assert_eq!(archive_chapter_cascade(&conn, &chapter.id).unwrap(), 1);
+        assert!(
+            get_chapter_by_id(&conn, &chapter.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+        assert!(
+            get_scene_by_id(&conn, &visible.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+        assert!(
+            get_scene_by_id(&conn, &hidden.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+
+        assert_eq!(restore_chapter_cascade(&conn, &chapter.id).unwrap(), 1);
+        assert!(
+            !get_chapter_by_id(&conn, &chapter.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+        assert!(
+            !get_scene_by_id(&conn, &visible.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+        // Archived before the chapter was, so it stays archived
+        assert!(
+            get_scene_by_id(&conn, &hidden.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+
+        // A second cascade doesn't pick up scenes restored individually since
+        archive_chapter_cascade(&conn, &chapter.id).unwrap();
+        restore_scene(&conn, &visible.id).unwrap();
+        archive_scene(&conn, &visible.id).unwrap();
+        assert_eq!(restore_chapter_cascade(&conn, &chapter.id).unwrap(), 0);
+        assert!(
+            get_scene_by_id(&conn, &visible.id)
+                .unwrap()
+                .unwrap()
+                .archived
+        );
+    }
+
+    #[test]
+    fn test_chapter_and_scene_color_roundtrip() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        assert_eq!(get_chapters(&conn, &project.id).unwrap()[0].color, None);
+
+        set_chapter_color(&conn, &chapter.id, Some("#FFAA00")).unwrap();
+        set_scene_color(&conn, &scene.id, Some("#00AAFF")).unwrap();
+        assert_eq!(
+            get_chapters(&conn, &project.id).unwrap()[0]
+                .color
+                .as_deref(),
+            Some("#FFAA00")
+        );
+        assert_eq!(
+            get_scenes(&conn, &chapter.id).unwrap()[0].color.as_deref(),
+            Some("#00AAFF")
+        );
+
+        set_scene_color(&conn, &scene.id, None).unwrap();
+        let reloaded = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(reloaded.color, None);
+
+        // Colors survive insertion of a copied entity
+        let mut copy = Scene::new(chapter.id, "Copy".to_string(), None, 1);
+        copy.color = Some("#123ABC".to_string());
+        insert_scene(&conn, &copy).unwrap();
+        let copied = get_scene_by_id(&conn, &copy.id).unwrap().unwrap();
+        assert_eq!(copied.color.as_deref(), Some("#123ABC"));
+    }
+
+    #[test]
+    fn test_scene_pov_roundtrip() {
+        let conn = setup_test_db();
+        conn.execute_batch("PRAGMA foreign_keys = ON;").unwrap();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let mara = Character::new(project.id, "Mara".to_string(), None, None);
+        insert_character(&conn, &mara).unwrap();
+        assert_eq!(
+            get_scenes(&conn, &chapter.id).unwrap()[0].pov_character_id,
+            None
+        );
+
+        set_scene_pov(&conn, &scene.id, Some(&mara.id)).unwrap();
+        let reloaded = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(reloaded.pov_character_id, Some(mara.id));
+
+        // Deleting the character clears the POV rather than the scene
+        delete_character(&conn, &mara.id).unwrap();
+        let reloaded = get_scene_by_id(&conn, &scene.id).unwrap().unwrap();
+        assert_eq!(reloaded.pov_character_id, None);
+    }
+
     #[test]
     fn test_get_archived_chapters() {
         let conn = setup_test_db();
@@ -3036,4 +5990,185 @@ mod tests {
         let archived = get_archived_scenes(&conn, &project.id).unwrap();
         assert_eq!(archived.len(), 1);
     }
+
+    // ========================================================================
+    // Trash Tests
+    // ========================================================================
+
+    #[test]
+    fn test_trashed_items_hidden_from_normal_queries() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let other_chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+        let kept = create_test_scene(&conn, chapter.id);
+
+        trash_scene(&conn, &scene.id).unwrap();
+        trash_chapter(&conn, &other_chapter.id).unwrap();
+
+        let scenes = get_scenes(&conn, &chapter.id).unwrap();
+        assert_eq!(scenes.len(), 1);
+        assert_eq!(scenes[0].id, kept.id);
+        let chapters = get_chapters(&conn, &project.id).unwrap();
+        assert_eq!(chapters.len(), 1);
+        assert_eq!(chapters[0].id, chapter.id);
+
+        let trashed_scenes = get_trashed_scenes(&conn, &project.id).unwrap();
+        assert_eq!(trashed_scenes.len(), 1);
+        assert_eq!(trashed_scenes[0].id, scene.id);
+        let trashed_chapters = get_trashed_chapters(&conn, &project.id).unwrap();
+        assert_eq!(trashed_chapters.len(), 1);
+        assert_eq!(trashed_chapters[0].id, other_chapter.id);
+
+        // Trash is separate from the archive
+        assert!(get_archived_scenes(&conn, &project.id).unwrap().is_empty());
+
+        restore_from_trash_scene(&conn, &scene.id).unwrap();
+        restore_from_trash_chapter(&conn, &other_chapter.id).unwrap();
+        assert_eq!(get_scenes(&conn, &chapter.id).unwrap().len(), 2);
+        assert_eq!(get_chapters(&conn, &project.id).unwrap().len(), 2);
+        assert!(get_trashed_scenes(&conn, &project.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_delete_trash_older_than() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let old_scene = create_test_scene(&conn, chapter.id);
+        let recent_scene = create_test_scene(&conn, chapter.id);
+
+        trash_scene(&conn, &old_scene.id).unwrap();
+        trash_scene(&conn, &recent_scene.id).unwrap();
+        conn.execute(
+            "UPDATE scenes SET trashed_at = '2020-01-01T00:00:00+00:00' WHERE id = ?1",
+            params![old_scene.id.to_string()],
+        )
+        .unwrap();
+
+        let (chapters, scenes) =
+            delete_trash_older_than(&conn, &project.id, "2021-01-01T00:00:00+00:00").unwrap();
+        assert_eq!((chapters, scenes), (0, 1));
+        assert!(get_scene_by_id(&conn, &old_scene.id).unwrap().is_none());
+        assert!(get_scene_by_id(&conn, &recent_scene.id).unwrap().is_some());
+    }
+
+    // ========================================================================
+    // Search Tests
+    // ========================================================================
+
+    #[test]
+    fn test_search_text_finds_beat_prose_with_context() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+
+        let mut first = Beat::new(scene.id, "Opening".to_string(), 0);
+        first.prose = Some("<p>The rain fell on the quiet harbor.</p>".to_string());
+        insert_beat(&conn, &first).unwrap();
+
+        let mut second = Beat::new(scene.id, "Reveal".to_string(), 1);
+        second.prose =
+            Some("<p>She found the <em>Silver Key</em> beneath the floorboards.</p>".to_string());
+        insert_beat(&conn, &second).unwrap();
+
+        let hits = search_text(&conn, &project.id, "silver key", None).unwrap();
+
+        assert_eq!(hits.len(), 1);
+        let hit = &hits[0];
+        assert_eq!(hit.beat_id, Some(second.id));
+        assert_eq!(hit.scene_id, scene.id);
+        assert_eq!(hit.chapter_id, chapter.id);
+        assert_eq!(hit.field, SearchField::Prose);
+        assert_eq!(
+            hit.snippet,
+            "She found the Silver Key beneath the floorboards."
+        );
+        assert_eq!(hit.match_offset, "She found the ".chars().count());
+        assert!(!hit.snippet.contains('<'));
+    }
+
+    #[test]
+    fn test_search_text_ignores_markup_and_orders_fields() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter = create_test_chapter(&conn, project.id);
+        let scene = create_test_scene(&conn, chapter.id);
+
+        let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+        beat.prose = Some("<p class=\"lead\">Nothing here.</p>".to_string());
+        insert_beat(&conn, &beat).unwrap();
+
+        // "lead" only appears inside a tag attribute
+        assert!(search_text(&conn, &project.id, "lead", None)
+            .unwrap()
+            .is_empty());
+
+        // "test" is in both the title and synopsis of the scene
+        let hits = search_text(&conn, &project.id, "TEST", None).unwrap();
+        let fields: Vec<SearchField> = hits.iter().map(|h| h.field).collect();
+        assert_eq!(fields, vec![SearchField::Title, SearchField::Synopsis]);
+        assert!(hits.iter().all(|h| h.beat_id.is_none()));
+    }
+
+    #[test]
+    fn test_search_text_limits_to_chapter() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+        let chapter_one = create_test_chapter(&conn, project.id);
+        let chapter_two = create_test_chapter(&conn, project.id);
+        let scene_one = create_test_scene(&conn, chapter_one.id);
+        let scene_two = create_test_scene(&conn, chapter_two.id);
+
+        for scene in [&scene_one, &scene_two] {
+            let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+            beat.prose = Some("<p>A lantern flickered.</p>".to_string());
+            insert_beat(&conn, &beat).unwrap();
+        }
+
+        let all = search_text(&conn, &project.id, "lantern", None).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let scoped = search_text(&conn, &project.id, "lantern", Some(&chapter_two.id)).unwrap();
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].scene_id, scene_two.id);
+
+        // LIKE wildcards in the query are matched literally
+        assert!(search_text(&conn, &project.id, "%", None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_writing_session_round_trip() {
+        let conn = setup_test_db();
+        let project = create_test_project(&conn);
+
+        let mut session = WritingSession::new(project.id, "2026-03-02".to_string(), 1200);
+        record_session_snapshot(&conn, &session).unwrap();
+        let open = get_open_writing_session(&conn, &project.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(open.id, session.id);
+
+        session.end_word_count = Some(1650);
+        record_session_snapshot(&conn, &session).unwrap();
+        assert!(get_open_writing_session(&conn, &project.id)
+            .unwrap()
+            .is_none());
+
+        let earlier = WritingSession::new(project.id, "2026-02-27".to_string(), 0);
+        record_session_snapshot(&conn, &earlier).unwrap();
+
+        let march = get_sessions_for_range(&conn, &project.id, "2026-03-01", "2026-03-31").unwrap();
+        assert_eq!(march.len(), 1);
+        assert_eq!(march[0].start_word_count, 1200);
+        assert_eq!(march[0].end_word_count, Some(1650));
+
+        let all = get_sessions_for_range(&conn, &project.id, "2026-01-01", "2026-12-31").unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].date, "2026-02-27");
+    }
 }
@@ -19,7 +19,13 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             word_target INTEGER,
             reference_types TEXT,
             project_type TEXT NOT NULL DEFAULT 'novel',
-            target_page_count INTEGER
+            target_page_count INTEGER,
+            cached_word_count INTEGER,
+            scene_statuses TEXT,
+            publisher TEXT,
+            isbn TEXT,
+            series_name TEXT,
+            series_index INTEGER
         );
 
         CREATE TABLE IF NOT EXISTS chapters (
@@ -29,7 +35,11 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             position INTEGER NOT NULL,
             source_id TEXT,
             synopsis TEXT,
-            planning_status TEXT NOT NULL DEFAULT 'fixed'
+            planning_status TEXT NOT NULL DEFAULT 'fixed',
+            color TEXT,
+            word_target INTEGER,
+            trashed INTEGER NOT NULL DEFAULT 0,
+            trashed_at TEXT
         );
 
         CREATE TABLE IF NOT EXISTS scenes (
@@ -43,7 +53,16 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             scene_type TEXT NOT NULL DEFAULT 'normal',
             scene_status TEXT NOT NULL DEFAULT 'draft',
             planning_status TEXT NOT NULL DEFAULT 'fixed',
-            editor_mode TEXT NOT NULL DEFAULT 'beat'
+            editor_mode TEXT NOT NULL DEFAULT 'beat',
+            compile_position INTEGER,
+            include_in_compile INTEGER NOT NULL DEFAULT 1,
+            archived_by_parent INTEGER NOT NULL DEFAULT 0,
+            color TEXT,
+            pov_character_id TEXT REFERENCES characters(id) ON DELETE SET NULL,
+            notes TEXT,
+            word_target INTEGER,
+            trashed INTEGER NOT NULL DEFAULT 0,
+            trashed_at TEXT
         );
 
         CREATE TABLE IF NOT EXISTS beats (
@@ -70,6 +89,24 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             PRIMARY KEY (character_id, key)
         );
 
+        CREATE TABLE IF NOT EXISTS character_aliases (
+            character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+            alias TEXT NOT NULL,
+            PRIMARY KEY (character_id, alias)
+        );
+
+        CREATE TABLE IF NOT EXISTS location_aliases (
+            location_id TEXT NOT NULL REFERENCES locations(id) ON DELETE CASCADE,
+            alias TEXT NOT NULL,
+            PRIMARY KEY (location_id, alias)
+        );
+
+        CREATE TABLE IF NOT EXISTS reference_item_aliases (
+            reference_item_id TEXT NOT NULL REFERENCES reference_items(id) ON DELETE CASCADE,
+            alias TEXT NOT NULL,
+            PRIMARY KEY (reference_item_id, alias)
+        );
+
         CREATE TABLE IF NOT EXISTS locations (
             id TEXT PRIMARY KEY,
             project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
@@ -159,7 +196,9 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             scene_count INTEGER NOT NULL,
             beat_count INTEGER NOT NULL,
             word_count INTEGER,
-            schema_version INTEGER NOT NULL DEFAULT 1
+            schema_version INTEGER NOT NULL DEFAULT 1,
+            compression TEXT NOT NULL DEFAULT 'gzip',
+            checksum TEXT
         );
 
         CREATE TABLE IF NOT EXISTS tags (
@@ -180,6 +219,13 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             PRIMARY KEY (tag_id, entity_type, entity_id)
         );
 
+        CREATE TABLE IF NOT EXISTS scene_tags (
+            scene_id TEXT NOT NULL REFERENCES scenes(id) ON DELETE CASCADE,
+            tag TEXT NOT NULL,
+            normalized_tag TEXT NOT NULL,
+            PRIMARY KEY (scene_id, normalized_tag)
+        );
+
         CREATE TABLE IF NOT EXISTS saved_filters (
             id TEXT PRIMARY KEY NOT NULL,
             project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
@@ -228,6 +274,22 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
             PRIMARY KEY (scene_id, reference_id)
         );
 
+        CREATE TABLE IF NOT EXISTS writing_sessions (
+            id TEXT PRIMARY KEY NOT NULL,
+            project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+            date TEXT NOT NULL,
+            start_word_count INTEGER NOT NULL,
+            end_word_count INTEGER,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS beat_revisions (
+            id TEXT PRIMARY KEY NOT NULL,
+            beat_id TEXT NOT NULL REFERENCES beats(id) ON DELETE CASCADE,
+            prose TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        );
+
         -- Create indexes for common queries
         CREATE INDEX IF NOT EXISTS idx_chapters_project ON chapters(project_id);
         CREATE INDEX IF NOT EXISTS idx_scenes_chapter ON scenes(chapter_id);
@@ -245,11 +307,17 @@ pub fn initialize_schema(conn: &Connection) -> Result<()> {
         CREATE INDEX IF NOT EXISTS idx_tags_project ON tags(project_id);
         CREATE INDEX IF NOT EXISTS idx_entity_tags_tag ON entity_tags(tag_id);
         CREATE INDEX IF NOT EXISTS idx_entity_tags_entity ON entity_tags(entity_type, entity_id);
+        CREATE INDEX IF NOT EXISTS idx_scene_tags_tag ON scene_tags(normalized_tag);
         CREATE INDEX IF NOT EXISTS idx_saved_filters_project ON saved_filters(project_id);
         CREATE INDEX IF NOT EXISTS idx_field_definitions_project ON field_definitions(project_id, entity_type);
         CREATE INDEX IF NOT EXISTS idx_field_values_definition ON field_values(field_definition_id);
         CREATE INDEX IF NOT EXISTS idx_field_values_entity ON field_values(entity_id);
         CREATE INDEX IF NOT EXISTS idx_dismissed_suggestions_scene ON dismissed_suggestions(scene_id);
+        CREATE INDEX IF NOT EXISTS idx_character_aliases_character ON character_aliases(character_id);
+        CREATE INDEX IF NOT EXISTS idx_location_aliases_location ON location_aliases(location_id);
+        CREATE INDEX IF NOT EXISTS idx_reference_item_aliases_item ON reference_item_aliases(reference_item_id);
+        CREATE INDEX IF NOT EXISTS idx_writing_sessions_project ON writing_sessions(project_id, date);
+        CREATE INDEX IF NOT EXISTS idx_beat_revisions_beat ON beat_revisions(beat_id);
 
         "#,
     )?;
@@ -385,6 +453,27 @@ fn apply_migrations(conn: &Connection) -> Result<()> {
             [],
         )?;
     }
+    if !columns.contains(&"cached_word_count".to_string()) {
+        conn.execute(
+            "ALTER TABLE projects ADD COLUMN cached_word_count INTEGER",
+            [],
+        )?;
+    }
+    if !columns.contains(&"scene_statuses".to_string()) {
+        conn.execute("ALTER TABLE projects ADD COLUMN scene_statuses TEXT", [])?;
+    }
+    if !columns.contains(&"publisher".to_string()) {
+        conn.execute("ALTER TABLE projects ADD COLUMN publisher TEXT", [])?;
+    }
+    if !columns.contains(&"isbn".to_string()) {
+        conn.execute("ALTER TABLE projects ADD COLUMN isbn TEXT", [])?;
+    }
+    if !columns.contains(&"series_name".to_string()) {
+        conn.execute("ALTER TABLE projects ADD COLUMN series_name TEXT", [])?;
+    }
+    if !columns.contains(&"series_index".to_string()) {
+        conn.execute("ALTER TABLE projects ADD COLUMN series_index INTEGER", [])?;
+    }
 
     // Migration: Add scene reference tables if missing
     let tables: Vec<String> = conn
@@ -497,6 +586,98 @@ fn apply_migrations(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    // Migration: Add compile_position to scenes
+    if !scene_cols.contains(&"compile_position".to_string()) {
+        conn.execute("ALTER TABLE scenes ADD COLUMN compile_position INTEGER", [])?;
+    }
+
+    // Migration: Add include_in_compile to scenes
+    if !scene_cols.contains(&"include_in_compile".to_string()) {
+        conn.execute(
+            "ALTER TABLE scenes ADD COLUMN include_in_compile INTEGER NOT NULL DEFAULT 1",
+            [],
+        )?;
+    }
+
+    // Migration: Track scenes archived along with their chapter
+    if !scene_cols.contains(&"archived_by_parent".to_string()) {
+        conn.execute(
+            "ALTER TABLE scenes ADD COLUMN archived_by_parent INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    // Migration: Add color labels to chapters and scenes
+    for table in ["chapters", "scenes"] {
+        let cols: Vec<String> = conn
+            .prepare(&format!("PRAGMA table_info({})", table))?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .filter_map(|r| r.ok())
+            .collect();
+        if !cols.contains(&"color".to_string()) {
+            conn.execute(&format!("ALTER TABLE {} ADD COLUMN color TEXT", table), [])?;
+        }
+    }
+
+    // Migration: Add word_target to chapters
+    let chapter_cols: Vec<String> = conn
+        .prepare("PRAGMA table_info(chapters)")?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .collect();
+    if !chapter_cols.contains(&"word_target".to_string()) {
+        conn.execute("ALTER TABLE chapters ADD COLUMN word_target INTEGER", [])?;
+    }
+
+    // Migration: Add point-of-view character to scenes
+    let scene_cols: Vec<String> = conn
+        .prepare("PRAGMA table_info(scenes)")?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .collect();
+    if !scene_cols.contains(&"pov_character_id".to_string()) {
+        conn.execute(
+            "ALTER TABLE scenes ADD COLUMN pov_character_id TEXT \
+             REFERENCES characters(id) ON DELETE SET NULL",
+            [],
+        )?;
+    }
+
+    // Migration: Add private author notes to scenes
+    if !scene_cols.contains(&"notes".to_string()) {
+        conn.execute("ALTER TABLE scenes ADD COLUMN notes TEXT", [])?;
+    }
+
+    // Migration: Add word_target to scenes
+    if !scene_cols.contains(&"word_target".to_string()) {
+        conn.execute("ALTER TABLE scenes ADD COLUMN word_target INTEGER", [])?;
+    }
+
+    // Migration: Add trash columns to chapters and scenes
+    for table in ["chapters", "scenes"] {
+        let cols: Vec<String> = conn
+            .prepare(&format!("PRAGMA table_info({})", table))?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        if !cols.contains(&"trashed".to_string()) {
+            conn.execute(
+                &format!(
+                    "ALTER TABLE {} ADD COLUMN trashed INTEGER NOT NULL DEFAULT 0",
+                    table
+                ),
+                [],
+            )?;
+        }
+        if !cols.contains(&"trashed_at".to_string()) {
+            conn.execute(
+                &format!("ALTER TABLE {} ADD COLUMN trashed_at TEXT", table),
+                [],
+            )?;
+        }
+    }
+
     // Migration: Create field_definitions/field_values tables and migrate attributes
     let tables: Vec<String> = conn
         .prepare("SELECT name FROM sqlite_master WHERE type='table'")?
@@ -599,12 +780,179 @@ fn apply_migrations(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    if !tables.contains(&"character_aliases".to_string()) {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE character_aliases (
+                character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+                alias TEXT NOT NULL,
+                PRIMARY KEY (character_id, alias)
+            );
+            CREATE INDEX idx_character_aliases_character ON character_aliases(character_id);
+            "#,
+        )?;
+    }
+
+    if !tables.contains(&"location_aliases".to_string()) {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE location_aliases (
+                location_id TEXT NOT NULL REFERENCES locations(id) ON DELETE CASCADE,
+                alias TEXT NOT NULL,
+                PRIMARY KEY (location_id, alias)
+            );
+            CREATE INDEX idx_location_aliases_location ON location_aliases(location_id);
+            "#,
+        )?;
+    }
+
+    if !tables.contains(&"reference_item_aliases".to_string()) {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE reference_item_aliases (
+                reference_item_id TEXT NOT NULL REFERENCES reference_items(id) ON DELETE CASCADE,
+                alias TEXT NOT NULL,
+                PRIMARY KEY (reference_item_id, alias)
+            );
+            CREATE INDEX idx_reference_item_aliases_item ON reference_item_aliases(reference_item_id);
+            "#,
+        )?;
+    }
+
+    if !tables.contains(&"writing_sessions".to_string()) {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE writing_sessions (
+                id TEXT PRIMARY KEY NOT NULL,
+                project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+                date TEXT NOT NULL,
+                start_word_count INTEGER NOT NULL,
+                end_word_count INTEGER,
+                created_at TEXT NOT NULL
+            );
+            CREATE INDEX idx_writing_sessions_project ON writing_sessions(project_id, date);
+            "#,
+        )?;
+    }
+
+    if !tables.contains(&"beat_revisions".to_string()) {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE beat_revisions (
+                id TEXT PRIMARY KEY NOT NULL,
+                beat_id TEXT NOT NULL REFERENCES beats(id) ON DELETE CASCADE,
+                prose TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+            CREATE INDEX idx_beat_revisions_beat ON beat_revisions(beat_id);
+            "#,
+        )?;
+    }
+
+    if !tables.contains(&"scene_tags".to_string()) {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE scene_tags (
+                scene_id TEXT NOT NULL REFERENCES scenes(id) ON DELETE CASCADE,
+                tag TEXT NOT NULL,
+                normalized_tag TEXT NOT NULL,
+                PRIMARY KEY (scene_id, normalized_tag)
+            );
+            CREATE INDEX idx_scene_tags_tag ON scene_tags(normalized_tag);
+            "#,
+        )?;
+    }
+
+    // Migration: Record how each snapshot file is compressed
+    let snapshot_cols: Vec<String> = conn
+        .prepare("PRAGMA table_info(snapshots)")?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .collect();
+    if !snapshot_cols.contains(&"compression".to_string()) {
+        conn.execute(
+            "ALTER TABLE snapshots ADD COLUMN compression TEXT NOT NULL DEFAULT 'gzip'",
+            [],
+        )?;
+    }
+    // Migration: Checksum of the snapshot payload for integrity checks
+    if !snapshot_cols.contains(&"checksum".to_string()) {
+        conn.execute("ALTER TABLE snapshots ADD COLUMN checksum TEXT", [])?;
+    }
+
     // Auto-migrate existing *_attributes into field_definitions + field_values
     migrate_attributes_to_fields(conn)?;
 
+    // Migration: Move comma-joined `aliases` attributes into the alias tables
+    migrate_alias_attributes(conn)?;
+
     Ok(())
 }
 
+/// `PRAGMA user_version` once the `aliases` attributes have been moved into the alias tables
+const ALIAS_TABLES_USER_VERSION: i32 = 1;
+
+/// Move the `aliases` attribute older imports stored on locations and reference items
+/// into `location_aliases` / `reference_item_aliases`, dropping the attribute and the
+/// custom field values `migrate_attributes_to_fields` copied from it. Runs once,
+/// recorded in `PRAGMA user_version`.
+fn migrate_alias_attributes(conn: &Connection) -> Result<()> {
+    let version: i32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if version >= ALIAS_TABLES_USER_VERSION {
+        return Ok(());
+    }
+
+    let sources = [
+        ("location_attributes", "location_aliases", "location_id"),
+        (
+            "reference_item_attributes",
+            "reference_item_aliases",
+            "reference_item_id",
+        ),
+    ];
+
+    let tx = conn.unchecked_transaction()?;
+    for (attributes_table, aliases_table, id_col) in sources {
+        let rows: Vec<(String, Option<String>)> = tx
+            .prepare(&format!(
+                "SELECT {id_col}, value FROM {attributes_table} WHERE key = 'aliases'"
+            ))?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>>>()?;
+
+        for (owner_id, value) in &rows {
+            let aliases = value.as_deref().unwrap_or_default().split([',', ';']);
+            for alias in aliases.map(str::trim).filter(|a| !a.is_empty()) {
+                tx.execute(
+                    &format!(
+                        "INSERT OR IGNORE INTO {aliases_table} ({id_col}, alias) VALUES (?1, ?2)"
+                    ),
+                    params![owner_id, alias],
+                )?;
+            }
+            tx.execute(
+                "DELETE FROM field_values
+                 WHERE entity_id = ?1 AND field_definition_id IN
+                     (SELECT id FROM field_definitions WHERE name = 'aliases')",
+                params![owner_id],
+            )?;
+        }
+        tx.execute(
+            &format!("DELETE FROM {attributes_table} WHERE key = 'aliases'"),
+            [],
+        )?;
+    }
+    // Drop the custom fields that only ever held the migrated values
+    tx.execute(
+        "DELETE FROM field_definitions
+         WHERE name = 'aliases' AND entity_type != 'character'
+           AND NOT EXISTS (SELECT 1 FROM field_values v WHERE v.field_definition_id = field_definitions.id)",
+        [],
+    )?;
+    tx.pragma_update(None, "user_version", ALIAS_TABLES_USER_VERSION)?;
+    tx.commit()
+}
+
 /// Migrate legacy *_attributes tables into the new field_definitions + field_values system.
 /// Only runs once: skips if field_definitions already has rows for a given project+entity_type.
 fn migrate_attributes_to_fields(conn: &Connection) -> Result<()> {
@@ -788,6 +1136,63 @@ mod tests {
         assert!(tables.contains(&"field_values".to_string()));
         assert!(tables.contains(&"dismissed_suggestions".to_string()));
         assert!(tables.contains(&"story_templates".to_string()));
+        assert!(tables.contains(&"character_aliases".to_string()));
+        assert!(tables.contains(&"location_aliases".to_string()));
+        assert!(tables.contains(&"reference_item_aliases".to_string()));
+        assert!(tables.contains(&"writing_sessions".to_string()));
+        assert!(tables.contains(&"beat_revisions".to_string()));
+        assert!(tables.contains(&"scene_tags".to_string()));
+
+        let scene_columns: Vec<String> = conn
+            .prepare("PRAGMA table_info(scenes)")
+            .unwrap()
+            .query_map([], |row| row.get(1))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert!(scene_columns.contains(&"trashed".to_string()));
+        assert!(scene_columns.contains(&"trashed_at".to_string()));
+        assert!(scene_columns.contains(&"compile_position".to_string()));
+        assert!(scene_columns.contains(&"include_in_compile".to_string()));
+        assert!(scene_columns.contains(&"archived_by_parent".to_string()));
+        assert!(scene_columns.contains(&"color".to_string()));
+        assert!(scene_columns.contains(&"pov_character_id".to_string()));
+        assert!(scene_columns.contains(&"notes".to_string()));
+        assert!(scene_columns.contains(&"word_target".to_string()));
+
+        let chapter_columns: Vec<String> = conn
+            .prepare("PRAGMA table_info(chapters)")
+            .unwrap()
+            .query_map([], |row| row.get(1))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert!(chapter_columns.contains(&"color".to_string()));
+        assert!(chapter_columns.contains(&"word_target".to_string()));
+
+        let project_columns: Vec<String> = conn
+            .prepare("PRAGMA table_info(projects)")
+            .unwrap()
+            .query_map([], |row| row.get(1))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert!(project_columns.contains(&"cached_word_count".to_string()));
+        assert!(project_columns.contains(&"scene_statuses".to_string()));
+        assert!(project_columns.contains(&"publisher".to_string()));
+        assert!(project_columns.contains(&"isbn".to_string()));
+        assert!(project_columns.contains(&"series_name".to_string()));
+        assert!(project_columns.contains(&"series_index".to_string()));
+
+        let snapshot_columns: Vec<String> = conn
+            .prepare("PRAGMA table_info(snapshots)")
+            .unwrap()
+            .query_map([], |row| row.get(1))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert!(snapshot_columns.contains(&"compression".to_string()));
+        assert!(snapshot_columns.contains(&"checksum".to_string()));
     }
 
     #[test]
@@ -846,4 +1251,76 @@ mod tests {
             .unwrap();
         assert_eq!(def_count_after, 2);
     }
+
+    #[test]
+    fn test_alias_attribute_migration() {
+        let conn = Connection::open_in_memory().unwrap();
+        initialize_schema(&conn).unwrap();
+
+        let project_id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO projects (id, name, source_type, created_at, modified_at) VALUES (?1, 'Test', 'Blank', datetime('now'), datetime('now'))",
+            params![project_id],
+        ).unwrap();
+        let location_id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO locations (id, project_id, name) VALUES (?1, ?2, 'Castle')",
+            params![location_id, project_id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO location_attributes (location_id, key, value) VALUES (?1, 'aliases', 'Fort Knox; The Keep')",
+            params![location_id],
+        ).unwrap();
+
+        // A fresh database is already marked as migrated
+        migrate_alias_attributes(&conn).unwrap();
+        let count_aliases = |conn: &Connection| -> i64 {
+            conn.query_row("SELECT count(*) FROM location_aliases", [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+        };
+        assert_eq!(count_aliases(&conn), 0);
+
+        conn.pragma_update(None, "user_version", 0).unwrap();
+        migrate_attributes_to_fields(&conn).unwrap();
+        migrate_alias_attributes(&conn).unwrap();
+
+        let aliases: Vec<String> = conn
+            .prepare("SELECT alias FROM location_aliases WHERE location_id = ?1 ORDER BY alias")
+            .unwrap()
+            .query_map(params![location_id], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(aliases, vec!["Fort Knox", "The Keep"]);
+
+        let leftover: i64 = conn
+            .query_row(
+                "SELECT count(*) FROM location_attributes WHERE key = 'aliases'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(leftover, 0);
+
+        // The custom field copied from the attribute goes with it
+        let field_rows: i64 = conn
+            .query_row(
+                "SELECT (SELECT count(*) FROM field_values) + (SELECT count(*) FROM field_definitions)",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(field_rows, 0);
+
+        // Later runs leave a new `aliases` attribute alone
+        conn.execute(
+            "INSERT INTO location_attributes (location_id, key, value) VALUES (?1, 'aliases', 'Tower')",
+            params![location_id],
+        ).unwrap();
+        migrate_alias_attributes(&conn).unwrap();
+        assert_eq!(count_aliases(&conn), 2);
+    }
 }
@@ -1,7 +1,9 @@
-use crate::models::{EntityTag, SavedFilter, Tag};
+use crate::models::{EntityTag, SavedFilter, SceneTagRef, Tag};
 use rusqlite::{params, Connection, Result};
 use uuid::Uuid;
 
+use super::queries::parse_uuid;
+
 // ============================================================================
 // Tags CRUD
 // ============================================================================
@@ -15,19 +17,19 @@ pub fn get_tags(conn: &Connection, project_id: &Uuid) -> Result<Vec<Tag>> {
     let tags = stmt
         .query_map(params![project_id.to_string()], |row| {
             Ok(Tag {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                project_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
                 name: row.get(2)?,
                 color: row.get(3)?,
                 parent_id: row
                     .get::<_, Option<String>>(4)?
-                    .and_then(|s| Uuid::parse_str(&s).ok()),
+                    .map(|s| parse_uuid(&s))
+                    .transpose()?,
                 position: row.get(5)?,
                 created_at: row.get(6)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(tags)
 }
@@ -144,19 +146,19 @@ pub fn get_entity_tags(conn: &Connection, entity_type: &str, entity_id: &Uuid) -
     let tags = stmt
         .query_map(params![entity_type, entity_id.to_string()], |row| {
             Ok(Tag {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                project_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
                 name: row.get(2)?,
                 color: row.get(3)?,
                 parent_id: row
                     .get::<_, Option<String>>(4)?
-                    .and_then(|s| Uuid::parse_str(&s).ok()),
+                    .map(|s| parse_uuid(&s))
+                    .transpose()?,
                 position: row.get(5)?,
                 created_at: row.get(6)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(tags)
 }
@@ -199,13 +201,12 @@ pub fn get_all_entity_tags_for_project(
     let tags = stmt
         .query_map(params![project_id.to_string()], |row| {
             Ok(EntityTag {
-                tag_id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
+                tag_id: parse_uuid(&row.get::<_, String>(0)?)?,
                 entity_type: row.get(1)?,
-                entity_id: Uuid::parse_str(&row.get::<_, String>(2)?).unwrap_or_default(),
+                entity_id: parse_uuid(&row.get::<_, String>(2)?)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(tags)
 }
@@ -295,10 +296,9 @@ pub fn filter_entities(
     let ids = stmt
         .query_map(param_refs.as_slice(), |row| {
             let id_str: String = row.get(0)?;
-            Ok(Uuid::parse_str(&id_str).unwrap_or_default())
+            parse_uuid(&id_str)
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(ids)
 }
@@ -337,16 +337,15 @@ pub fn get_saved_filters(conn: &Connection, project_id: &Uuid) -> Result<Vec<Sav
     let filters = stmt
         .query_map(params![project_id.to_string()], |row| {
             Ok(SavedFilter {
-                id: Uuid::parse_str(&row.get::<_, String>(0)?).unwrap_or_default(),
-                project_id: Uuid::parse_str(&row.get::<_, String>(1)?).unwrap_or_default(),
+                id: parse_uuid(&row.get::<_, String>(0)?)?,
+                project_id: parse_uuid(&row.get::<_, String>(1)?)?,
                 name: row.get(2)?,
                 entity_type: row.get(3)?,
                 filter_json: row.get(4)?,
                 position: row.get(5)?,
             })
         })?
-        .filter_map(|r| r.ok())
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(filters)
 }
@@ -375,6 +374,84 @@ pub fn delete_saved_filter(conn: &Connection, id: &Uuid) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Scene Tags
+// ============================================================================
+
+/// Free-text scene tags ("POV: Alice", "subplot: heist") are stored as the
+/// user typed them (trimmed) and matched case-insensitively.
+pub fn normalize_scene_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+/// Tag a scene. Re-adding a tag that differs only in case is a no-op.
+pub fn add_scene_tag(conn: &Connection, scene_id: &Uuid, tag: &str) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO scene_tags (scene_id, tag, normalized_tag) VALUES (?1, ?2, ?3)",
+        params![scene_id.to_string(), tag.trim(), normalize_scene_tag(tag)],
+    )?;
+    Ok(())
+}
+
+pub fn remove_scene_tag(conn: &Connection, scene_id: &Uuid, tag: &str) -> Result<()> {
+    conn.execute(
+        "DELETE FROM scene_tags WHERE scene_id = ?1 AND normalized_tag = ?2",
+        params![scene_id.to_string(), normalize_scene_tag(tag)],
+    )?;
+    Ok(())
+}
+
+pub fn get_scene_tags(conn: &Connection, scene_id: &Uuid) -> Result<Vec<String>> {
+    let mut stmt =
+        conn.prepare("SELECT tag FROM scene_tags WHERE scene_id = ?1 ORDER BY normalized_tag")?;
+    let tags = stmt
+        .query_map(params![scene_id.to_string()], |row| row.get(0))?
+        .collect::<Result<Vec<String>>>()?;
+    Ok(tags)
+}
+
+/// Every scene tag in a project, including those on archived scenes
+pub fn get_all_scene_tags_for_project(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<Vec<SceneTagRef>> {
+    let mut stmt = conn.prepare(
+        "SELECT st.scene_id, st.tag FROM scene_tags st
+         JOIN scenes s ON s.id = st.scene_id
+         JOIN chapters c ON c.id = s.chapter_id
+         WHERE c.project_id = ?1
+         ORDER BY st.scene_id, st.normalized_tag",
+    )?;
+    let tags = stmt
+        .query_map(params![project_id.to_string()], |row| {
+            Ok(SceneTagRef {
+                scene_id: parse_uuid(&row.get::<_, String>(0)?)?,
+                tag: row.get(1)?,
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(tags)
+}
+
+/// Ids of the project's live scenes carrying `tag`, in binder order
+pub fn get_scenes_by_tag(conn: &Connection, project_id: &Uuid, tag: &str) -> Result<Vec<Uuid>> {
+    let mut stmt = conn.prepare(
+        "SELECT s.id FROM scene_tags st
+         JOIN scenes s ON s.id = st.scene_id
+         JOIN chapters c ON c.id = s.chapter_id
+         WHERE c.project_id = ?1 AND st.normalized_tag = ?2
+           AND s.archived = 0 AND s.trashed = 0 AND c.archived = 0 AND c.trashed = 0
+         ORDER BY c.position, s.position",
+    )?;
+    let ids = stmt
+        .query_map(
+            params![project_id.to_string(), normalize_scene_tag(tag)],
+            |row| parse_uuid(&row.get::<_, String>(0)?),
+        )?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ids)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -418,6 +495,19 @@ mod tests {
         assert!(tags.is_empty());
     }
 
+    #[test]
+    fn test_get_tags_reports_malformed_id() {
+        let (conn, project_id) = setup();
+        conn.execute(
+            "INSERT INTO tags (id, project_id, name, position, created_at)
+             VALUES ('not-a-uuid', ?1, 'Broken', 0, datetime('now'))",
+            params![project_id.to_string()],
+        )
+        .unwrap();
+
+        assert!(get_tags(&conn, &project_id).is_err());
+    }
+
     #[test]
     fn test_entity_tagging() {
         let (conn, project_id) = setup();
@@ -502,4 +592,62 @@ mod tests {
             assert!(tags.is_empty());
         }
     }
+
+    #[test]
+    fn test_scene_tags_filter_and_cascade() {
+        use crate::db::{delete_scene, insert_chapter, insert_scene};
+        use crate::models::{Chapter, Scene};
+
+        let (conn, project_id) = setup();
+        let chapter = Chapter::new(project_id, "One".to_string(), 0);
+        insert_chapter(&conn, &chapter).unwrap();
+        let first = Scene::new(chapter.id, "First".to_string(), None, 0);
+        let second = Scene::new(chapter.id, "Second".to_string(), None, 1);
+        insert_scene(&conn, &first).unwrap();
+        insert_scene(&conn, &second).unwrap();
+
+        add_scene_tag(&conn, &first.id, "  POV: Alice ").unwrap();
+        add_scene_tag(&conn, &first.id, "subplot: heist").unwrap();
+        // Same tag in another case is not added twice
+        add_scene_tag(&conn, &first.id, "pov: alice").unwrap();
+        add_scene_tag(&conn, &second.id, "Subplot: Heist").unwrap();
+
+        assert_eq!(
+            get_scene_tags(&conn, &first.id).unwrap(),
+            vec!["POV: Alice".to_string(), "subplot: heist".to_string()]
+        );
+        assert_eq!(
+            get_scene_tags(&conn, &second.id).unwrap(),
+            vec!["Subplot: Heist".to_string()]
+        );
+
+        assert_eq!(
+            get_scenes_by_tag(&conn, &project_id, "SUBPLOT: HEIST").unwrap(),
+            vec![first.id, second.id]
+        );
+        assert_eq!(
+            get_scenes_by_tag(&conn, &project_id, "pov: alice").unwrap(),
+            vec![first.id]
+        );
+
+        remove_scene_tag(&conn, &first.id, "Pov: Alice").unwrap();
+        assert_eq!(
+            get_scene_tags(&conn, &first.id).unwrap(),
+            vec!["subplot: heist".to_string()]
+        );
+
+        delete_scene(&conn, &second.id).unwrap();
+        let remaining: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM scene_tags WHERE scene_id = ?1",
+                params![second.id.to_string()],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(remaining, 0);
+        assert_eq!(
+            get_scenes_by_tag(&conn, &project_id, "subplot: heist").unwrap(),
+            vec![first.id]
+        );
+    }
 }
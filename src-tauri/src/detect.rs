@@ -4,16 +4,16 @@ use rusqlite::{params, Connection, Result};
 use uuid::Uuid;
 
 use crate::db;
-use crate::models::ReferenceSuggestion;
+use crate::models::{AutoLinkCounts, Location, ReferenceItem, ReferenceSuggestion};
 
 /// Entry in the name index: (reference_id, reference_type, display_name, confidence)
 type NameEntry = (Uuid, String, String, f32);
 
 /// Build a lookup from normalised name -> (reference_id, type, display_name, confidence).
 ///
-/// Characters get their full name at 1.0 and, when the first token is unique across
-/// all references, that first name at 0.7.  Locations and reference_items get their
-/// full name at 1.0.
+/// Characters get their full name at 1.0, each alias at 0.9 and, when the first token
+/// is unique across all references, that first name at 0.7.  Locations and
+/// reference_items get their full name at 1.0 and each alias at 0.9.
 pub fn build_name_index(
     conn: &Connection,
     project_id: &Uuid,
@@ -21,6 +21,9 @@ pub fn build_name_index(
     let characters = db::get_characters(conn, project_id)?;
     let locations = db::get_locations(conn, project_id)?;
     let reference_items = db::get_all_reference_items(conn, project_id)?;
+    let character_aliases = db::get_project_character_aliases(conn, project_id)?;
+    let location_aliases = db::get_project_location_aliases(conn, project_id)?;
+    let reference_item_aliases = db::get_project_reference_item_aliases(conn, project_id)?;
 
     let mut index: HashMap<String, NameEntry> = HashMap::new();
 
@@ -68,6 +71,18 @@ pub fn build_name_index(
         }
     }
 
+    let character_names: HashMap<Uuid, &String> =
+        characters.iter().map(|ch| (ch.id, &ch.name)).collect();
+    for (character_id, alias) in &character_aliases {
+        if let Some(name) = character_names.get(character_id) {
+            insert_alias(
+                &mut index,
+                alias,
+                (*character_id, "character".to_string(), (*name).clone(), 0.9),
+            );
+        }
+    }
+
     for loc in &locations {
         let key = loc.name.trim().to_lowercase();
         if !key.is_empty() {
@@ -86,9 +101,49 @@ pub fn build_name_index(
         }
     }
 
+    let locations_by_id: HashMap<Uuid, &Location> =
+        locations.iter().map(|loc| (loc.id, loc)).collect();
+    for (location_id, alias) in &location_aliases {
+        if let Some(loc) = locations_by_id.get(location_id) {
+            insert_alias(
+                &mut index,
+                alias,
+                (loc.id, "location".to_string(), loc.name.clone(), 0.9),
+            );
+        }
+    }
+
+    let reference_items_by_id: HashMap<Uuid, &ReferenceItem> =
+        reference_items.iter().map(|ri| (ri.id, ri)).collect();
+    for (reference_item_id, alias) in &reference_item_aliases {
+        if let Some(ri) = reference_items_by_id.get(reference_item_id) {
+            insert_alias(
+                &mut index,
+                alias,
+                (ri.id, ri.reference_type.clone(), ri.name.clone(), 0.9),
+            );
+        }
+    }
+
     Ok(index)
 }
 
+/// Add an alias to the index, upgrading a weaker existing match but never
+/// replacing a full-name match.
+fn insert_alias(index: &mut HashMap<String, NameEntry>, alias: &str, entry: NameEntry) {
+    let key = alias.trim().to_lowercase();
+    if key.is_empty() {
+        return;
+    }
+
+    match index.get(&key) {
+        Some(existing) if existing.3 >= entry.3 => {}
+        _ => {
+            index.insert(key, entry);
+        }
+    }
+}
+
 /// Strip HTML tags from a string, returning plain text.
 pub fn strip_html(html: &str) -> String {
     let mut out = String::with_capacity(html.len());
@@ -121,13 +176,8 @@ pub fn detect_references(
     detect_references_with_index(conn, &name_index, scene_id)
 }
 
-/// Internal: run detection for a single scene using a pre-built index.
-fn detect_references_with_index(
-    conn: &Connection,
-    name_index: &HashMap<String, NameEntry>,
-    scene_id: &Uuid,
-) -> Result<Vec<ReferenceSuggestion>> {
-    // Gather prose text
+/// Gather a scene's prose and its beats' prose into a single HTML string.
+fn scene_prose_html(conn: &Connection, scene_id: &Uuid) -> Result<String> {
     let mut prose_parts: Vec<String> = Vec::new();
 
     if let Some(scene) = db::get_scene_by_id(conn, scene_id)? {
@@ -143,7 +193,16 @@ fn detect_references_with_index(
         }
     }
 
-    let raw_html = prose_parts.join(" ");
+    Ok(prose_parts.join(" "))
+}
+
+/// Internal: run detection for a single scene using a pre-built index.
+fn detect_references_with_index(
+    conn: &Connection,
+    name_index: &HashMap<String, NameEntry>,
+    scene_id: &Uuid,
+) -> Result<Vec<ReferenceSuggestion>> {
+    let raw_html = scene_prose_html(conn, scene_id)?;
     if raw_html.is_empty() {
         return Ok(Vec::new());
     }
@@ -268,6 +327,162 @@ pub fn detect_all_references(
     Ok(result)
 }
 
+/// Returns true when `needle` occurs in `haystack` bounded by non-word characters.
+///
+/// Both arguments are expected to already be lowercased.
+fn contains_whole_word(haystack: &str, needle: &str) -> bool {
+    if needle.is_empty() {
+        return false;
+    }
+
+    haystack.match_indices(needle).any(|(start, _)| {
+        let end = start + needle.len();
+        let before_ok = haystack[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let after_ok = haystack[end..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric());
+        before_ok && after_ok
+    })
+}
+
+/// Find characters linked to a scene whose name (or any alias) never appears in
+/// that scene's prose.
+///
+/// Matching is whole-word and case-insensitive. Archived scenes are skipped, and
+/// scenes where every linked character is mentioned are omitted from the result.
+pub fn find_unmentioned_linked_characters(
+    conn: &Connection,
+    project_id: &Uuid,
+) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+    let characters = db::get_characters(conn, project_id)?;
+    let mut names: HashMap<Uuid, Vec<String>> = characters
+        .iter()
+        .map(|ch| (ch.id, vec![ch.name.trim().to_lowercase()]))
+        .collect();
+    for (character_id, alias) in db::get_project_character_aliases(conn, project_id)? {
+        if let Some(list) = names.get_mut(&character_id) {
+            list.push(alias.trim().to_lowercase());
+        }
+    }
+
+    let mut result: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+
+    for scene in db::get_all_project_scenes(conn, project_id)? {
+        if scene.archived {
+            continue;
+        }
+
+        let linked = db::get_scene_characters(conn, &scene.id)?;
+        if linked.is_empty() {
+            continue;
+        }
+
+        let plain = strip_html(&scene_prose_html(conn, &scene.id)?).to_lowercase();
+        let unmentioned: Vec<Uuid> = linked
+            .into_iter()
+            .filter(|character_id| {
+                !names
+                    .get(character_id)
+                    .is_some_and(|list| list.iter().any(|name| contains_whole_word(&plain, name)))
+            })
+            .collect();
+
+        if !unmentioned.is_empty() {
+            result.insert(scene.id, unmentioned);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Link every non-archived scene to the characters, locations and reference items
+/// whose name or any alias appears in its prose as a whole word.
+///
+/// Existing links are left alone, so running it again is a no-op; the returned
+/// counts only include links that were newly created.
+pub fn auto_link_references(conn: &Connection, project_id: &Uuid) -> Result<AutoLinkCounts> {
+    let lowered = |name: &str| name.trim().to_lowercase();
+
+    let mut character_names: HashMap<Uuid, Vec<String>> = db::get_characters(conn, project_id)?
+        .iter()
+        .map(|ch| (ch.id, vec![lowered(&ch.name)]))
+        .collect();
+    for (character_id, alias) in db::get_project_character_aliases(conn, project_id)? {
+        if let Some(list) = character_names.get_mut(&character_id) {
+            list.push(lowered(&alias));
+        }
+    }
+    let mut location_names: HashMap<Uuid, Vec<String>> = db::get_locations(conn, project_id)?
+        .iter()
+        .map(|loc| (loc.id, vec![lowered(&loc.name)]))
+        .collect();
+    for (location_id, alias) in db::get_project_location_aliases(conn, project_id)? {
+        if let Some(list) = location_names.get_mut(&location_id) {
+            list.push(lowered(&alias));
+        }
+    }
+    let mut item_names: HashMap<Uuid, Vec<String>> = db::get_all_reference_items(conn, project_id)?
+        .iter()
+        .map(|ri| (ri.id, vec![lowered(&ri.name)]))
+        .collect();
+    for (item_id, alias) in db::get_project_reference_item_aliases(conn, project_id)? {
+        if let Some(list) = item_names.get_mut(&item_id) {
+            list.push(lowered(&alias));
+        }
+    }
+
+    let mut counts = AutoLinkCounts::default();
+
+    for scene in db::get_all_project_scenes(conn, project_id)? {
+        if scene.archived {
+            continue;
+        }
+        let plain = strip_html(&scene_prose_html(conn, &scene.id)?).to_lowercase();
+        if plain.trim().is_empty() {
+            continue;
+        }
+
+        let linked: HashSet<Uuid> = db::get_scene_characters(conn, &scene.id)?
+            .into_iter()
+            .collect();
+        for (character_id, names) in &character_names {
+            if !linked.contains(character_id)
+                && names.iter().any(|n| contains_whole_word(&plain, n))
+            {
+                db::add_scene_character_ref(conn, &scene.id, character_id)?;
+                counts.characters += 1;
+            }
+        }
+
+        let linked: HashSet<Uuid> = db::get_scene_locations(conn, &scene.id)?
+            .into_iter()
+            .collect();
+        for (location_id, names) in &location_names {
+            if !linked.contains(location_id) && names.iter().any(|n| contains_whole_word(&plain, n))
+            {
+                db::add_scene_location_ref(conn, &scene.id, location_id)?;
+                counts.locations += 1;
+            }
+        }
+
+        let linked: HashSet<Uuid> = db::get_scene_reference_item_ids(conn, &scene.id)?
+            .into_iter()
+            .collect();
+        for (item_id, names) in &item_names {
+            if !linked.contains(item_id) && names.iter().any(|n| contains_whole_word(&plain, n)) {
+                db::add_scene_reference_item_ref(conn, &scene.id, item_id)?;
+                counts.reference_items += 1;
+            }
+        }
+    }
+
+    Ok(counts)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -493,6 +708,162 @@ mod tests {
         assert!(ref_ids.contains(&ch.id.to_string()));
     }
 
+    #[test]
+    fn test_detect_references_matches_aliases() {
+        let conn = setup_test_db();
+        let project_id = insert_test_project(&conn);
+
+        let chapter = Chapter::new(project_id, "Ch 1".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+
+        let mut scene = Scene::new(chapter.id, "Scene 1".to_string(), None, 0);
+        scene.prose = Some("<p>Lizzy ran toward the Keep.</p>".to_string());
+        db::insert_scene(&conn, &scene).unwrap();
+
+        let ch = Character::new(project_id, "Elizabeth Bennet".to_string(), None, None);
+        db::insert_character(&conn, &ch).unwrap();
+        db::add_character_alias(&conn, &ch.id, "Lizzy").unwrap();
+
+        let loc = Location::new(project_id, "Castle".to_string(), None, None);
+        db::insert_location(&conn, &loc).unwrap();
+        db::add_location_alias(&conn, &loc.id, "The Keep").unwrap();
+
+        let suggestions = detect_references(&conn, &project_id, &scene.id).unwrap();
+
+        let alias_hit = suggestions
+            .iter()
+            .find(|s| s.reference_id == ch.id.to_string())
+            .expect("alias should match character");
+        assert_eq!(alias_hit.reference_name, "Elizabeth Bennet");
+        assert_eq!(alias_hit.match_text, "Lizzy");
+        assert!(suggestions
+            .iter()
+            .any(|s| s.reference_id == loc.id.to_string()));
+    }
+
+    #[test]
+    fn test_contains_whole_word() {
+        assert!(contains_whole_word("alice went home.", "alice"));
+        assert!(contains_whole_word("said mr. darcy", "mr. darcy"));
+        assert!(!contains_whole_word("malice aforethought", "alice"));
+        assert!(!contains_whole_word("alicent waved", "alice"));
+        assert!(!contains_whole_word("anything", ""));
+    }
+
+    #[test]
+    fn test_find_unmentioned_linked_characters() {
+        let conn = setup_test_db();
+        let project_id = insert_test_project(&conn);
+
+        let chapter = Chapter::new(project_id, "Ch 1".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+
+        let mut scene = Scene::new(chapter.id, "Scene 1".to_string(), None, 0);
+        scene.prose = Some("<p>Lizzy opened the door. Malice hung in the air.</p>".to_string());
+        db::insert_scene(&conn, &scene).unwrap();
+
+        let elizabeth = Character::new(project_id, "Elizabeth".to_string(), None, None);
+        db::insert_character(&conn, &elizabeth).unwrap();
+        db::add_character_alias(&conn, &elizabeth.id, "LIZZY").unwrap();
+
+        let alice = Character::new(project_id, "Alice".to_string(), None, None);
+        db::insert_character(&conn, &alice).unwrap();
+
+        db::add_scene_character_ref(&conn, &scene.id, &elizabeth.id).unwrap();
+        db::add_scene_character_ref(&conn, &scene.id, &alice.id).unwrap();
+
+        let result = find_unmentioned_linked_characters(&conn, &project_id).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.get(&scene.id), Some(&vec![alice.id]));
+    }
+
+    #[test]
+    fn test_auto_link_references() {
+        let conn = setup_test_db();
+        let project_id = insert_test_project(&conn);
+
+        let chapter = Chapter::new(project_id, "Ch 1".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+
+        let scene = Scene::new(chapter.id, "Scene 1".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+        beat.prose = Some("<p>Alice crossed Winterfell with the sword.</p>".to_string());
+        db::insert_beat(&conn, &beat).unwrap();
+
+        let alice = Character::new(project_id, "Alice".to_string(), None, None);
+        db::insert_character(&conn, &alice).unwrap();
+        let al = Character::new(project_id, "Al".to_string(), None, None);
+        db::insert_character(&conn, &al).unwrap();
+        let winterfell = Location::new(project_id, "Winterfell".to_string(), None, None);
+        db::insert_location(&conn, &winterfell).unwrap();
+
+        let counts = auto_link_references(&conn, &project_id).unwrap();
+        assert_eq!(
+            counts,
+            AutoLinkCounts {
+                characters: 1,
+                locations: 1,
+                reference_items: 0,
+            }
+        );
+        assert_eq!(
+            db::get_scene_characters(&conn, &scene.id).unwrap(),
+            vec![alice.id],
+            "\"Al\" must not match inside \"Alice\""
+        );
+
+        // Running again links nothing new
+        let again = auto_link_references(&conn, &project_id).unwrap();
+        assert_eq!(again, AutoLinkCounts::default());
+    }
+
+    #[test]
+    fn test_auto_link_references_matches_location_and_item_aliases() {
+        let conn = setup_test_db();
+        let project_id = insert_test_project(&conn);
+
+        let chapter = Chapter::new(project_id, "Ch 1".to_string(), 0);
+        db::insert_chapter(&conn, &chapter).unwrap();
+        let scene = Scene::new(chapter.id, "Scene 1".to_string(), None, 0);
+        db::insert_scene(&conn, &scene).unwrap();
+        let mut beat = Beat::new(scene.id, "Beat".to_string(), 0);
+        beat.prose = Some("<p>They rode into the Capital carrying the blade.</p>".to_string());
+        db::insert_beat(&conn, &beat).unwrap();
+
+        let city = Location::new(project_id, "King's Landing".to_string(), None, None);
+        db::insert_location(&conn, &city).unwrap();
+        db::add_location_alias(&conn, &city.id, "the Capital").unwrap();
+        let sword = ReferenceItem::new(
+            project_id,
+            "items".to_string(),
+            "Ice".to_string(),
+            None,
+            None,
+        );
+        db::insert_reference_item(&conn, &sword).unwrap();
+        db::add_reference_item_alias(&conn, &sword.id, "Blade").unwrap();
+
+        let counts = auto_link_references(&conn, &project_id).unwrap();
+        assert_eq!(
+            counts,
+            AutoLinkCounts {
+                characters: 0,
+                locations: 1,
+                reference_items: 1,
+            }
+        );
+        assert_eq!(
+            db::get_scene_locations(&conn, &scene.id).unwrap(),
+            vec![city.id]
+        );
+        assert_eq!(
+            db::get_scene_reference_item_ids(&conn, &scene.id).unwrap(),
+            vec![sword.id]
+        );
+    }
+
     #[test]
     fn test_detect_references_empty_prose() {
         let conn = setup_test_db();
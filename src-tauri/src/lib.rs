@@ -103,8 +103,12 @@ pub fn run() {
             commands::import_plottr,
             commands::import_ywriter,
             commands::import_markdown,
+            commands::import_odt,
+            commands::import_docx,
+            commands::import_fountain,
             commands::import_longform,
             commands::import_scrivener,
+            commands::import_beats_csv,
             commands::preview_import,
             commands::create_sample_project,
             commands::create_blank_project,
@@ -112,6 +116,7 @@ pub fn run() {
             commands::get_page_count_estimate,
             commands::get_project,
             commands::get_recent_projects,
+            commands::get_recent_project_infos,
             commands::get_all_projects,
             commands::update_project_settings,
             commands::delete_project,
@@ -122,16 +127,28 @@ pub fn run() {
             commands::get_beats,
             commands::create_beat,
             commands::get_characters,
+            commands::get_characters_with_attribute,
+            commands::get_character_appearances,
+            commands::get_character_aliases,
+            commands::add_character_alias,
+            commands::remove_character_alias,
             commands::get_locations,
             commands::get_references,
             commands::get_scene_reference_items,
+            commands::get_all_reference_items,
             commands::get_scene_reference_state,
             commands::create_reference,
             commands::update_reference,
             commands::delete_reference,
+            commands::get_reference_aliases,
+            commands::add_reference_alias,
+            commands::remove_reference_alias,
+            commands::find_orphaned_refs,
             commands::save_scene_reference_state,
             commands::reclassify_references,
             commands::save_beat_prose,
+            commands::get_beat_revisions,
+            commands::restore_beat_revision,
             commands::delete_beat,
             commands::reorder_beats,
             commands::split_beat,
@@ -143,20 +160,31 @@ pub fn run() {
             commands::delete_discovery_note,
             commands::promote_discovery_note_to_beat,
             commands::save_scene_synopsis,
+            commands::update_scene_notes,
             commands::update_scene_metadata,
             commands::update_scene_planning_status,
+            commands::update_scene_compile_position,
+            commands::set_scene_compile_inclusion,
             commands::update_chapter_planning_status,
+            commands::set_chapter_color,
+            commands::set_chapter_word_target,
+            commands::set_scene_color,
+            commands::set_scene_pov,
             commands::update_chapter_synopsis,
             commands::save_scene_prose,
             commands::switch_scene_editor_mode,
+            commands::consolidate_scene_prose,
             commands::save_scene_page_prose,
             commands::reorder_chapters,
+            commands::move_chapter,
             commands::reorder_scenes,
             commands::move_scene_to_chapter,
+            commands::move_and_reorder_scene,
             commands::get_chapter_content_counts,
             commands::get_scene_beat_count,
             commands::delete_chapter,
             commands::delete_scene,
+            commands::merge_scenes,
             commands::reimport_project,
             commands::get_sync_preview,
             commands::apply_sync,
@@ -171,7 +199,15 @@ pub fn run() {
             commands::archive_scene,
             commands::restore_chapter,
             commands::restore_scene,
+            commands::archive_chapter_cascade,
+            commands::restore_chapter_cascade,
             commands::get_archived_items,
+            commands::trash_chapter,
+            commands::trash_scene,
+            commands::restore_from_trash_chapter,
+            commands::restore_from_trash_scene,
+            commands::get_trashed_items,
+            commands::empty_trash,
             // Lock and Part commands
             commands::lock_chapter,
             commands::unlock_chapter,
@@ -182,17 +218,39 @@ pub fn run() {
             commands::export_to_markdown,
             commands::export_to_longform,
             commands::export_to_docx,
+            commands::get_compile_preview,
+            commands::export_to_rtf,
+            commands::export_to_text,
+            commands::export_to_latex,
+            commands::export_reference_sheet,
             commands::export_to_epub,
             commands::get_project_word_count,
+            commands::start_writing_session,
+            commands::end_writing_session,
+            commands::get_writing_stats,
+            commands::get_word_target_progress,
+            commands::get_chapter_target_progress,
+            commands::get_chapter_status_summary,
+            commands::get_word_count_breakdown,
+            commands::validate_for_submission,
             commands::generate_treatment,
+            commands::export_outline_pdf,
+            commands::export_outline,
             commands::preview_scrivener_matches,
             commands::export_to_scrivener,
             // Snapshot commands
             commands::create_snapshot,
             commands::list_snapshots,
+            commands::get_snapshot_history,
             commands::delete_snapshot,
+            commands::prune_snapshots,
             commands::restore_snapshot,
             commands::preview_snapshot,
+            commands::diff_snapshot,
+            commands::backup_database,
+            commands::get_database_path,
+            commands::export_project_archive,
+            commands::import_project_archive,
             // App settings commands
             commands::get_app_settings,
             commands::update_app_settings,
@@ -219,6 +277,10 @@ pub fn run() {
             commands::bulk_tag,
             commands::bulk_untag,
             commands::get_all_entity_tags,
+            commands::add_scene_tag,
+            commands::remove_scene_tag,
+            commands::get_scene_tags,
+            commands::get_scenes_by_tag,
             commands::filter_entities,
             commands::save_filter,
             commands::get_saved_filters,
@@ -226,7 +288,12 @@ pub fn run() {
             // Auto-detect commands
             commands::detect_scene_references,
             commands::detect_all_references,
+            commands::get_unmentioned_linked_characters,
+            commands::auto_link_references,
             commands::dismiss_suggestion,
+            commands::search_project,
+            commands::find_and_replace,
+            commands::preview_find_and_replace,
             // Template commands
             commands::get_bundled_templates,
             commands::get_user_templates,
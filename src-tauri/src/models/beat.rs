@@ -29,3 +29,23 @@ impl Beat {
         self
     }
 }
+
+/// An earlier version of a beat's prose, kept so a single beat can be rolled back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeatRevision {
+    pub id: Uuid,
+    pub beat_id: Uuid,
+    pub prose: String,
+    pub created_at: String,
+}
+
+impl BeatRevision {
+    pub fn new(beat_id: Uuid, prose: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            beat_id,
+            prose,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
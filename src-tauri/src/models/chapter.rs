@@ -22,6 +22,12 @@ pub struct Chapter {
     pub synopsis: Option<String>,
     #[serde(default)]
     pub planning_status: PlanningStatus,
+    /// Color label as a `#RRGGBB` hex string
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Word-count goal for this chapter
+    #[serde(default)]
+    pub word_target: Option<i32>,
 }
 
 impl Chapter {
@@ -37,6 +43,8 @@ impl Chapter {
             is_part: false,
             synopsis: None,
             planning_status: PlanningStatus::Fixed,
+            color: None,
+            word_target: None,
         }
     }
 
@@ -51,6 +59,21 @@ impl Chapter {
     }
 }
 
+/// Validate a chapter or scene color label, which must be a `#RRGGBB` hex string.
+/// Returns the color normalized to uppercase.
+pub fn validate_color_label(color: &str) -> Result<String, String> {
+    let hex = color.trim();
+    let valid =
+        hex.len() == 7 && hex.starts_with('#') && hex[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err(format!(
+            "Invalid color '{}': expected a hex color like #FFAA00",
+            color
+        ));
+    }
+    Ok(hex.to_ascii_uppercase())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +95,16 @@ mod tests {
         let json = serde_json::to_string(&chapter).unwrap();
         assert!(json.contains("Test Chapter"));
     }
+
+    #[test]
+    fn test_validate_color_label() {
+        assert_eq!(validate_color_label("#FFAA00").unwrap(), "#FFAA00");
+        assert_eq!(validate_color_label(" #ffaa0b ").unwrap(), "#FFAA0B");
+
+        for invalid in [
+            "", "FFAA00", "#FFF", "#FFAA000", "#GGAA00", "red", "#FFAA0é",
+        ] {
+            assert!(validate_color_label(invalid).is_err(), "{}", invalid);
+        }
+    }
 }
@@ -34,3 +34,12 @@ impl Character {
         self
     }
 }
+
+/// A scene a character is referenced in, for the character timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CharacterAppearance {
+    pub scene_id: Uuid,
+    pub scene_title: String,
+    pub chapter_id: Uuid,
+    pub chapter_title: String,
+}
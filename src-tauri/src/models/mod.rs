@@ -8,11 +8,13 @@ pub mod project;
 pub mod reference_item;
 pub mod scene;
 pub mod scene_reference_state;
+pub mod search;
 pub mod settings;
 pub mod snapshot;
 pub mod suggestion;
 pub mod tag;
 pub mod template;
+pub mod writing_session;
 
 pub use beat::*;
 pub use chapter::*;
@@ -24,8 +26,10 @@ pub use project::*;
 pub use reference_item::*;
 pub use scene::*;
 pub use scene_reference_state::*;
+pub use search::*;
 pub use settings::*;
 pub use snapshot::*;
 pub use suggestion::*;
 pub use tag::*;
 pub use template::*;
+pub use writing_session::*;
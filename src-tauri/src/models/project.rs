@@ -8,6 +8,9 @@ pub enum SourceType {
     Markdown,
     YWriter,
     Longform,
+    Fountain,
+    Odt,
+    Docx,
     Blank,
 }
 
@@ -19,6 +22,9 @@ impl SourceType {
             SourceType::Markdown => "markdown",
             SourceType::YWriter => "ywriter",
             SourceType::Longform => "longform",
+            SourceType::Fountain => "fountain",
+            SourceType::Odt => "odt",
+            SourceType::Docx => "docx",
             SourceType::Blank => "blank",
         }
     }
@@ -30,6 +36,9 @@ impl SourceType {
             "markdown" => Some(SourceType::Markdown),
             "ywriter" => Some(SourceType::YWriter),
             "longform" => Some(SourceType::Longform),
+            "fountain" => Some(SourceType::Fountain),
+            "odt" => Some(SourceType::Odt),
+            "docx" => Some(SourceType::Docx),
             "blank" => Some(SourceType::Blank),
             _ => None,
         }
@@ -61,6 +70,21 @@ pub struct Project {
     pub project_type: String,
     /// Target page count (screenplay only, ~250 words/page)
     pub target_page_count: Option<i32>,
+    /// Custom scene workflow stages (e.g. "Zero Draft") offered alongside the
+    /// built-in statuses
+    #[serde(default)]
+    pub scene_statuses: Vec<String>,
+    /// Publisher or imprint name for title pages and EPUB metadata
+    #[serde(default)]
+    pub publisher: Option<String>,
+    #[serde(default)]
+    pub isbn: Option<String>,
+    /// Series this book belongs to
+    #[serde(default)]
+    pub series_name: Option<String>,
+    /// Position of this book within `series_name` (1-based)
+    #[serde(default)]
+    pub series_index: Option<i32>,
 }
 
 impl Project {
@@ -88,6 +112,11 @@ impl Project {
             reference_types: Self::default_reference_types(),
             project_type: Self::default_project_type(),
             target_page_count: None,
+            scene_statuses: Vec::new(),
+            publisher: None,
+            isbn: None,
+            series_name: None,
+            series_index: None,
         }
     }
 }
@@ -102,6 +131,7 @@ mod tests {
         assert_eq!(SourceType::Plottr.as_str(), "plottr");
         assert_eq!(SourceType::Markdown.as_str(), "markdown");
         assert_eq!(SourceType::Longform.as_str(), "longform");
+        assert_eq!(SourceType::Fountain.as_str(), "fountain");
     }
 
     #[test]
@@ -110,6 +140,7 @@ mod tests {
         assert_eq!(SourceType::parse("PLOTTR"), Some(SourceType::Plottr));
         assert_eq!(SourceType::parse("Markdown"), Some(SourceType::Markdown));
         assert_eq!(SourceType::parse("LONGFORM"), Some(SourceType::Longform));
+        assert_eq!(SourceType::parse("Fountain"), Some(SourceType::Fountain));
         assert_eq!(SourceType::parse("unknown"), None);
     }
 
@@ -136,3 +167,15 @@ mod tests {
         assert!(json.contains("Markdown"));
     }
 }
+
+/// A recently opened project with the summary shown on the start screen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentProjectInfo {
+    pub project: Project,
+    /// Scenes outside the archive
+    pub scene_count: usize,
+    /// Word count read from the project's cached count
+    pub word_count: usize,
+    /// How long ago the project was modified, e.g. "3 days ago"
+    pub modified_relative: String,
+}
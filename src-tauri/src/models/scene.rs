@@ -82,7 +82,9 @@ impl SceneType {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum SceneStatus {
     #[default]
@@ -107,6 +109,31 @@ impl SceneStatus {
             _ => SceneStatus::Draft,
         }
     }
+
+    /// Parse a stored status column, which holds either a built-in status or a
+    /// label from the project's `scene_statuses`. Custom labels are returned
+    /// alongside a `Draft` fallback.
+    pub fn parse_stored(raw: &str) -> (Self, Option<String>) {
+        let label = raw.trim();
+        match label.to_lowercase().as_str() {
+            "" | "draft" => (SceneStatus::Draft, None),
+            "revised" => (SceneStatus::Revised, None),
+            "final" => (SceneStatus::Final, None),
+            _ => (SceneStatus::Draft, Some(label.to_string())),
+        }
+    }
+}
+
+/// Clean up a project's custom status labels: trim, drop blanks, drop names of
+/// built-in statuses, and drop repeats, keeping the first occurrence's order
+pub fn normalize_custom_statuses(labels: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    labels
+        .into_iter()
+        .map(|label| label.trim().to_string())
+        .filter(|label| SceneStatus::parse_stored(label).1.is_some())
+        .filter(|label| seen.insert(label.to_lowercase()))
+        .collect()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,6 +158,30 @@ pub struct Scene {
     pub planning_status: PlanningStatus,
     #[serde(default)]
     pub editor_mode: EditorMode,
+    /// Position in compiled output when it differs from binder order; `None`
+    /// falls back to `position`
+    #[serde(default)]
+    pub compile_position: Option<i32>,
+    /// Whether exports and manuscript word counts include this scene; off for
+    /// notes and research kept in the binder
+    #[serde(default = "Scene::default_include_in_compile")]
+    pub include_in_compile: bool,
+    /// Color label as a `#RRGGBB` hex string
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Character whose point of view the scene is told from
+    #[serde(default)]
+    pub pov_character_id: Option<Uuid>,
+    /// Private author notes; unlike `synopsis`, never exported
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Word-count goal for this scene
+    #[serde(default)]
+    pub word_target: Option<i32>,
+    /// Workflow stage from the project's custom `scene_statuses`; while set,
+    /// `scene_status` reads as `Draft`
+    #[serde(default)]
+    pub custom_status: Option<String>,
 }
 
 impl Scene {
@@ -149,9 +200,20 @@ impl Scene {
             scene_status: SceneStatus::Draft,
             planning_status: PlanningStatus::Fixed,
             editor_mode: EditorMode::Beat,
+            compile_position: None,
+            include_in_compile: true,
+            color: None,
+            pov_character_id: None,
+            notes: None,
+            word_target: None,
+            custom_status: None,
         }
     }
 
+    fn default_include_in_compile() -> bool {
+        true
+    }
+
     pub fn with_source_id(mut self, source_id: Option<String>) -> Self {
         self.source_id = source_id;
         self
@@ -9,3 +9,12 @@ pub struct SceneReferenceState {
     pub position: i32,
     pub expanded: bool,
 }
+
+/// A scene reference row whose character, location or reference item no longer exists
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrphanedRef {
+    pub scene_id: Uuid,
+    /// "characters", "locations" or "reference_items"
+    pub reference_type: String,
+    pub reference_id: Uuid,
+}
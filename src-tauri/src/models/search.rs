@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Which part of a scene a search hit was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchField {
+    Title,
+    Synopsis,
+    Prose,
+}
+
+/// A single full-text search match within a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub chapter_id: Uuid,
+    pub scene_id: Uuid,
+    /// Set when the match is in a beat's prose rather than on the scene itself
+    pub beat_id: Option<Uuid>,
+    pub field: SearchField,
+    /// Plain-text excerpt around the match, with HTML stripped
+    pub snippet: String,
+    /// Character offset of the match within the field's plain text
+    pub match_offset: usize,
+}
+
+/// A scene or beat that find-and-replace would change, for review before
+/// anything is written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplacePreview {
+    pub scene_id: Uuid,
+    /// Set when the matches are in a beat's prose rather than the scene's
+    pub beat_id: Option<Uuid>,
+    /// Plain-text excerpt around the first match, with HTML stripped
+    pub context_snippet: String,
+    /// Character offset of the first match within `context_snippet`
+    pub highlight_start: usize,
+    /// Length of the highlighted match in characters
+    pub highlight_length: usize,
+    /// How many replacements this field would receive
+    pub match_count: usize,
+}
@@ -27,6 +27,15 @@ pub struct AppSettings {
     /// Email address
     #[serde(default)]
     pub contact_email: Option<String>,
+
+    /// Author website, listed with the contact details on title pages
+    #[serde(default)]
+    pub website: Option<String>,
+
+    /// Take an automatic snapshot once a project grows by more than this many
+    /// words since its last snapshot (unset or 0 disables)
+    #[serde(default)]
+    pub auto_snapshot_word_threshold: Option<u32>,
 }
 
 impl AppSettings {
@@ -34,6 +34,45 @@ impl SnapshotTrigger {
     }
 }
 
+/// How a snapshot file is compressed
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotCompression {
+    None,
+    #[default]
+    Gzip,
+    /// Smaller files for large manuscripts
+    Zstd,
+}
+
+impl SnapshotCompression {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SnapshotCompression::None => "none",
+            SnapshotCompression::Gzip => "gzip",
+            SnapshotCompression::Zstd => "zstd",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "none" => Some(SnapshotCompression::None),
+            "gzip" => Some(SnapshotCompression::Gzip),
+            "zstd" => Some(SnapshotCompression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Snapshot file extension, e.g. `json.gz`
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            SnapshotCompression::None => "json",
+            SnapshotCompression::Gzip => "json.gz",
+            SnapshotCompression::Zstd => "json.zst",
+        }
+    }
+}
+
 /// Metadata about a snapshot, stored in the database
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotMetadata {
@@ -51,6 +90,12 @@ pub struct SnapshotMetadata {
     pub beat_count: i32,
     pub word_count: Option<i32>,
     pub schema_version: i32,
+    /// Snapshots from before compression was selectable are gzip
+    #[serde(default)]
+    pub compression: SnapshotCompression,
+    /// SHA-256 of the uncompressed payload; older snapshots have none
+    #[serde(default)]
+    pub checksum: Option<String>,
 }
 
 impl SnapshotMetadata {
@@ -83,8 +128,20 @@ impl SnapshotMetadata {
             beat_count,
             word_count,
             schema_version: 1,
+            compression: SnapshotCompression::default(),
+            checksum: None,
         }
     }
+
+    pub fn with_compression(mut self, compression: SnapshotCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub fn with_checksum(mut self, checksum: String) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
 }
 
 /// Reference linking a scene to a character
@@ -108,6 +165,25 @@ pub struct SceneReferenceItemRef {
     pub reference_item_id: Uuid,
 }
 
+/// A free-text tag on a scene
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneTagRef {
+    pub scene_id: Uuid,
+    pub tag: String,
+}
+
+/// Trash and cascade-archive state of a chapter or scene, which the models
+/// themselves do not carry. Only items with a flag set are recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinderItemFlags {
+    pub id: Uuid,
+    pub trashed: bool,
+    pub trashed_at: Option<String>,
+    /// Scene was archived along with its chapter
+    #[serde(default)]
+    pub archived_by_parent: bool,
+}
+
 /// The full snapshot data stored in the compressed file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotData {
@@ -129,6 +205,10 @@ pub struct SnapshotData {
     pub scene_reference_states: Vec<SceneReferenceState>,
     #[serde(default)]
     pub discovery_notes: Vec<DiscoveryNote>,
+    #[serde(default)]
+    pub scene_tags: Vec<SceneTagRef>,
+    #[serde(default)]
+    pub binder_flags: Vec<BinderItemFlags>,
 }
 
 impl SnapshotData {
@@ -146,6 +226,8 @@ impl SnapshotData {
         scene_reference_item_refs: Vec<SceneReferenceItemRef>,
         scene_reference_states: Vec<SceneReferenceState>,
         discovery_notes: Vec<DiscoveryNote>,
+        scene_tags: Vec<SceneTagRef>,
+        binder_flags: Vec<BinderItemFlags>,
     ) -> Self {
         Self {
             version: 1,
@@ -162,6 +244,8 @@ impl SnapshotData {
             scene_reference_item_refs,
             scene_reference_states,
             discovery_notes,
+            scene_tags,
+            binder_flags,
         }
     }
 
@@ -222,6 +306,21 @@ mod tests {
         assert_eq!(SnapshotTrigger::parse("unknown"), None);
     }
 
+    #[test]
+    fn test_snapshot_compression_round_trips() {
+        for compression in [
+            SnapshotCompression::None,
+            SnapshotCompression::Gzip,
+            SnapshotCompression::Zstd,
+        ] {
+            assert_eq!(
+                SnapshotCompression::parse(compression.as_str()),
+                Some(compression)
+            );
+        }
+        assert_eq!(SnapshotCompression::parse("lz4"), None);
+    }
+
     #[test]
     fn test_snapshot_metadata_new() {
         let project_id = Uuid::new_v4();
@@ -9,3 +9,11 @@ pub struct ReferenceSuggestion {
     pub positions: Vec<usize>,
     pub confidence: f32,
 }
+
+/// Number of scene references created by an auto-link pass, per entity type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoLinkCounts {
+    pub characters: usize,
+    pub locations: usize,
+    pub reference_items: usize,
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A stretch of writing bounded by project word counts taken at start and end
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WritingSession {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Local calendar date the session started on (YYYY-MM-DD)
+    pub date: String,
+    pub start_word_count: i64,
+    /// None while the session is still in progress
+    pub end_word_count: Option<i64>,
+    pub created_at: String,
+}
+
+impl WritingSession {
+    pub fn new(project_id: Uuid, date: String, start_word_count: i64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            date,
+            start_word_count,
+            end_word_count: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Net words added during the session (negative when text was cut)
+    pub fn words_written(&self) -> i64 {
+        self.end_word_count
+            .map_or(0, |end| end - self.start_word_count)
+    }
+}
+
+/// Words written on a single day, summed over that day's sessions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionStat {
+    pub date: String,
+    pub words_written: i64,
+    pub session_count: usize,
+}
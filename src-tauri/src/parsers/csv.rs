@@ -0,0 +1,209 @@
+use std::path::Path;
+
+use thiserror::Error;
+
+use super::encoding::read_text_file;
+
+#[derive(Debug, Error)]
+pub enum CsvError {
+    #[error("Failed to read file: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("CSV is missing a header row")]
+    MissingHeader,
+    #[error("CSV is missing required column: {0}")]
+    MissingColumn(String),
+    #[error("Unterminated quoted field on line {0}")]
+    UnterminatedQuote(usize),
+}
+
+// ============================================================================
+// Parsed Output
+// ============================================================================
+
+/// A single beat row from a beat-sheet CSV
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeatSheetRow {
+    pub content: String,
+    /// Prose as HTML, one `<p>` per non-blank line of the cell
+    pub prose: Option<String>,
+}
+
+// ============================================================================
+// Parser Implementation
+// ============================================================================
+
+/// Parse a beat-sheet CSV file.
+///
+/// The first row is a header and must contain a `content` column. A `prose`
+/// column is optional and its text is converted to HTML paragraphs. Rows whose
+/// content is blank are skipped.
+pub fn parse_beat_sheet_csv<P: AsRef<Path>>(path: P) -> Result<Vec<BeatSheetRow>, CsvError> {
+    let raw = read_text_file(path)?;
+    parse_beat_sheet_csv_str(&raw)
+}
+
+/// Parse beat-sheet CSV content that has already been read into memory
+pub fn parse_beat_sheet_csv_str(raw: &str) -> Result<Vec<BeatSheetRow>, CsvError> {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let mut records = parse_csv_records(raw)?.into_iter();
+
+    let header = records.next().ok_or(CsvError::MissingHeader)?;
+    let column_index = |name: &str| {
+        header
+            .iter()
+            .position(|field| field.trim().eq_ignore_ascii_case(name))
+    };
+    let content_idx =
+        column_index("content").ok_or_else(|| CsvError::MissingColumn("content".to_string()))?;
+    let prose_idx = column_index("prose");
+
+    let mut rows = Vec::new();
+    for record in records {
+        let content = record
+            .get(content_idx)
+            .map(|value| value.trim())
+            .unwrap_or_default();
+        if content.is_empty() {
+            continue;
+        }
+
+        let prose = prose_idx
+            .and_then(|idx| record.get(idx))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(prose_to_html);
+
+        rows.push(BeatSheetRow {
+            content: content.to_string(),
+            prose,
+        });
+    }
+
+    Ok(rows)
+}
+
+/// Convert a plain-text prose cell into HTML, one paragraph per non-blank line
+fn prose_to_html(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| format!("<p>{}</p>", escape_html(line)))
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Split CSV text into records following RFC 4180 quoting rules.
+///
+/// Quoted fields may contain commas, doubled quotes, and line breaks.
+fn parse_csv_records(raw: &str) -> Result<Vec<Vec<String>>, CsvError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut quote_start_line = 1;
+    let mut chars = raw.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push('\n');
+                }
+                '\r' => {}
+                _ => field.push(ch),
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => {
+                in_quotes = true;
+                quote_start_line = line;
+            }
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                line += 1;
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(ch),
+        }
+    }
+
+    if in_quotes {
+        return Err(CsvError::UnterminatedQuote(quote_start_line));
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_three_row_beat_sheet() {
+        let csv = "content,prose\n\
+                   Hero wakes up,\n\
+                   \n\
+                   \"Mentor arrives, uninvited\",\"She says \"\"hello\"\".\"\n\
+                   Call to adventure,\n";
+
+        let rows = parse_beat_sheet_csv_str(csv).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].content, "Hero wakes up");
+        assert_eq!(rows[0].prose, None);
+        assert_eq!(rows[1].content, "Mentor arrives, uninvited");
+        assert_eq!(rows[1].prose.as_deref(), Some("<p>She says \"hello\".</p>"));
+        assert_eq!(rows[2].content, "Call to adventure");
+    }
+
+    #[test]
+    fn test_parse_beat_sheet_requires_content_column() {
+        let result = parse_beat_sheet_csv_str("title,notes\nOne,Two\n");
+        assert!(matches!(result, Err(CsvError::MissingColumn(_))));
+    }
+
+    #[test]
+    fn test_parse_beat_sheet_multiline_quoted_field() {
+        let csv = "Prose,Content\r\n\"Line one\r\nLine two\",Beat\r\n";
+        let rows = parse_beat_sheet_csv_str(csv).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].content, "Beat");
+        assert_eq!(
+            rows[0].prose.as_deref(),
+            Some("<p>Line one</p><p>Line two</p>")
+        );
+    }
+
+    #[test]
+    fn test_parse_beat_sheet_prose_is_escaped_html() {
+        let csv = "content,prose\nBeat,\"Fish & chips <hot>\n\n  Then home.  \"\n";
+        let rows = parse_beat_sheet_csv_str(csv).unwrap();
+
+        assert_eq!(
+            rows[0].prose.as_deref(),
+            Some("<p>Fish &amp; chips &lt;hot&gt;</p><p>Then home.</p>")
+        );
+    }
+}
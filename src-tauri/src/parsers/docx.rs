@@ -0,0 +1,548 @@
+//! Word Document (.docx) Parser
+//!
+//! Imports a manuscript from Word or any editor that saves DOCX. A DOCX file is
+//! a zip; the body lives in `word/document.xml` and style names in
+//! `word/styles.xml`. docx-rs only writes documents, so the XML is read with
+//! quick-xml as the yWriter parser does.
+//!
+//! Key mappings:
+//! - Title → project name
+//! - Heading 1 → Kindling Chapter
+//! - Heading 2 → Kindling Scene (as Kindling's own DOCX export writes scene titles)
+//! - Scene-break paragraphs (`#`, `* * *`, `⁂`) → a new scene
+//! - Deeper headings → `<h3>`–`<h6>` inside the scene's prose
+//! - Body paragraphs → prose of a single "Scene Content" beat per scene, with
+//!   bold and italic runs kept as `<strong>` and `<em>`
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use thiserror::Error;
+
+use crate::models::{Beat, Chapter, Project, Scene, SourceType};
+
+use super::manuscript::{is_scene_break_marker, ManuscriptBuilder};
+
+#[derive(Debug, Error)]
+pub enum DocxError {
+    #[error("Failed to read file: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Failed to open DOCX archive: {0}")]
+    ZipError(#[from] zip::result::ZipError),
+    #[error("Failed to parse XML: {0}")]
+    XmlError(#[from] quick_xml::Error),
+    #[error("Invalid DOCX file structure: {0}")]
+    InvalidStructure(String),
+}
+
+// ============================================================================
+// Parsed Output
+// ============================================================================
+
+pub struct ParsedDocx {
+    pub project: Project,
+    pub chapters: Vec<Chapter>,
+    pub scenes: Vec<Scene>,
+    pub beats: Vec<Beat>,
+}
+
+// ============================================================================
+// Styles
+// ============================================================================
+
+/// The parts of a `w:style` the importer cares about
+#[derive(Debug, Default, Clone)]
+struct DocxStyle {
+    name: String,
+    based_on: Option<String>,
+    /// Zero-based outline level, as in `w:outlineLvl`
+    outline_level: Option<u8>,
+    bold: bool,
+    italic: bool,
+}
+
+/// What a paragraph style makes of its paragraph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParagraphRole {
+    Title,
+    Heading(u8),
+    Body,
+}
+
+/// Heading level of a built-in heading style, by name ("heading 2") or id ("Heading2")
+fn heading_level_from_name(name: &str) -> Option<u8> {
+    let lower = name.to_lowercase();
+    lower
+        .strip_prefix("heading")
+        .and_then(|level| level.trim().parse::<u8>().ok())
+        .filter(|level| (1..=6).contains(level))
+}
+
+fn paragraph_role(styles: &HashMap<String, DocxStyle>, style_id: &str) -> ParagraphRole {
+    let mut current = Some(style_id.to_string());
+    // basedOn chains are short; the limit guards against cycles
+    for _ in 0..16 {
+        let Some(id) = current.take() else {
+            break;
+        };
+        let style = styles.get(&id);
+        let name = style.map(|s| s.name.as_str()).unwrap_or(id.as_str());
+        if name.eq_ignore_ascii_case("title") {
+            return ParagraphRole::Title;
+        }
+        if let Some(level) = heading_level_from_name(name).or_else(|| heading_level_from_name(&id))
+        {
+            return ParagraphRole::Heading(level);
+        }
+        if let Some(level) = style.and_then(|s| s.outline_level) {
+            return ParagraphRole::Heading(level + 1);
+        }
+        current = style.and_then(|s| s.based_on.clone());
+    }
+    ParagraphRole::Body
+}
+
+/// Bold and italic from a character style, following `w:basedOn`
+fn run_style_format(styles: &HashMap<String, DocxStyle>, style_id: &str) -> (bool, bool) {
+    let (mut bold, mut italic) = (false, false);
+    let mut current = Some(style_id.to_string());
+    for _ in 0..16 {
+        let Some(style) = current.take().and_then(|id| styles.get(&id)) else {
+            break;
+        };
+        bold |= style.bold;
+        italic |= style.italic;
+        current = style.based_on.clone();
+    }
+    (bold, italic)
+}
+
+fn attr_value(e: &BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+/// Whether a toggle property like `<w:b/>` or `<w:i w:val="0"/>` is on
+fn toggle_value(e: &BytesStart) -> bool {
+    !matches!(
+        attr_value(e, b"w:val").as_deref(),
+        Some("0") | Some("false") | Some("off")
+    )
+}
+
+/// Read `word/styles.xml` into a map keyed by style id
+fn parse_styles(content: &str) -> Result<HashMap<String, DocxStyle>, DocxError> {
+    let mut reader = Reader::from_str(content);
+    let mut styles = HashMap::new();
+    let mut current: Option<(String, DocxStyle)> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) if e.name().as_ref() == b"w:style" => {
+                current = attr_value(&e, b"w:styleId").map(|id| (id, DocxStyle::default()));
+            }
+            Event::Start(e) | Event::Empty(e) => {
+                let Some((_, style)) = current.as_mut() else {
+                    continue;
+                };
+                match e.name().as_ref() {
+                    b"w:name" => style.name = attr_value(&e, b"w:val").unwrap_or_default(),
+                    b"w:basedOn" => style.based_on = attr_value(&e, b"w:val"),
+                    b"w:outlineLvl" => {
+                        style.outline_level = attr_value(&e, b"w:val")
+                            .and_then(|v| v.parse::<u8>().ok())
+                            .filter(|level| *level < 6);
+                    }
+                    b"w:b" => style.bold = toggle_value(&e),
+                    b"w:i" => style.italic = toggle_value(&e),
+                    _ => {}
+                }
+            }
+            Event::End(e) if e.name().as_ref() == b"w:style" => {
+                if let Some((id, style)) = current.take() {
+                    styles.insert(id, style);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(styles)
+}
+
+// ============================================================================
+// Paragraphs
+// ============================================================================
+
+/// Text and markup of the paragraph being read
+#[derive(Default)]
+struct OpenParagraph {
+    style_id: Option<String>,
+    /// Zero-based outline level set directly on the paragraph
+    outline_level: Option<u8>,
+    html: String,
+    text: String,
+    /// Bold/italic of the tags currently open in `html`
+    open_format: (bool, bool),
+}
+
+impl OpenParagraph {
+    /// Append text with the given bold/italic, reusing open tags when they match
+    fn push_text(&mut self, text: &str, format: (bool, bool)) {
+        if format != self.open_format {
+            self.close_format();
+            if format.0 {
+                self.html.push_str("<strong>");
+            }
+            if format.1 {
+                self.html.push_str("<em>");
+            }
+            self.open_format = format;
+        }
+        self.html.push_str(&escape(text));
+        self.text.push_str(text);
+    }
+
+    fn push_line_break(&mut self) {
+        self.html.push_str("<br>");
+        self.text.push(' ');
+    }
+
+    fn close_format(&mut self) {
+        if self.open_format.1 {
+            self.html.push_str("</em>");
+        }
+        if self.open_format.0 {
+            self.html.push_str("</strong>");
+        }
+        self.open_format = (false, false);
+    }
+}
+
+// ============================================================================
+// XML Parser
+// ============================================================================
+
+/// Parse a Word document
+pub fn parse_docx_file<P: AsRef<Path>>(path: P) -> Result<ParsedDocx, DocxError> {
+    let path = path.as_ref();
+    let file = fs::File::open(path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+
+    let mut document = String::new();
+    archive
+        .by_name("word/document.xml")
+        .map_err(|_| DocxError::InvalidStructure("missing word/document.xml".to_string()))?
+        .read_to_string(&mut document)?;
+
+    // Styles are optional; without them only style ids like "Heading1" are recognised
+    let mut styles_xml = String::new();
+    if let Ok(mut entry) = archive.by_name("word/styles.xml") {
+        entry.read_to_string(&mut styles_xml)?;
+    }
+    let styles = if styles_xml.is_empty() {
+        HashMap::new()
+    } else {
+        parse_styles(&styles_xml)?
+    };
+
+    parse_docx_content(&document, &styles, path)
+}
+
+/// Parse the `word/document.xml` of a DOCX file
+fn parse_docx_content(
+    content: &str,
+    styles: &HashMap<String, DocxStyle>,
+    path: &Path,
+) -> Result<ParsedDocx, DocxError> {
+    let mut project = Project::new(
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Untitled")
+            .to_string(),
+        SourceType::Docx,
+        Some(path.to_string_lossy().to_string()),
+    );
+    let mut title: Option<String> = None;
+
+    let mut reader = Reader::from_str(content);
+    let mut builder = ManuscriptBuilder::new(project.id, "docx");
+    let mut paragraph: Option<OpenParagraph> = None;
+    let mut run_format = (false, false);
+    let mut in_run_props = false;
+    let mut in_text = false;
+    // Depth inside text boxes, whose paragraphs aren't part of the flow
+    let mut skip_depth = 0usize;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => match e.name().as_ref() {
+                b"w:txbxContent" => skip_depth += 1,
+                b"w:p" if skip_depth == 0 => paragraph = Some(OpenParagraph::default()),
+                b"w:r" => run_format = (false, false),
+                b"w:rPr" => in_run_props = true,
+                b"w:t" => in_text = true,
+                _ => {}
+            },
+            Event::Empty(e) => {
+                let Some(p) = paragraph.as_mut().filter(|_| skip_depth == 0) else {
+                    continue;
+                };
+                match e.name().as_ref() {
+                    b"w:pStyle" => p.style_id = attr_value(&e, b"w:val"),
+                    b"w:outlineLvl" => {
+                        p.outline_level = attr_value(&e, b"w:val")
+                            .and_then(|v| v.parse::<u8>().ok())
+                            .filter(|level| *level < 6);
+                    }
+                    b"w:rStyle" if in_run_props => {
+                        if let Some(id) = attr_value(&e, b"w:val") {
+                            let (bold, italic) = run_style_format(styles, &id);
+                            run_format = (run_format.0 || bold, run_format.1 || italic);
+                        }
+                    }
+                    b"w:b" if in_run_props => run_format.0 = toggle_value(&e),
+                    b"w:i" if in_run_props => run_format.1 = toggle_value(&e),
+                    b"w:tab" if !in_run_props => p.push_text(" ", run_format),
+                    b"w:noBreakHyphen" => p.push_text("-", run_format),
+                    b"w:cr" => p.push_line_break(),
+                    // Page and column breaks don't belong in prose
+                    b"w:br" if attr_value(&e, b"w:type").is_none_or(|t| t == "textWrapping") => {
+                        p.push_line_break()
+                    }
+                    _ => {}
+                }
+            }
+            Event::End(e) => match e.name().as_ref() {
+                b"w:txbxContent" => skip_depth = skip_depth.saturating_sub(1),
+                b"w:rPr" => in_run_props = false,
+                b"w:t" => in_text = false,
+                b"w:p" if skip_depth == 0 => {
+                    if let Some(p) = paragraph.take() {
+                        close_paragraph(p, styles, &mut builder, &mut title);
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(e) if in_text && skip_depth == 0 => {
+                if let Some(p) = paragraph.as_mut() {
+                    p.push_text(&String::from_utf8_lossy(&e), run_format);
+                }
+            }
+            Event::GeneralRef(e) if in_text && skip_depth == 0 => {
+                if let Some(p) = paragraph.as_mut() {
+                    let resolved = match e.resolve_char_ref()? {
+                        Some(ch) => ch.to_string(),
+                        None => match &e[..] {
+                            b"amp" => "&".to_string(),
+                            b"lt" => "<".to_string(),
+                            b"gt" => ">".to_string(),
+                            b"quot" => "\"".to_string(),
+                            b"apos" => "'".to_string(),
+                            _ => String::new(),
+                        },
+                    };
+                    p.push_text(&resolved, run_format);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if let Some(title) = title {
+        project.name = title;
+    }
+    let (chapters, scenes, beats) = builder.finish();
+
+    Ok(ParsedDocx {
+        project,
+        chapters,
+        scenes,
+        beats,
+    })
+}
+
+fn close_paragraph(
+    mut p: OpenParagraph,
+    styles: &HashMap<String, DocxStyle>,
+    builder: &mut ManuscriptBuilder,
+    title: &mut Option<String>,
+) {
+    p.close_format();
+    let text = p.text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let role = match (p.outline_level, p.style_id.as_deref()) {
+        (Some(level), _) => ParagraphRole::Heading(level + 1),
+        (None, Some(style_id)) => paragraph_role(styles, style_id),
+        (None, None) => ParagraphRole::Body,
+    };
+
+    match role {
+        ParagraphRole::Title => {
+            if title.is_none() && !text.is_empty() {
+                *title = Some(text);
+            }
+        }
+        ParagraphRole::Heading(1) => builder.start_chapter(text),
+        ParagraphRole::Heading(2) => builder.start_scene(text),
+        ParagraphRole::Heading(level) => {
+            if !text.is_empty() {
+                builder.push_paragraph(format!("<h{0}>{1}</h{0}>", level, escape(&text)));
+            }
+        }
+        ParagraphRole::Body => {
+            if is_scene_break_marker(&text) {
+                builder.scene_break();
+            } else if !text.is_empty() {
+                builder.push_paragraph(format!("<p>{}</p>", p.html.trim()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use zip::write::FileOptions;
+
+    const STYLES_XML: &str = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+        <w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/></w:style>
+        <w:style w:type="paragraph" w:styleId="Kapitel"><w:name w:val="heading 1"/></w:style>
+        <w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/></w:style>
+        <w:style w:type="character" w:styleId="Emphasis"><w:name w:val="Emphasis"/><w:rPr><w:i/></w:rPr></w:style>
+    </w:styles>"#;
+
+    fn paragraph(style: Option<&str>, runs: &str) -> String {
+        let style = style
+            .map(|s| format!(r#"<w:pPr><w:pStyle w:val="{}"/></w:pPr>"#, s))
+            .unwrap_or_default();
+        format!("<w:p>{}{}</w:p>", style, runs)
+    }
+
+    fn run(text: &str) -> String {
+        format!(r#"<w:r><w:t xml:space="preserve">{}</w:t></w:r>"#, text)
+    }
+
+    /// Write a minimal DOCX holding `body` and the test styles
+    fn write_docx(dir: &Path, body: &str) -> PathBuf {
+        let path = dir.join("manuscript.docx");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&path).unwrap());
+        let document = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}</w:body></w:document>"#,
+            body
+        );
+        for (name, content) in [
+            ("word/document.xml", document.as_str()),
+            ("word/styles.xml", STYLES_XML),
+        ] {
+            zip.start_file(name, FileOptions::<()>::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_docx_manuscript() {
+        let body = [
+            paragraph(Some("Title"), &run("The Lighthouse")),
+            paragraph(Some("Kapitel"), &run("Chapter One")),
+            paragraph(
+                None,
+                &format!(
+                    "{}{}{}",
+                    run("The keeper "),
+                    r#"<w:r><w:rPr><w:i/></w:rPr><w:t>never</w:t></w:r>"#,
+                    run(" slept.")
+                ),
+            ),
+            paragraph(
+                None,
+                r#"<w:r><w:rPr><w:b/></w:rPr><w:t>Waves &amp; wind.</w:t></w:r>"#,
+            ),
+            paragraph(None, &run("* * *")),
+            paragraph(
+                None,
+                &format!(
+                    "{}{}",
+                    r#"<w:r><w:rPr><w:rStyle w:val="Emphasis"/></w:rPr><w:t>Later</w:t></w:r>"#,
+                    run(", the storm.")
+                ),
+            ),
+            paragraph(Some("Heading1"), &run("Chapter Two")),
+            paragraph(Some("Heading2"), &run("Morning")),
+            paragraph(
+                None,
+                r#"<w:r><w:t>Quiet</w:t><w:br/><w:t>again.</w:t></w:r>"#,
+            ),
+            paragraph(None, &run("#")),
+            paragraph(None, &run("Calm.")),
+        ]
+        .concat();
+        let dir = tempfile::tempdir().unwrap();
+        let parsed = parse_docx_file(write_docx(dir.path(), &body)).unwrap();
+
+        assert_eq!(parsed.project.name, "The Lighthouse");
+        assert_eq!(parsed.project.source_type, SourceType::Docx);
+
+        let chapter_titles: Vec<&str> = parsed.chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(chapter_titles, vec!["Chapter One", "Chapter Two"]);
+        let scene_titles: Vec<&str> = parsed.scenes.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            scene_titles,
+            vec!["Scene 1", "Scene 2", "Morning", "Scene 2"]
+        );
+        assert_eq!(parsed.scenes[2].chapter_id, parsed.chapters[1].id);
+
+        let prose: Vec<&str> = parsed
+            .beats
+            .iter()
+            .map(|b| b.prose.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            prose,
+            vec![
+                "<p>The keeper <em>never</em> slept.</p>\n<p><strong>Waves &amp; wind.</strong></p>",
+                "<p><em>Later</em>, the storm.</p>",
+                "<p>Quiet<br>again.</p>",
+                "<p>Calm.</p>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_docx_missing_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("empty.docx");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&path).unwrap());
+        zip.start_file("[Content_Types].xml", FileOptions::<()>::default())
+            .unwrap();
+        zip.finish().unwrap();
+
+        assert!(matches!(
+            parse_docx_file(&path),
+            Err(DocxError::InvalidStructure(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_scene_break_marker() {
+        for marker in ["#", "* * *", "***", "⁂", " ~ ~ ~ "] {
+            assert!(is_scene_break_marker(marker), "{}", marker);
+        }
+        for text in ["", "#hashtag", "Chapter # 1", "* * * * * * * * * *"] {
+            assert!(!is_scene_break_marker(text), "{}", text);
+        }
+    }
+}
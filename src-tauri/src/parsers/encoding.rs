@@ -0,0 +1,116 @@
+//! Text decoding shared by the file-based importers
+//!
+//! Files saved by Windows tools often arrive as UTF-16 with a byte-order mark or
+//! as Windows-1252 ("Latin-1") rather than UTF-8. Importers read through
+//! [`read_text_file`] so those files decode instead of failing.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252};
+
+/// Detect the encoding of a byte slice from its BOM. Without one, valid UTF-8
+/// is read as UTF-8 and anything else as Windows-1252.
+pub(crate) fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
+    // Check for UTF-16 BOM
+    if bytes.len() >= 2 {
+        if bytes[0] == 0xFF && bytes[1] == 0xFE {
+            return UTF_16LE;
+        }
+        if bytes[0] == 0xFE && bytes[1] == 0xFF {
+            return UTF_16BE;
+        }
+    }
+    // Check for UTF-8 BOM
+    if bytes.len() >= 3 && bytes[0] == 0xEF && bytes[1] == 0xBB && bytes[2] == 0xBF {
+        return UTF_8;
+    }
+    if std::str::from_utf8(bytes).is_ok() {
+        UTF_8
+    } else {
+        WINDOWS_1252
+    }
+}
+
+/// Decode bytes to a string in their detected encoding, dropping any BOM
+pub(crate) fn decode_content(bytes: &[u8]) -> Result<String, String> {
+    let encoding = detect_encoding(bytes);
+
+    let (decoded, _, had_errors) = encoding.decode(bytes);
+    if had_errors {
+        return Err(format!(
+            "Failed to decode file content as {}",
+            encoding.name()
+        ));
+    }
+
+    Ok(decoded.into_owned())
+}
+
+/// Read a text file in whatever encoding it was saved in; undecodable content
+/// is reported as [`io::ErrorKind::InvalidData`]
+pub(crate) fn read_text_file<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let bytes = fs::read(path)?;
+    decode_content(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le_with_bom(text: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in text.encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_detect_encoding_utf8() {
+        let bytes = b"Hello world";
+        assert_eq!(detect_encoding(bytes), UTF_8);
+    }
+
+    #[test]
+    fn test_detect_encoding_utf8_bom() {
+        let bytes = &[0xEF, 0xBB, 0xBF, b'H', b'i'];
+        assert_eq!(detect_encoding(bytes), UTF_8);
+    }
+
+    #[test]
+    fn test_detect_encoding_utf16le() {
+        let bytes = &[0xFF, 0xFE, 0x48, 0x00];
+        assert_eq!(detect_encoding(bytes), UTF_16LE);
+    }
+
+    #[test]
+    fn test_detect_encoding_utf16be() {
+        let bytes = &[0xFE, 0xFF, 0x00, 0x48];
+        assert_eq!(detect_encoding(bytes), UTF_16BE);
+    }
+
+    #[test]
+    fn test_decode_utf16le_strips_bom() {
+        let bytes = utf16le_with_bom("# Café\n");
+        assert_eq!(decode_content(&bytes).unwrap(), "# Café\n");
+    }
+
+    #[test]
+    fn test_decode_latin1_fallback() {
+        // "Café" with é as the single Windows-1252 byte 0xE9
+        let bytes = b"Caf\xE9";
+        assert_eq!(detect_encoding(bytes), WINDOWS_1252);
+        assert_eq!(decode_content(bytes).unwrap(), "Café");
+    }
+
+    #[test]
+    fn test_read_text_file_decodes_utf16le() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outline.md");
+        fs::write(&path, utf16le_with_bom("# Chapter\n\n## Scene\n")).unwrap();
+
+        assert_eq!(read_text_file(&path).unwrap(), "# Chapter\n\n## Scene\n");
+    }
+}
@@ -0,0 +1,675 @@
+//! Fountain (.fountain) Screenplay Parser
+//!
+//! Parses plain-text Fountain screenplays and converts them to Kindling's data model.
+//!
+//! Key mappings:
+//! - Title page `Title:` / `Author:` → Project name / author pen name
+//! - `# Section` → Kindling Chapter (acts)
+//! - `## Section` → Kindling Scene (sequences); when the script has no `##`
+//!   sections, scene headings (`INT.`, `EXT.`, `.FORCED`) become scenes instead
+//! - `= Synopsis` → Scene synopsis (or chapter synopsis before any scene)
+//! - Action and dialogue blocks → Kindling Beats with HTML prose
+//! - Dialogue character cues → Kindling Characters linked to their scenes
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::{Beat, Chapter, Character, Project, Scene, SourceType};
+
+use super::encoding::read_text_file;
+
+#[derive(Debug, Error)]
+pub enum FountainError {
+    #[error("Failed to read file: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+// ============================================================================
+// Parsed Output
+// ============================================================================
+
+/// Result of parsing a Fountain file
+#[derive(Debug)]
+pub struct ParsedFountain {
+    pub project: Project,
+    pub chapters: Vec<Chapter>,
+    pub scenes: Vec<Scene>,
+    pub beats: Vec<Beat>,
+    pub characters: Vec<Character>,
+    pub scene_character_refs: Vec<(Uuid, Uuid)>,
+}
+
+// ============================================================================
+// Parser Implementation
+// ============================================================================
+
+/// Parse a Fountain screenplay file
+pub fn parse_fountain_file<P: AsRef<Path>>(path: P) -> Result<ParsedFountain, FountainError> {
+    let path = path.as_ref();
+    let content = read_text_file(path)?;
+    Ok(parse_fountain_content(&content, path))
+}
+
+/// Parse Fountain content that has already been read into memory
+pub fn parse_fountain_content(content: &str, path: &Path) -> ParsedFountain {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let content = strip_boneyard_and_notes(&content.replace("\r\n", "\n"));
+    let lines: Vec<&str> = content.lines().collect();
+
+    let (title_page, body_start) = parse_title_page(&lines);
+
+    let project_name = title_page
+        .get("title")
+        .map(|title| plain_text(title))
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("Untitled")
+                .to_string()
+        });
+
+    let mut project = Project::new(
+        project_name,
+        SourceType::Fountain,
+        Some(path.to_string_lossy().to_string()),
+    );
+    project.project_type = "screenplay".to_string();
+    project.author_pen_name = title_page
+        .get("author")
+        .or_else(|| title_page.get("authors"))
+        .map(|author| plain_text(author))
+        .filter(|author| !author.is_empty());
+
+    let body = &lines[body_start..];
+    let sequences_are_scenes = body
+        .iter()
+        .any(|line| section_level(line).is_some_and(|(level, _)| level == 2));
+
+    let mut builder = FountainBuilder::new(project.id);
+
+    for block in body.split(|line| line.trim().is_empty()) {
+        let mut rest = block;
+
+        // Sections, synopses and page breaks are line-level elements that may
+        // sit directly above the block they describe.
+        while let Some(line) = rest.first() {
+            let trimmed = line.trim();
+            if let Some((level, title)) = section_level(trimmed) {
+                match level {
+                    1 => builder.start_chapter(title),
+                    2 => builder.start_scene(title),
+                    _ => {}
+                }
+            } else if let Some(synopsis) = trimmed
+                .strip_prefix('=')
+                .filter(|_| !trimmed.starts_with("==="))
+            {
+                builder.add_synopsis(synopsis.trim());
+            } else if !trimmed.starts_with("===") {
+                break;
+            }
+            rest = &rest[1..];
+        }
+
+        let Some(first) = rest.first() else {
+            continue;
+        };
+
+        if let Some(heading) = scene_heading(first.trim()) {
+            if sequences_are_scenes {
+                let html = format!("<p><strong>{}</strong></p>", escape_html(&heading));
+                builder.add_beat(heading, html);
+            } else {
+                builder.start_scene(heading);
+            }
+            rest = &rest[1..];
+            if rest.is_empty() {
+                continue;
+            }
+        }
+
+        if rest.len() > 1 {
+            if let Some(name) = character_cue(rest[0].trim()) {
+                builder.add_dialogue(&name, rest);
+                continue;
+            }
+        }
+
+        builder.add_action(rest);
+    }
+
+    builder.finish(project)
+}
+
+/// Accumulates chapters, scenes and beats while walking the script
+struct FountainBuilder {
+    project_id: Uuid,
+    chapters: Vec<Chapter>,
+    scenes: Vec<Scene>,
+    beats: Vec<Beat>,
+    characters: Vec<Character>,
+    character_ids: HashMap<String, Uuid>,
+    scene_character_refs: Vec<(Uuid, Uuid)>,
+    beat_position: i32,
+}
+
+impl FountainBuilder {
+    fn new(project_id: Uuid) -> Self {
+        Self {
+            project_id,
+            chapters: Vec::new(),
+            scenes: Vec::new(),
+            beats: Vec::new(),
+            characters: Vec::new(),
+            character_ids: HashMap::new(),
+            scene_character_refs: Vec::new(),
+            beat_position: 0,
+        }
+    }
+
+    fn start_chapter(&mut self, title: String) {
+        let position = self.chapters.len() as i32;
+        self.chapters.push(
+            Chapter::new(self.project_id, title, position)
+                .with_source_id(Some(format!("fountain:chapter:{position}"))),
+        );
+    }
+
+    fn start_scene(&mut self, title: String) {
+        if self.chapters.is_empty() {
+            self.start_chapter("Act 1".to_string());
+        }
+        let chapter = self.chapters.last().expect("chapter exists");
+        let position = self
+            .scenes
+            .iter()
+            .filter(|scene| scene.chapter_id == chapter.id)
+            .count() as i32;
+        let scene = Scene::new(chapter.id, title, None, position).with_source_id(Some(format!(
+            "fountain:scene:{}:{}",
+            chapter.position, position
+        )));
+        self.scenes.push(scene);
+        self.beat_position = 0;
+    }
+
+    /// Ensure there is a scene in the current chapter to attach content to
+    fn current_scene(&mut self) -> &Scene {
+        let chapter_id = self.chapters.last().map(|chapter| chapter.id);
+        let has_scene = chapter_id.is_some()
+            && self
+                .scenes
+                .last()
+                .is_some_and(|scene| Some(scene.chapter_id) == chapter_id);
+        if !has_scene {
+            self.start_scene("Scene 1".to_string());
+        }
+        self.scenes.last().expect("scene exists")
+    }
+
+    fn add_synopsis(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let chapter_id = self.chapters.last().map(|chapter| chapter.id);
+        let target = match self.scenes.last_mut() {
+            Some(scene) if Some(scene.chapter_id) == chapter_id => &mut scene.synopsis,
+            _ => match self.chapters.last_mut() {
+                Some(chapter) => &mut chapter.synopsis,
+                None => return,
+            },
+        };
+
+        match target {
+            Some(existing) => {
+                existing.push(' ');
+                existing.push_str(text);
+            }
+            None => *target = Some(text.to_string()),
+        }
+    }
+
+    fn add_beat(&mut self, content: String, prose: String) {
+        let (scene_id, chapter_id, scene_position) = {
+            let scene = self.current_scene();
+            (scene.id, scene.chapter_id, scene.position)
+        };
+        let chapter_position = self
+            .chapters
+            .iter()
+            .find(|chapter| chapter.id == chapter_id)
+            .map(|chapter| chapter.position)
+            .unwrap_or(0);
+
+        let mut beat =
+            Beat::new(scene_id, content, self.beat_position).with_source_id(Some(format!(
+                "fountain:beat:{chapter_position}:{scene_position}:{}",
+                self.beat_position
+            )));
+        beat.prose = Some(prose);
+        self.beats.push(beat);
+        self.beat_position += 1;
+    }
+
+    fn add_action(&mut self, lines: &[&str]) {
+        let text: Vec<String> = lines
+            .iter()
+            .map(|line| action_line(line))
+            .filter(|line| !line.is_empty())
+            .collect();
+        if text.is_empty() {
+            return;
+        }
+
+        let content = text
+            .iter()
+            .map(|line| plain_text(line))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let prose = text
+            .iter()
+            .map(|line| format!("<p>{}</p>", inline_html(line)))
+            .collect::<String>();
+        self.add_beat(content, prose);
+    }
+
+    fn add_dialogue(&mut self, name: &str, lines: &[&str]) {
+        let cue = lines[0]
+            .trim()
+            .trim_start_matches('@')
+            .trim_end_matches('^');
+        let mut prose = format!("<p><strong>{}</strong></p>", escape_html(cue.trim()));
+        let mut spoken: Vec<String> = Vec::new();
+
+        for line in &lines[1..] {
+            let line = line.trim();
+            if line.starts_with('(') && line.ends_with(')') {
+                prose.push_str(&format!("<p><em>{}</em></p>", escape_html(line)));
+            } else if !line.is_empty() {
+                prose.push_str(&format!("<p>{}</p>", inline_html(line)));
+                spoken.push(plain_text(line));
+            }
+        }
+
+        let content = format!("{}: {}", name, spoken.join(" "));
+        self.add_beat(content, prose);
+
+        let scene_id = self.scenes.last().expect("scene exists").id;
+        let key = name.to_uppercase();
+        let character_id = match self.character_ids.get(&key) {
+            Some(id) => *id,
+            None => {
+                let character = Character::new(
+                    self.project_id,
+                    name.to_string(),
+                    None,
+                    Some(format!("fountain:character:{key}")),
+                );
+                let id = character.id;
+                self.characters.push(character);
+                self.character_ids.insert(key, id);
+                id
+            }
+        };
+        if !self
+            .scene_character_refs
+            .contains(&(scene_id, character_id))
+        {
+            self.scene_character_refs.push((scene_id, character_id));
+        }
+    }
+
+    fn finish(mut self, project: Project) -> ParsedFountain {
+        if self.chapters.is_empty() {
+            self.start_chapter("Act 1".to_string());
+        }
+        ParsedFountain {
+            project,
+            chapters: self.chapters,
+            scenes: self.scenes,
+            beats: self.beats,
+            characters: self.characters,
+            scene_character_refs: self.scene_character_refs,
+        }
+    }
+}
+
+/// Parse the optional `Key: value` title page at the top of the script.
+///
+/// Returns the lowercased keys mapped to their values and the index of the
+/// first body line. Indented lines continue the previous key's value.
+fn parse_title_page(lines: &[&str]) -> (HashMap<String, String>, usize) {
+    let mut fields: HashMap<String, String> = HashMap::new();
+    let start = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or(lines.len());
+
+    if lines
+        .get(start)
+        .is_none_or(|line| title_page_key(line).is_none())
+    {
+        return (fields, 0);
+    }
+
+    let mut current_key: Option<String> = None;
+    let mut index = start;
+    while index < lines.len() && !lines[index].trim().is_empty() {
+        let line = lines[index];
+        if let Some((key, value)) = title_page_key(line) {
+            fields.insert(key.clone(), value.to_string());
+            current_key = Some(key);
+        } else if let Some(key) = &current_key {
+            let value = fields.entry(key.clone()).or_default();
+            if !value.is_empty() {
+                value.push(' ');
+            }
+            value.push_str(line.trim());
+        }
+        index += 1;
+    }
+
+    (fields, index)
+}
+
+/// Split an unindented `Key: value` title page line
+fn title_page_key(line: &str) -> Option<(String, &str)> {
+    if line.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let (key, value) = line.split_once(':')?;
+    let valid = !key.is_empty()
+        && key.starts_with(|c: char| c.is_ascii_alphabetic())
+        && key.chars().all(|c| c.is_ascii_alphabetic() || c == ' ');
+    valid.then(|| (key.trim().to_lowercase(), value.trim()))
+}
+
+/// Remove `/* boneyard */` and `[[notes]]`, which never reach the imported text
+fn strip_boneyard_and_notes(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while !rest.is_empty() {
+        let next_boneyard = rest.find("/*");
+        let next_note = rest.find("[[");
+        let (start, close) = match (next_boneyard, next_note) {
+            (Some(b), Some(n)) if n < b => (n, "]]"),
+            (Some(b), _) => (b, "*/"),
+            (None, Some(n)) => (n, "]]"),
+            (None, None) => {
+                out.push_str(rest);
+                break;
+            }
+        };
+
+        out.push_str(&rest[..start]);
+        match rest[start + 2..].find(close) {
+            Some(end) => rest = &rest[start + 2 + end + 2..],
+            None => break,
+        }
+    }
+
+    out
+}
+
+/// Returns the heading level and title for `#` section lines
+fn section_level(line: &str) -> Option<(usize, String)> {
+    let trimmed = line.trim();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if level == 0 {
+        return None;
+    }
+    Some((level, trimmed[level..].trim().to_string()))
+}
+
+/// Returns the heading text when the line is a scene heading
+fn scene_heading(line: &str) -> Option<String> {
+    let heading = if let Some(forced) = line.strip_prefix('.') {
+        if forced.starts_with('.') || forced.trim().is_empty() {
+            return None;
+        }
+        forced.trim()
+    } else {
+        const PREFIXES: [&str; 8] = [
+            "INT./EXT", "INT/EXT", "I/E", "INT.", "EXT.", "EST.", "INT ", "EXT ",
+        ];
+        let upper = line.to_uppercase();
+        if !PREFIXES.iter().any(|prefix| upper.starts_with(prefix)) {
+            return None;
+        }
+        line.trim()
+    };
+
+    // Drop trailing scene numbers such as `#12A#`
+    let heading = match heading
+        .strip_suffix('#')
+        .and_then(|h| h.rfind('#').map(|i| &h[..i]))
+    {
+        Some(without_number) => without_number.trim(),
+        None => heading,
+    };
+    Some(heading.to_string())
+}
+
+/// Returns the character name when the line is a dialogue cue.
+///
+/// Cues are all-caps lines (or forced with `@`); extensions such as `(V.O.)`
+/// and the dual-dialogue caret are not part of the name.
+fn character_cue(line: &str) -> Option<String> {
+    let (forced, line) = match line.strip_prefix('@') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let line = line.trim_end_matches('^').trim();
+    let name = line.split('(').next().unwrap_or_default().trim();
+
+    if name.is_empty() || !name.chars().any(|c| c.is_alphabetic()) {
+        return None;
+    }
+    if !forced
+        && (name.chars().any(|c| c.is_lowercase())
+            || name.ends_with("TO:")
+            || line.starts_with('!')
+            || scene_heading(line).is_some())
+    {
+        return None;
+    }
+
+    Some(name.to_string())
+}
+
+/// Normalise one line of an action block (forced action, centering, transitions)
+fn action_line(line: &str) -> String {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('!').unwrap_or(trimmed);
+    let trimmed = trimmed.strip_prefix('~').unwrap_or(trimmed);
+    let trimmed = match trimmed.strip_prefix('>') {
+        Some(inner) => inner.strip_suffix('<').unwrap_or(inner),
+        None => trimmed,
+    };
+    trimmed.trim().to_string()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Convert Fountain emphasis (`***`, `**`, `*`, `_`) to HTML tags
+fn inline_html(text: &str) -> String {
+    convert_emphasis(&escape_html(text), true)
+}
+
+/// Strip Fountain emphasis markers, leaving plain text
+fn plain_text(text: &str) -> String {
+    convert_emphasis(text.trim(), false)
+}
+
+fn convert_emphasis(text: &str, html: bool) -> String {
+    const ESCAPED_STAR: char = '\u{E000}';
+    const ESCAPED_UNDERSCORE: char = '\u{E001}';
+
+    let mut out = text
+        .replace("\\*", &ESCAPED_STAR.to_string())
+        .replace("\\_", &ESCAPED_UNDERSCORE.to_string());
+
+    let markers: [(&str, &str, &str); 4] = [
+        ("***", "<strong><em>", "</em></strong>"),
+        ("**", "<strong>", "</strong>"),
+        ("*", "<em>", "</em>"),
+        ("_", "<u>", "</u>"),
+    ];
+    for (marker, open, close) in markers {
+        let (open, close) = if html { (open, close) } else { ("", "") };
+        out = replace_marker_pairs(&out, marker, open, close);
+    }
+
+    out.replace(ESCAPED_STAR, "*")
+        .replace(ESCAPED_UNDERSCORE, "_")
+}
+
+/// Replace balanced `marker…marker` pairs; unmatched markers are left as-is
+fn replace_marker_pairs(text: &str, marker: &str, open: &str, close: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(marker) {
+        let after = &rest[start + marker.len()..];
+        match after.find(marker) {
+            Some(end) if end > 0 && !after.starts_with(char::is_whitespace) => {
+                out.push_str(&rest[..start]);
+                out.push_str(open);
+                out.push_str(&after[..end]);
+                out.push_str(close);
+                rest = &after[end + marker.len()..];
+            }
+            _ => {
+                out.push_str(&rest[..start + marker.len()]);
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/short-film.fountain")
+    }
+
+    #[test]
+    fn test_parse_fountain_fixture() {
+        let parsed = parse_fountain_file(fixture_path()).unwrap();
+
+        assert_eq!(parsed.project.name, "The Lighthouse Keeper");
+        assert_eq!(parsed.project.author_pen_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(parsed.project.source_type, SourceType::Fountain);
+        assert_eq!(parsed.project.project_type, "screenplay");
+
+        let chapter_titles: Vec<&str> = parsed.chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(chapter_titles, vec!["Act One", "Act Two"]);
+        assert_eq!(
+            parsed.chapters[0].synopsis.as_deref(),
+            Some("The keeper is alone.")
+        );
+
+        let scene_titles: Vec<&str> = parsed.scenes.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(scene_titles, vec!["The Storm", "The Visitor", "Dawn"]);
+        assert_eq!(
+            parsed.scenes[0].synopsis.as_deref(),
+            Some("A storm batters the lighthouse.")
+        );
+        assert_eq!(parsed.scenes[2].chapter_id, parsed.chapters[1].id);
+        assert_eq!(parsed.scenes[2].position, 0);
+    }
+
+    #[test]
+    fn test_fountain_beats_carry_html_prose() {
+        let parsed = parse_fountain_file(fixture_path()).unwrap();
+        let storm = &parsed.scenes[0];
+        let beats: Vec<&Beat> = parsed
+            .beats
+            .iter()
+            .filter(|b| b.scene_id == storm.id)
+            .collect();
+
+        // Scene heading, action, dialogue
+        assert_eq!(beats.len(), 3);
+        assert_eq!(beats[0].content, "INT. LIGHTHOUSE - NIGHT");
+        assert_eq!(
+            beats[1].prose.as_deref(),
+            Some("<p>Rain lashes the <em>glass</em>.</p><p>MARTHA trims the wick.</p>")
+        );
+        assert_eq!(beats[2].content, "MARTHA: Not tonight. Not again.");
+        assert_eq!(
+            beats[2].prose.as_deref(),
+            Some(
+                "<p><strong>MARTHA (V.O.)</strong></p><p><em>(whispering)</em></p><p>Not tonight.</p><p>Not again.</p>"
+            )
+        );
+        assert!(beats.iter().all(|b| !b.content.contains("note")));
+    }
+
+    #[test]
+    fn test_fountain_characters_from_cues() {
+        let parsed = parse_fountain_file(fixture_path()).unwrap();
+
+        let names: Vec<&str> = parsed.characters.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["MARTHA", "STRANGER"]);
+
+        let martha = &parsed.characters[0];
+        let martha_scenes = parsed
+            .scene_character_refs
+            .iter()
+            .filter(|(_, character_id)| *character_id == martha.id)
+            .count();
+        assert_eq!(martha_scenes, 2);
+    }
+
+    #[test]
+    fn test_scene_headings_become_scenes_without_sequences() {
+        let content = "Title: **Small** Script\n\
+                       Author: A. Writer\n\
+                       \n\
+                       INT. KITCHEN - DAY #1#\n\
+                       \n\
+                       Toast pops.\n\
+                       \n\
+                       .FLASHBACK\n\
+                       \n\
+                       BOB\n\
+                       Remember?\n";
+
+        let parsed = parse_fountain_content(content, Path::new("small.fountain"));
+
+        assert_eq!(parsed.project.name, "Small Script");
+        assert_eq!(parsed.chapters.len(), 1);
+        assert_eq!(parsed.chapters[0].title, "Act 1");
+        let scene_titles: Vec<&str> = parsed.scenes.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(scene_titles, vec!["INT. KITCHEN - DAY", "FLASHBACK"]);
+        assert_eq!(parsed.beats.len(), 2);
+        assert_eq!(parsed.beats[1].scene_id, parsed.scenes[1].id);
+    }
+
+    #[test]
+    fn test_convert_emphasis() {
+        assert_eq!(
+            inline_html("***both*** **bold** *it* _under_ \\*lit\\*"),
+            "<strong><em>both</em></strong> <strong>bold</strong> <em>it</em> <u>under</u> *lit*"
+        );
+        assert_eq!(plain_text("a * b and *c*"), "a * b and c");
+        assert_eq!(inline_html("x < y & z"), "x &lt; y &amp; z");
+    }
+}
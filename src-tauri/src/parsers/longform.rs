@@ -11,6 +11,8 @@ use crate::models::{
     SourceType,
 };
 
+use super::encoding::read_text_file;
+
 const LONGFORM_DEFAULT_CHAPTER_SOURCE_ID: &str = "longform:default";
 const LONGFORM_BEATS_MARKER: &str = "<!-- kindling: beats -->";
 
@@ -228,7 +230,7 @@ struct DataviewContext<'a> {
 
 pub fn parse_longform_index<P: AsRef<Path>>(path: P) -> Result<ParsedLongform, LongformError> {
     let path = path.as_ref();
-    let content = fs::read_to_string(path)?;
+    let content = read_text_file(path)?;
 
     let (frontmatter_str, _) = split_frontmatter(&content)
         .ok_or_else(|| LongformError::InvalidStructure("Missing YAML frontmatter".to_string()))?;
@@ -338,7 +340,7 @@ fn find_longform_indexes(vault_dir: &Path) -> Result<Vec<PathBuf>, LongformError
             continue;
         }
 
-        let content = fs::read_to_string(entry.path())?;
+        let content = read_text_file(entry.path())?;
         let (frontmatter_str, _) = match split_frontmatter(&content) {
             Some(parts) => parts,
             None => continue,
@@ -428,7 +430,7 @@ fn collect_reference_notes(
             continue;
         }
 
-        let content = fs::read_to_string(entry.path())?;
+        let content = read_text_file(entry.path())?;
         let (frontmatter, body) = match split_frontmatter(&content) {
             Some((frontmatter_str, body)) => {
                 let parsed = serde_yaml::from_str::<NoteFrontmatter>(&frontmatter_str).ok();
@@ -959,7 +961,7 @@ fn merge_reference_item_note(
 }
 
 fn parse_scene_file(path: &Path) -> Result<SceneContent, LongformError> {
-    let content = fs::read_to_string(path)?;
+    let content = read_text_file(path)?;
     let (frontmatter, body) = match split_frontmatter(&content) {
         Some((frontmatter_str, body)) => {
             let parsed = serde_yaml::from_str::<SceneFrontmatter>(&frontmatter_str).ok();
@@ -0,0 +1,127 @@
+//! Shared outline building for manuscript imports (ODT, DOCX)
+//!
+//! Word-processor documents arrive as a flat run of headings and paragraphs.
+//! [`ManuscriptBuilder`] turns that run into chapters and scenes, collecting
+//! each scene's paragraphs into the prose of one "Scene Content" beat, as the
+//! Scrivener import does.
+
+use uuid::Uuid;
+
+use crate::models::{Beat, Chapter, Scene};
+
+/// Whether a paragraph is only a scene-break marker such as `#`, `* * *` or `⁂`
+pub(crate) fn is_scene_break_marker(text: &str) -> bool {
+    let marker = text.trim();
+    !marker.is_empty()
+        && marker.chars().count() <= 9
+        && marker
+            .chars()
+            .all(|c| c.is_whitespace() || matches!(c, '#' | '*' | '⁂' | '~'))
+}
+
+/// Accumulates chapters, scenes and prose paragraphs in document order
+pub(crate) struct ManuscriptBuilder {
+    project_id: Uuid,
+    /// Prefix for source ids, e.g. `odt` gives `odt:chapter:0`
+    source_prefix: &'static str,
+    chapters: Vec<Chapter>,
+    scenes: Vec<Scene>,
+    /// Prose paragraphs per scene, parallel to `scenes`
+    paragraphs: Vec<Vec<String>>,
+    /// A scene break was seen; the next paragraph starts a new scene
+    break_pending: bool,
+}
+
+impl ManuscriptBuilder {
+    pub(crate) fn new(project_id: Uuid, source_prefix: &'static str) -> Self {
+        Self {
+            project_id,
+            source_prefix,
+            chapters: Vec::new(),
+            scenes: Vec::new(),
+            paragraphs: Vec::new(),
+            break_pending: false,
+        }
+    }
+
+    /// Start a chapter; an empty title becomes "Chapter N"
+    pub(crate) fn start_chapter(&mut self, title: String) {
+        let position = self.chapters.len() as i32;
+        let title = if title.is_empty() {
+            format!("Chapter {}", position + 1)
+        } else {
+            title
+        };
+        self.chapters.push(
+            Chapter::new(self.project_id, title, position)
+                .with_source_id(Some(format!("{}:chapter:{}", self.source_prefix, position))),
+        );
+        self.break_pending = false;
+    }
+
+    /// Start a scene in the current chapter; an empty title becomes "Scene N"
+    pub(crate) fn start_scene(&mut self, title: String) {
+        if self.chapters.is_empty() {
+            self.start_chapter(String::new());
+        }
+        let chapter = self.chapters.last().expect("chapter exists");
+        let position = self
+            .scenes
+            .iter()
+            .filter(|s| s.chapter_id == chapter.id)
+            .count() as i32;
+        let title = if title.is_empty() {
+            format!("Scene {}", position + 1)
+        } else {
+            title
+        };
+        self.scenes.push(
+            Scene::new(chapter.id, title, None, position).with_source_id(Some(format!(
+                "{}:scene:{}:{}",
+                self.source_prefix, chapter.position, position
+            ))),
+        );
+        self.paragraphs.push(Vec::new());
+        self.break_pending = false;
+    }
+
+    /// End the current scene; only takes effect once more prose follows
+    pub(crate) fn scene_break(&mut self) {
+        if self.paragraphs.last().is_some_and(|p| !p.is_empty()) {
+            self.break_pending = true;
+        }
+    }
+
+    /// Add a prose paragraph (already HTML) to the current scene
+    pub(crate) fn push_paragraph(&mut self, html: String) {
+        let in_current_chapter = match (self.chapters.last(), self.scenes.last()) {
+            (Some(chapter), Some(scene)) => scene.chapter_id == chapter.id,
+            _ => false,
+        };
+        if !in_current_chapter || self.break_pending {
+            self.start_scene(String::new());
+        }
+        self.paragraphs.last_mut().expect("scene exists").push(html);
+    }
+
+    /// Turn each scene's paragraphs into one prose beat
+    pub(crate) fn finish(self) -> (Vec<Chapter>, Vec<Scene>, Vec<Beat>) {
+        let scene_prefix = format!("{}:scene:", self.source_prefix);
+        let beat_prefix = format!("{}:beat:", self.source_prefix);
+        let mut beats = Vec::new();
+        for (scene, paragraphs) in self.scenes.iter().zip(self.paragraphs) {
+            if paragraphs.is_empty() {
+                continue;
+            }
+            let source_id = scene
+                .source_id
+                .as_deref()
+                .map(|id| id.replacen(&scene_prefix, &beat_prefix, 1));
+            let mut beat =
+                Beat::new(scene.id, "Scene Content".to_string(), 0).with_source_id(source_id);
+            beat.prose = Some(paragraphs.join("\n"));
+            beats.push(beat);
+        }
+        (self.chapters, self.scenes, beats)
+    }
+}
@@ -1,11 +1,14 @@
-use std::fs;
 use std::path::Path;
 
 use serde::Deserialize;
 use thiserror::Error;
+use uuid::Uuid;
+use walkdir::WalkDir;
 
 use crate::models::{Beat, Chapter, Project, Scene, SourceType};
 
+use super::encoding::read_text_file;
+
 #[derive(Debug, Error)]
 pub enum MarkdownError {
     #[error("Failed to read file: {0}")]
@@ -52,9 +55,16 @@ pub struct ParsedMarkdown {
 /// - H1 as chapter
 /// - H2 as scene
 /// - List items or paragraphs under H2 as beats
+///
+/// When the document uses any H3 heading, the outline is read as three levels
+/// instead: H1 becomes a part (`is_part = true`), H2 a chapter and H3 a scene.
+/// Content under a heading that has no scene yet lands in an "Untitled Scene".
+///
+/// Paragraph beats also carry their text as prose, with `*`/`_` emphasis
+/// converted to `<em>` and `**`/`__` to `<strong>`.
 pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown, MarkdownError> {
     let path = path.as_ref();
-    let content = fs::read_to_string(path)?;
+    let content = read_text_file(path)?;
 
     let (frontmatter, body) = match split_frontmatter(&content) {
         Some((frontmatter_str, body)) => {
@@ -99,6 +109,20 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
     let mut scenes: Vec<Scene> = Vec::new();
     let mut beats: Vec<Beat> = Vec::new();
 
+    let has_parts = body
+        .lines()
+        .any(|line| line.trim_start().starts_with("### "));
+    let default_chapter_title = if has_parts {
+        "Untitled Chapter"
+    } else {
+        "Chapter 1"
+    };
+    let default_scene_title = if has_parts {
+        "Untitled Scene"
+    } else {
+        "Scene 1"
+    };
+
     let mut current_chapter: Option<Chapter> = None;
     let mut current_scene: Option<Scene> = None;
     let mut chapter_position = 0;
@@ -137,7 +161,9 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
             collecting_synopsis = false;
         }
 
-        if let Some(stripped) = trimmed_start.strip_prefix("# ") {
+        let heading = parse_heading(trimmed_start, has_parts);
+
+        if let Some((level @ (HeadingLevel::Part | HeadingLevel::Chapter), stripped)) = heading {
             // Save previous scene and chapter if they exist
             if collecting_synopsis {
                 finish_synopsis(&mut current_scene, &mut synopsis_lines);
@@ -150,17 +176,18 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
                 chapters.push(chapter);
             }
 
-            // New chapter
+            // New chapter (or part)
             let title = stripped.trim().to_string();
             current_chapter = Some(
                 Chapter::new(project.id, title, chapter_position)
-                    .with_source_id(Some(markdown_chapter_source_id(chapter_position))),
+                    .with_source_id(Some(markdown_chapter_source_id(chapter_position)))
+                    .with_is_part(level == HeadingLevel::Part),
             );
             chapter_position += 1;
             scene_position = 0;
             beat_position = 0;
             pending_synopsis = false;
-        } else if let Some(stripped) = trimmed_start.strip_prefix("## ") {
+        } else if let Some((HeadingLevel::Scene, stripped)) = heading {
             // Save previous scene if it exists
             if collecting_synopsis {
                 finish_synopsis(&mut current_scene, &mut synopsis_lines);
@@ -171,12 +198,14 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
             }
 
             // New scene under current chapter (or create default chapter)
-            if current_chapter.is_none() {
-                current_chapter = Some(
-                    Chapter::new(project.id, "Chapter 1".to_string(), chapter_position)
-                        .with_source_id(Some(markdown_chapter_source_id(chapter_position))),
-                );
-                chapter_position += 1;
+            if start_default_chapter_if_needed(
+                &project,
+                &mut current_chapter,
+                &mut chapters,
+                &mut chapter_position,
+                default_chapter_title,
+            ) {
+                scene_position = 0;
             }
 
             if let Some(ref chapter) = current_chapter {
@@ -193,20 +222,27 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
         } else if trimmed_start.starts_with("- ") || trimmed_start.starts_with("* ") {
             // Beat (list item with content)
             if current_scene.is_none() {
-                if current_chapter.is_none() {
-                    current_chapter = Some(
-                        Chapter::new(project.id, "Chapter 1".to_string(), chapter_position)
-                            .with_source_id(Some(markdown_chapter_source_id(chapter_position))),
-                    );
-                    chapter_position += 1;
+                if start_default_chapter_if_needed(
+                    &project,
+                    &mut current_chapter,
+                    &mut chapters,
+                    &mut chapter_position,
+                    default_chapter_title,
+                ) {
+                    scene_position = 0;
                 }
                 if let Some(ref chapter) = current_chapter {
                     current_scene = Some(
-                        Scene::new(chapter.id, "Scene 1".to_string(), None, scene_position)
-                            .with_source_id(Some(markdown_scene_source_id(
-                                chapter.position,
-                                scene_position,
-                            ))),
+                        Scene::new(
+                            chapter.id,
+                            default_scene_title.to_string(),
+                            None,
+                            scene_position,
+                        )
+                        .with_source_id(Some(markdown_scene_source_id(
+                            chapter.position,
+                            scene_position,
+                        ))),
                     );
                     scene_position += 1;
                     beat_position = 0;
@@ -232,20 +268,27 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
         } else if !trimmed.is_empty() && !trimmed_start.starts_with('#') {
             // Regular paragraph under a scene becomes a beat
             if current_scene.is_none() {
-                if current_chapter.is_none() {
-                    current_chapter = Some(
-                        Chapter::new(project.id, "Chapter 1".to_string(), chapter_position)
-                            .with_source_id(Some(markdown_chapter_source_id(chapter_position))),
-                    );
-                    chapter_position += 1;
+                if start_default_chapter_if_needed(
+                    &project,
+                    &mut current_chapter,
+                    &mut chapters,
+                    &mut chapter_position,
+                    default_chapter_title,
+                ) {
+                    scene_position = 0;
                 }
                 if let Some(ref chapter) = current_chapter {
                     current_scene = Some(
-                        Scene::new(chapter.id, "Scene 1".to_string(), None, scene_position)
-                            .with_source_id(Some(markdown_scene_source_id(
-                                chapter.position,
-                                scene_position,
-                            ))),
+                        Scene::new(
+                            chapter.id,
+                            default_scene_title.to_string(),
+                            None,
+                            scene_position,
+                        )
+                        .with_source_id(Some(markdown_scene_source_id(
+                            chapter.position,
+                            scene_position,
+                        ))),
                     );
                     scene_position += 1;
                     beat_position = 0;
@@ -256,10 +299,14 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
                     .as_ref()
                     .map(|chapter| chapter.position)
                     .unwrap_or(0);
-                let beat =
-                    Beat::new(scene.id, trimmed.to_string(), beat_position).with_source_id(Some(
-                        markdown_beat_source_id(chapter_position, scene.position, beat_position),
-                    ));
+                let mut beat =
+                    Beat::new(scene.id, markdown_inline_to_plain(trimmed), beat_position)
+                        .with_source_id(Some(markdown_beat_source_id(
+                            chapter_position,
+                            scene.position,
+                            beat_position,
+                        )));
+                beat.prose = Some(format!("<p>{}</p>", markdown_inline_to_html(trimmed)));
                 beats.push(beat);
                 beat_position += 1;
             }
@@ -306,6 +353,65 @@ pub fn parse_markdown_outline<P: AsRef<Path>>(path: P) -> Result<ParsedMarkdown,
     })
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeadingLevel {
+    Part,
+    Chapter,
+    Scene,
+}
+
+/// Classify an ATX heading line. H3 only carries meaning in three-level
+/// outlines; deeper headings are not structural.
+fn parse_heading(line: &str, has_parts: bool) -> Option<(HeadingLevel, &str)> {
+    if let Some(rest) = line.strip_prefix("### ") {
+        has_parts.then_some((HeadingLevel::Scene, rest))
+    } else if let Some(rest) = line.strip_prefix("## ") {
+        let level = if has_parts {
+            HeadingLevel::Chapter
+        } else {
+            HeadingLevel::Scene
+        };
+        Some((level, rest))
+    } else if let Some(rest) = line.strip_prefix("# ") {
+        let level = if has_parts {
+            HeadingLevel::Part
+        } else {
+            HeadingLevel::Chapter
+        };
+        Some((level, rest))
+    } else {
+        None
+    }
+}
+
+/// Make sure there is a regular chapter to hold a scene, closing out a part
+/// that has no chapter of its own yet. Returns true when a chapter was created.
+fn start_default_chapter_if_needed(
+    project: &Project,
+    current_chapter: &mut Option<Chapter>,
+    chapters: &mut Vec<Chapter>,
+    chapter_position: &mut i32,
+    title: &str,
+) -> bool {
+    if current_chapter
+        .as_ref()
+        .is_some_and(|chapter| chapter.is_part)
+    {
+        if let Some(part) = current_chapter.take() {
+            chapters.push(part);
+        }
+    }
+    if current_chapter.is_some() {
+        return false;
+    }
+    *current_chapter = Some(
+        Chapter::new(project.id, title.to_string(), *chapter_position)
+            .with_source_id(Some(markdown_chapter_source_id(*chapter_position))),
+    );
+    *chapter_position += 1;
+    true
+}
+
 fn markdown_chapter_source_id(chapter_position: i32) -> String {
     format!("markdown:chapter:{chapter_position}")
 }
@@ -322,6 +428,313 @@ fn markdown_beat_source_id(
     format!("markdown:beat:{chapter_position}:{scene_position}:{beat_position}")
 }
 
+// ============================================================================
+// Export Round-Trip
+// ============================================================================
+
+/// A scene file from a Kindling Markdown export, matched back to its scene by
+/// the `source_id` in its frontmatter
+#[derive(Debug)]
+pub struct ExportedSceneFile {
+    pub scene_id: Uuid,
+    /// Prose HTML per beat marker, or a single entry when the file has none
+    pub beat_prose: Vec<String>,
+    pub has_beat_markers: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ExportedSceneFrontmatter {
+    source_id: Option<String>,
+    scene_heading_level: Option<usize>,
+    beat_heading_level: Option<usize>,
+}
+
+/// Read the scene files of a folder written by `export_to_markdown` with
+/// frontmatter enabled. Files without a `source_id` (chapter or single-file
+/// exports, or hand-made notes) are ignored.
+pub fn parse_markdown_export_folder<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<ExportedSceneFile>, MarkdownError> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(path)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(Result::ok)
+    {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if entry.path().extension().and_then(|s| s.to_str()) != Some("md") {
+            continue;
+        }
+        let content = read_text_file(entry.path())?;
+        if let Some(file) = parse_exported_scene(&content) {
+            files.push(file);
+        }
+    }
+    Ok(files)
+}
+
+/// Split an exported scene file into prose sections. The first heading is the
+/// scene title; beat markers are headings at the frontmatter's
+/// `beat_heading_level`, or one level below the title in files that predate it.
+/// The synopsis blockquote and the trailing `---` references block are not prose.
+fn parse_exported_scene(content: &str) -> Option<ExportedSceneFile> {
+    let (frontmatter, body) = split_frontmatter(content)?;
+    let meta: ExportedSceneFrontmatter = serde_yaml::from_str(&frontmatter).ok()?;
+    let scene_id = Uuid::parse_str(meta.source_id?.trim()).ok()?;
+    // Exports that record the scene level but no beat level have no markers
+    let recorded_beat_level = meta.scene_heading_level.map(|_| meta.beat_heading_level);
+
+    let mut beat_level: Option<Option<usize>> = None;
+    let mut has_beat_markers = false;
+    let mut sections: Vec<Vec<String>> = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    let flush = |paragraph: &mut Vec<&str>, current: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            let text = decode_basic_entities(&paragraph.join(" "));
+            current.push(format!("<p>{}</p>", markdown_inline_to_html(&text)));
+            paragraph.clear();
+        }
+    };
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        let is_heading = (1..=6).contains(&level) && trimmed[level..].starts_with(' ');
+
+        let Some(beat_level) = beat_level else {
+            if is_heading && meta.scene_heading_level.is_none_or(|l| l == level) {
+                beat_level = Some(recorded_beat_level.unwrap_or(Some(level + 1)));
+            }
+            continue;
+        };
+
+        if trimmed == "---" {
+            break;
+        }
+        if trimmed.is_empty() || parse_blockquote_line(trimmed).is_some() {
+            flush(&mut paragraph, &mut current);
+        } else if is_heading && beat_level == Some(level) {
+            flush(&mut paragraph, &mut current);
+            if has_beat_markers || !current.is_empty() {
+                sections.push(std::mem::take(&mut current));
+            }
+            has_beat_markers = true;
+        } else if is_heading {
+            // A heading inside the prose itself
+            flush(&mut paragraph, &mut current);
+            let text = decode_basic_entities(trimmed[level..].trim());
+            current.push(format!(
+                "<h{level}>{}</h{level}>",
+                markdown_inline_to_html(&text)
+            ));
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush(&mut paragraph, &mut current);
+    sections.push(current);
+
+    Some(ExportedSceneFile {
+        scene_id,
+        beat_prose: sections.into_iter().map(|s| s.join("\n")).collect(),
+        has_beat_markers,
+    })
+}
+
+/// Undo the entity escaping the exporter leaves in plain text
+fn decode_basic_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+// ============================================================================
+// Inline Emphasis
+// ============================================================================
+
+struct DelimiterRun {
+    marker: char,
+    count: usize,
+    can_open: bool,
+    can_close: bool,
+    open_tags: Vec<&'static str>,
+    close_tags: Vec<&'static str>,
+}
+
+enum InlineNode {
+    Text(String),
+    Delimiter(DelimiterRun),
+}
+
+/// Convert Markdown emphasis to `<em>`/`<strong>`, escaping the surrounding text
+fn markdown_inline_to_html(text: &str) -> String {
+    render_inline(&parse_emphasis(text), true)
+}
+
+/// Strip matched Markdown emphasis markers, leaving plain text
+pub fn markdown_inline_to_plain(text: &str) -> String {
+    render_inline(&parse_emphasis(text), false)
+}
+
+/// Split text into literal runs and `*`/`_` delimiter runs, then pair the
+/// delimiters up. Flanking follows CommonMark closely enough for prose:
+/// a run opens when followed by non-whitespace and closes when preceded by
+/// it, and `_` never opens or closes inside a word.
+fn parse_emphasis(text: &str) -> Vec<InlineNode> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut nodes = Vec::new();
+    let mut literal = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        if ch == '\\' && matches!(chars.get(index + 1), Some('*' | '_')) {
+            literal.push(chars[index + 1]);
+            index += 2;
+            continue;
+        }
+        if ch != '*' && ch != '_' {
+            literal.push(ch);
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        while index < chars.len() && chars[index] == ch {
+            index += 1;
+        }
+        let before = start.checked_sub(1).map(|i| chars[i]);
+        let after = chars.get(index).copied();
+        let left_flanking = after.is_some_and(|c| !c.is_whitespace());
+        let right_flanking = before.is_some_and(|c| !c.is_whitespace());
+        let (can_open, can_close) = if ch == '_' {
+            (
+                left_flanking && !before.is_some_and(char::is_alphanumeric),
+                right_flanking && !after.is_some_and(char::is_alphanumeric),
+            )
+        } else {
+            (left_flanking, right_flanking)
+        };
+
+        if !literal.is_empty() {
+            nodes.push(InlineNode::Text(std::mem::take(&mut literal)));
+        }
+        nodes.push(InlineNode::Delimiter(DelimiterRun {
+            marker: ch,
+            count: index - start,
+            can_open,
+            can_close,
+            open_tags: Vec::new(),
+            close_tags: Vec::new(),
+        }));
+    }
+
+    if !literal.is_empty() {
+        nodes.push(InlineNode::Text(literal));
+    }
+
+    match_delimiters(&mut nodes);
+    nodes
+}
+
+fn delimiter_mut(nodes: &mut [InlineNode], index: usize) -> &mut DelimiterRun {
+    match &mut nodes[index] {
+        InlineNode::Delimiter(run) => run,
+        InlineNode::Text(_) => unreachable!("index always points at a delimiter run"),
+    }
+}
+
+/// Pair closing runs with the nearest open run of the same marker. Two
+/// markers on each side make `<strong>`, one makes `<em>`; a `***` pair
+/// takes the `<em>` first so it renders as `<strong><em>…</em></strong>`.
+fn match_delimiters(nodes: &mut [InlineNode]) {
+    let mut openers: Vec<usize> = Vec::new();
+
+    for index in 0..nodes.len() {
+        let (marker, can_open, can_close) = match &nodes[index] {
+            InlineNode::Delimiter(run) => (run.marker, run.can_open, run.can_close),
+            InlineNode::Text(_) => continue,
+        };
+
+        if can_close {
+            while delimiter_mut(nodes, index).count > 0 {
+                let Some(slot) = openers
+                    .iter()
+                    .rposition(|&opener| delimiter_mut(nodes, opener).marker == marker)
+                else {
+                    break;
+                };
+                let opener = openers[slot];
+                let opener_count = delimiter_mut(nodes, opener).count;
+                let closer_count = delimiter_mut(nodes, index).count;
+                let used = if opener_count >= 3 && closer_count >= 3 {
+                    1
+                } else {
+                    opener_count.min(closer_count).min(2)
+                };
+                let (open_tag, close_tag) = if used == 2 {
+                    ("<strong>", "</strong>")
+                } else {
+                    ("<em>", "</em>")
+                };
+
+                let opener_run = delimiter_mut(nodes, opener);
+                opener_run.count -= used;
+                opener_run.open_tags.push(open_tag);
+                let opener_exhausted = opener_run.count == 0;
+
+                let closer_run = delimiter_mut(nodes, index);
+                closer_run.count -= used;
+                closer_run.close_tags.push(close_tag);
+
+                // Runs between the pair can no longer match across it
+                openers.truncate(slot + 1);
+                if opener_exhausted {
+                    openers.pop();
+                }
+            }
+        }
+
+        if can_open && delimiter_mut(nodes, index).count > 0 {
+            openers.push(index);
+        }
+    }
+}
+
+fn render_inline(nodes: &[InlineNode], html: bool) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            InlineNode::Text(text) if html => out.push_str(&escape_html(text)),
+            InlineNode::Text(text) => out.push_str(text),
+            InlineNode::Delimiter(run) => {
+                if html {
+                    run.close_tags.iter().for_each(|tag| out.push_str(tag));
+                }
+                // Unmatched markers stay in the text as written
+                out.extend(std::iter::repeat_n(run.marker, run.count));
+                if html {
+                    run.open_tags.iter().rev().for_each(|tag| out.push_str(tag));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct MarkdownFrontmatter {
     title: Option<String>,
@@ -434,6 +847,23 @@ mod tests {
         assert!(result.beats.len() > 50); // Hamlet has many beats
     }
 
+    #[test]
+    fn test_parse_utf16le_markdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("windows.md");
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "# Chapitre un\n\n## Le café\n\n- Élise arrive\n".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        std::fs::write(&path, bytes).unwrap();
+
+        let result = parse_markdown_outline(&path).unwrap();
+
+        assert_eq!(result.chapters[0].title, "Chapitre un");
+        assert_eq!(result.scenes[0].title, "Le café");
+        assert_eq!(result.beats[0].content, "Élise arrive");
+    }
+
     #[test]
     fn test_parse_scenes_only_fixture() {
         let path = fixtures_dir().join("scenes-only.md");
@@ -906,19 +1336,19 @@ mod tests {
     }
 
     #[test]
-    fn test_ignores_h3_and_beyond() {
+    fn test_ignores_h4_and_beyond() {
         use std::io::Write;
         use tempfile::NamedTempFile;
 
-        let content = r#"# Chapter
+        let content = r#"# Part
 
-## Scene
+## Chapter
 
-### This is not a scene
+### Scene
 
-- This beat is under the scene, not the H3
+- This beat is under the scene
 
-#### Neither is this
+#### This is not a scene
 
 - Still a beat under the scene
 "#;
@@ -928,10 +1358,176 @@ mod tests {
 
         let result = parse_markdown_outline(file.path()).unwrap();
 
-        assert_eq!(result.chapters.len(), 1);
+        assert_eq!(result.chapters.len(), 2);
         assert_eq!(result.scenes.len(), 1);
-        // H3 and H4 lines are treated as paragraph beats
-        assert!(result.beats.len() >= 2);
+        assert_eq!(result.scenes[0].title, "Scene");
+        // H4 lines are skipped; both list items stay under the H3 scene
+        assert_eq!(result.beats.len(), 2);
+        assert!(result
+            .beats
+            .iter()
+            .all(|b| b.scene_id == result.scenes[0].id));
+    }
+
+    #[test]
+    fn test_three_level_outline_with_parts() {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let content = r#"# Part One
+
+## Chapter 1
+
+### Opening
+
+- Hero wakes up
+
+### Call
+
+- A letter arrives
+
+## Chapter 2
+
+Loose note with no scene heading
+
+# Part Two
+
+### Orphan Scene
+
+- Scene directly under a part
+"#;
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+
+        let result = parse_markdown_outline(file.path()).unwrap();
+
+        let titles: Vec<_> = result.chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Part One",
+                "Chapter 1",
+                "Chapter 2",
+                "Part Two",
+                "Untitled Chapter"
+            ]
+        );
+        let parts: Vec<_> = result.chapters.iter().map(|c| c.is_part).collect();
+        assert_eq!(parts, vec![true, false, false, true, false]);
+        let positions: Vec<_> = result.chapters.iter().map(|c| c.position).collect();
+        assert_eq!(positions, vec![0, 1, 2, 3, 4]);
+
+        // Parts hold no scenes of their own
+        assert!(result.scenes.iter().all(
+            |s| s.chapter_id != result.chapters[0].id && s.chapter_id != result.chapters[3].id
+        ));
+
+        let chapter_1: Vec<_> = result
+            .scenes
+            .iter()
+            .filter(|s| s.chapter_id == result.chapters[1].id)
+            .collect();
+        assert_eq!(chapter_1.len(), 2);
+        assert_eq!(chapter_1[0].title, "Opening");
+        assert_eq!(chapter_1[1].title, "Call");
+        assert_eq!(chapter_1[1].position, 1);
+
+        let chapter_2: Vec<_> = result
+            .scenes
+            .iter()
+            .filter(|s| s.chapter_id == result.chapters[2].id)
+            .collect();
+        assert_eq!(chapter_2.len(), 1);
+        assert_eq!(chapter_2[0].title, "Untitled Scene");
+
+        let untitled: Vec<_> = result
+            .scenes
+            .iter()
+            .filter(|s| s.chapter_id == result.chapters[4].id)
+            .collect();
+        assert_eq!(untitled.len(), 1);
+        assert_eq!(untitled[0].title, "Orphan Scene");
+        assert_eq!(untitled[0].position, 0);
+
+        assert_eq!(result.beats.len(), 4);
+        let loose = result
+            .beats
+            .iter()
+            .find(|b| b.content == "Loose note with no scene heading")
+            .unwrap();
+        assert_eq!(loose.scene_id, chapter_2[0].id);
+    }
+
+    #[test]
+    fn test_inline_emphasis_to_html() {
+        assert_eq!(
+            markdown_inline_to_html("**a** *b*"),
+            "<strong>a</strong> <em>b</em>"
+        );
+        assert_eq!(
+            markdown_inline_to_html("__a__ _b_"),
+            "<strong>a</strong> <em>b</em>"
+        );
+        // Nested
+        assert_eq!(
+            markdown_inline_to_html("**bold *and italic***"),
+            "<strong>bold <em>and italic</em></strong>"
+        );
+        assert_eq!(
+            markdown_inline_to_html("*it __strong__ it*"),
+            "<em>it <strong>strong</strong> it</em>"
+        );
+        assert_eq!(
+            markdown_inline_to_html("***both***"),
+            "<strong><em>both</em></strong>"
+        );
+        // Adjacent
+        assert_eq!(
+            markdown_inline_to_html("**a****b**"),
+            "<strong>a</strong><strong>b</strong>"
+        );
+        assert_eq!(markdown_inline_to_html("*a**b*"), "<em>a</em><em>b</em>");
+        // Literals
+        assert_eq!(
+            markdown_inline_to_html("snake_case_name and 2 * 3 < 7"),
+            "snake_case_name and 2 * 3 &lt; 7"
+        );
+        assert_eq!(
+            markdown_inline_to_html(r"\*not\* *yes*"),
+            "*not* <em>yes</em>"
+        );
+        assert_eq!(markdown_inline_to_html("**unclosed"), "**unclosed");
+    }
+
+    #[test]
+    fn test_paragraph_beats_keep_emphasis_as_prose() {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let content = r#"# Chapter
+
+## Scene
+
+She **never** looked *back*.
+
+- A *list* beat
+"#;
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+
+        let result = parse_markdown_outline(file.path()).unwrap();
+
+        assert_eq!(result.beats.len(), 2);
+        assert_eq!(result.beats[0].content, "She never looked back.");
+        assert_eq!(
+            result.beats[0].prose.as_deref(),
+            Some("<p>She <strong>never</strong> looked <em>back</em>.</p>")
+        );
+        // Outline list items stay as written, with no prose
+        assert_eq!(result.beats[1].content, "A *list* beat");
+        assert!(result.beats[1].prose.is_none());
     }
 
     #[test]
@@ -1048,4 +1644,50 @@ mod tests {
         // Project ID should be unique from all others
         assert_ne!(result.project.id, result.chapters[0].id);
     }
+
+    #[test]
+    fn test_parse_exported_scene_splits_beats() {
+        let id = Uuid::new_v4();
+        let content = format!(
+            "---\ntitle: Harbor\nstatus: draft\nsource_id: {id}\n---\n\n\
+             # Harbor\n\n> Mara arrives.\n\n## Arrival\n\nFirst line\nwraps.\n\n\
+             ## Storm\n\n### Aside\n\nRain *falls*.\n\n---\n\n**Characters:** Mara\n"
+        );
+
+        let file = parse_exported_scene(&content).unwrap();
+        assert_eq!(file.scene_id, id);
+        assert!(file.has_beat_markers);
+        assert_eq!(
+            file.beat_prose,
+            vec![
+                "<p>First line wraps.</p>".to_string(),
+                "<h3>Aside</h3>\n<p>Rain <em>falls</em>.</p>".to_string(),
+            ]
+        );
+
+        // Beat markers two levels below the scene, as the frontmatter records
+        let content = format!(
+            "---\ntitle: Harbor\nsource_id: {id}\nscene_heading_level: 1\n\
+             beat_heading_level: 3\n---\n\n# Harbor\n\n### Arrival\n\nShe lands.\n\n\
+             ## Interlude\n\nA pause.\n"
+        );
+        let file = parse_exported_scene(&content).unwrap();
+        assert!(file.has_beat_markers);
+        assert_eq!(
+            file.beat_prose,
+            vec!["<p>She lands.</p>\n<h2>Interlude</h2>\n<p>A pause.</p>".to_string()]
+        );
+
+        // No beat level recorded means the export had no beat markers
+        let content = format!(
+            "---\nsource_id: {id}\nscene_heading_level: 2\n---\n\n## Harbor\n\n\
+             ### Aside\n\nRain.\n"
+        );
+        let file = parse_exported_scene(&content).unwrap();
+        assert!(!file.has_beat_markers);
+        assert_eq!(file.beat_prose, vec!["<h3>Aside</h3>\n<p>Rain.</p>"]);
+
+        // Without a source_id the file is not a round-trip candidate
+        assert!(parse_exported_scene("---\ntitle: Notes\n---\n\n# Notes\n").is_none());
+    }
 }
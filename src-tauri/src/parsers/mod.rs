@@ -1,11 +1,22 @@
+pub mod csv;
+pub mod docx;
+mod encoding;
+pub mod fountain;
 pub mod longform;
+mod manuscript;
 pub mod markdown;
+pub mod odt;
 pub mod plottr;
 pub mod scrivener;
+mod source_ids;
 pub mod ywriter;
 
+pub use csv::*;
+pub use docx::*;
+pub use fountain::*;
 pub use longform::*;
 pub use markdown::*;
+pub use odt::*;
 pub use plottr::*;
 pub use scrivener::*;
 pub use ywriter::*;
@@ -0,0 +1,431 @@
+//! OpenDocument Text (.odt) Parser
+//!
+//! Imports a manuscript written in LibreOffice or another ODF editor. An ODT
+//! file is a zip; the document body lives in `content.xml`.
+//!
+//! Key mappings:
+//! - Heading 1 → Kindling Chapter
+//! - Heading 2 → Kindling Scene
+//! - Deeper headings → `<h3>`–`<h6>` inside the scene's prose
+//! - Body paragraphs → prose of a single "Scene Content" beat per scene, with
+//!   bold and italic spans kept as `<strong>` and `<em>`
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use thiserror::Error;
+
+use crate::models::{Beat, Chapter, Project, Scene, SourceType};
+
+use super::manuscript::ManuscriptBuilder;
+
+#[derive(Debug, Error)]
+pub enum OdtError {
+    #[error("Failed to read file: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Failed to open ODT archive: {0}")]
+    ZipError(#[from] zip::result::ZipError),
+    #[error("Failed to parse XML: {0}")]
+    XmlError(#[from] quick_xml::Error),
+    #[error("Invalid ODT file structure: {0}")]
+    InvalidStructure(String),
+}
+
+// ============================================================================
+// Parsed Output
+// ============================================================================
+
+pub struct ParsedOdt {
+    pub project: Project,
+    pub chapters: Vec<Chapter>,
+    pub scenes: Vec<Scene>,
+    pub beats: Vec<Beat>,
+}
+
+// ============================================================================
+// Styles
+// ============================================================================
+
+/// The parts of an ODF style the importer cares about
+#[derive(Debug, Default, Clone)]
+struct OdtStyle {
+    parent: Option<String>,
+    bold: bool,
+    italic: bool,
+}
+
+/// Formatting and heading level of a style, following its parent chain
+#[derive(Debug, Default, Clone, Copy)]
+struct ResolvedStyle {
+    bold: bool,
+    italic: bool,
+    heading_level: Option<u8>,
+}
+
+/// Heading level of a built-in heading style name such as `Heading_20_2`
+/// (how ODF encodes "Heading 2")
+fn heading_level_from_name(name: &str) -> Option<u8> {
+    let decoded = name.replace("_20_", " ");
+    decoded
+        .strip_prefix("Heading ")
+        .and_then(|level| level.trim().parse::<u8>().ok())
+        .filter(|level| (1..=6).contains(level))
+}
+
+fn resolve_style(styles: &HashMap<String, OdtStyle>, name: &str) -> ResolvedStyle {
+    let mut resolved = ResolvedStyle::default();
+    let mut current = Some(name.to_string());
+    // Parent chains are short; the limit guards against cycles
+    for _ in 0..16 {
+        let Some(style_name) = current.take() else {
+            break;
+        };
+        match style_name.as_str() {
+            "Emphasis" => resolved.italic = true,
+            "Strong_20_Emphasis" => resolved.bold = true,
+            _ => {}
+        }
+        if resolved.heading_level.is_none() {
+            resolved.heading_level = heading_level_from_name(&style_name);
+        }
+        if let Some(style) = styles.get(&style_name) {
+            resolved.bold |= style.bold;
+            resolved.italic |= style.italic;
+            current = style.parent.clone();
+        }
+    }
+    resolved
+}
+
+fn attr_value(e: &BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+/// Text and markup of the paragraph or heading being read
+struct OpenParagraph {
+    heading_level: Option<u8>,
+    html: String,
+    text: String,
+    /// Closing tags for each open `<text:span>`, innermost last
+    span_closers: Vec<&'static str>,
+}
+
+impl OpenParagraph {
+    fn push_text(&mut self, text: &str) {
+        self.html.push_str(&escape(text));
+        self.text.push_str(text);
+    }
+}
+
+// ============================================================================
+// XML Parser
+// ============================================================================
+
+/// Parse an OpenDocument Text file
+pub fn parse_odt_file<P: AsRef<Path>>(path: P) -> Result<ParsedOdt, OdtError> {
+    let path = path.as_ref();
+    let file = fs::File::open(path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+    let mut content = String::new();
+    archive
+        .by_name("content.xml")
+        .map_err(|_| OdtError::InvalidStructure("missing content.xml".to_string()))?
+        .read_to_string(&mut content)?;
+
+    parse_odt_content(&content, path)
+}
+
+/// Parse the `content.xml` of an ODT file
+fn parse_odt_content(content: &str, path: &Path) -> Result<ParsedOdt, OdtError> {
+    let project_name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Untitled")
+        .to_string();
+    let project = Project::new(
+        project_name,
+        SourceType::Odt,
+        Some(path.to_string_lossy().to_string()),
+    );
+
+    let mut reader = Reader::from_str(content);
+    let mut styles: HashMap<String, OdtStyle> = HashMap::new();
+    let mut current_style: Option<(String, OdtStyle)> = None;
+    let mut builder = ManuscriptBuilder::new(project.id, "odt");
+    let mut paragraph: Option<OpenParagraph> = None;
+    let mut in_body = false;
+    // Depth inside footnotes and comments, whose paragraphs aren't prose
+    let mut skip_depth = 0usize;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                let name = e.name();
+                match name.as_ref() {
+                    b"style:style" => {
+                        current_style = Some(read_style_start(&e));
+                    }
+                    b"style:text-properties" => read_text_properties(&e, &mut current_style),
+                    b"office:text" => in_body = true,
+                    b"text:note" | b"office:annotation" => skip_depth += 1,
+                    b"text:p" | b"text:h" if in_body && skip_depth == 0 => {
+                        paragraph = Some(open_paragraph(&e, &styles));
+                    }
+                    b"text:span" if skip_depth == 0 => {
+                        if let Some(p) = paragraph.as_mut() {
+                            let style = attr_value(&e, b"text:style-name")
+                                .map(|s| resolve_style(&styles, &s))
+                                .unwrap_or_default();
+                            let (open, close) = match (style.bold, style.italic) {
+                                (true, true) => ("<strong><em>", "</em></strong>"),
+                                (true, false) => ("<strong>", "</strong>"),
+                                (false, true) => ("<em>", "</em>"),
+                                (false, false) => ("", ""),
+                            };
+                            p.html.push_str(open);
+                            p.span_closers.push(close);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Empty(e) => match e.name().as_ref() {
+                b"style:style" => {
+                    let (name, style) = read_style_start(&e);
+                    styles.insert(name, style);
+                }
+                b"style:text-properties" => read_text_properties(&e, &mut current_style),
+                b"text:s" if skip_depth == 0 => {
+                    if let Some(p) = paragraph.as_mut() {
+                        let count = attr_value(&e, b"text:c")
+                            .and_then(|c| c.parse::<usize>().ok())
+                            .unwrap_or(1);
+                        p.push_text(&" ".repeat(count));
+                    }
+                }
+                b"text:tab" if skip_depth == 0 => {
+                    if let Some(p) = paragraph.as_mut() {
+                        p.push_text(" ");
+                    }
+                }
+                b"text:line-break" if skip_depth == 0 => {
+                    if let Some(p) = paragraph.as_mut() {
+                        p.html.push_str("<br>");
+                        p.text.push(' ');
+                    }
+                }
+                _ => {}
+            },
+            Event::End(e) => match e.name().as_ref() {
+                b"style:style" => {
+                    if let Some((name, style)) = current_style.take() {
+                        styles.insert(name, style);
+                    }
+                }
+                b"office:text" => in_body = false,
+                b"text:note" | b"office:annotation" => skip_depth = skip_depth.saturating_sub(1),
+                b"text:span" if skip_depth == 0 => {
+                    if let Some(p) = paragraph.as_mut() {
+                        let close = p.span_closers.pop().unwrap_or_default();
+                        p.html.push_str(close);
+                    }
+                }
+                b"text:p" | b"text:h" if skip_depth == 0 => {
+                    if let Some(p) = paragraph.take() {
+                        close_paragraph(p, &mut builder);
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(e) if skip_depth == 0 => {
+                if let Some(p) = paragraph.as_mut() {
+                    p.push_text(&String::from_utf8_lossy(&e));
+                }
+            }
+            Event::GeneralRef(e) if skip_depth == 0 => {
+                if let Some(p) = paragraph.as_mut() {
+                    let resolved = match e.resolve_char_ref()? {
+                        Some(ch) => ch.to_string(),
+                        None => match &e[..] {
+                            b"amp" => "&".to_string(),
+                            b"lt" => "<".to_string(),
+                            b"gt" => ">".to_string(),
+                            b"quot" => "\"".to_string(),
+                            b"apos" => "'".to_string(),
+                            _ => String::new(),
+                        },
+                    };
+                    p.push_text(&resolved);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let (chapters, scenes, beats) = builder.finish();
+
+    Ok(ParsedOdt {
+        project,
+        chapters,
+        scenes,
+        beats,
+    })
+}
+
+fn read_style_start(e: &BytesStart) -> (String, OdtStyle) {
+    let name = attr_value(e, b"style:name").unwrap_or_default();
+    let style = OdtStyle {
+        parent: attr_value(e, b"style:parent-style-name"),
+        ..Default::default()
+    };
+    (name, style)
+}
+
+fn read_text_properties(e: &BytesStart, current_style: &mut Option<(String, OdtStyle)>) {
+    if let Some((_, style)) = current_style.as_mut() {
+        if attr_value(e, b"fo:font-weight").is_some_and(|w| w == "bold" || w == "700") {
+            style.bold = true;
+        }
+        if attr_value(e, b"fo:font-style").is_some_and(|s| s == "italic" || s == "oblique") {
+            style.italic = true;
+        }
+    }
+}
+
+fn open_paragraph(e: &BytesStart, styles: &HashMap<String, OdtStyle>) -> OpenParagraph {
+    let style = attr_value(e, b"text:style-name")
+        .map(|s| resolve_style(styles, &s))
+        .unwrap_or_default();
+    let heading_level = if e.name().as_ref() == b"text:h" {
+        let level = attr_value(e, b"text:outline-level")
+            .and_then(|l| l.parse::<u8>().ok())
+            .or(style.heading_level)
+            .unwrap_or(1);
+        Some(level.clamp(1, 6))
+    } else {
+        style.heading_level
+    };
+
+    let mut html = String::new();
+    let mut span_closers = Vec::new();
+    // Formatting on a whole body paragraph wraps all of its text
+    if heading_level.is_none() {
+        if style.bold {
+            html.push_str("<strong>");
+            span_closers.push("</strong>");
+        }
+        if style.italic {
+            html.push_str("<em>");
+            span_closers.push("</em>");
+        }
+    }
+
+    OpenParagraph {
+        heading_level,
+        html,
+        text: String::new(),
+        span_closers,
+    }
+}
+
+fn close_paragraph(mut p: OpenParagraph, builder: &mut ManuscriptBuilder) {
+    while let Some(close) = p.span_closers.pop() {
+        p.html.push_str(close);
+    }
+    let title = p.text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    match p.heading_level {
+        Some(1) => builder.start_chapter(title),
+        Some(2) => builder.start_scene(title),
+        Some(level) => {
+            if !title.is_empty() {
+                builder.push_paragraph(format!("<h{0}>{1}</h{0}>", level, escape(&title)));
+            }
+        }
+        None => {
+            if !p.text.trim().is_empty() {
+                builder.push_paragraph(format!("<p>{}</p>", p.html.trim()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/lighthouse.odt")
+    }
+
+    #[test]
+    fn test_parse_odt_fixture() {
+        let parsed = parse_odt_file(fixture_path()).unwrap();
+
+        assert_eq!(parsed.project.name, "lighthouse");
+        assert_eq!(parsed.project.source_type, SourceType::Odt);
+
+        let chapter_titles: Vec<&str> = parsed.chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(chapter_titles, vec!["The Lighthouse", "Aftermath"]);
+
+        let scene_titles: Vec<&str> = parsed.scenes.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(scene_titles, vec!["Arrival", "Storm", "Scene 1"]);
+        assert_eq!(parsed.scenes[2].chapter_id, parsed.chapters[1].id);
+
+        assert_eq!(parsed.beats.len(), 3);
+        let arrival = parsed.beats[0].prose.as_deref().unwrap();
+        assert!(
+            arrival.contains("The keeper <em>never</em> slept."),
+            "{}",
+            arrival
+        );
+        assert!(arrival.contains("Waves &amp; wind. <strong>Bold</strong> claims."));
+        assert!(arrival.contains("<h3>Logbook</h3>"));
+
+        let storm = parsed.beats[1].prose.as_deref().unwrap();
+        assert_eq!(storm, "<p>Rain fell.<br>Then   more.</p>");
+    }
+
+    #[test]
+    fn test_parse_odt_content_without_headings() {
+        let content = r#"<office:document-content><office:body><office:text>
+            <text:p text:style-name="Standard">Just one paragraph.</text:p>
+            <text:p text:style-name="Standard">A footnote<text:note><text:note-body><text:p>Hidden</text:p></text:note-body></text:note> here.</text:p>
+        </office:text></office:body></office:document-content>"#;
+
+        let parsed = parse_odt_content(content, Path::new("draft.odt")).unwrap();
+
+        assert_eq!(parsed.chapters.len(), 1);
+        assert_eq!(parsed.chapters[0].title, "Chapter 1");
+        assert_eq!(parsed.scenes.len(), 1);
+        assert_eq!(
+            parsed.beats[0].prose.as_deref(),
+            Some("<p>Just one paragraph.</p>\n<p>A footnote here.</p>")
+        );
+    }
+
+    #[test]
+    fn test_parse_odt_missing_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.odt");
+        let file = fs::File::create(&path).unwrap();
+        let mut zip = zip::ZipWriter::new(file);
+        zip.start_file("mimetype", zip::write::FileOptions::<()>::default())
+            .unwrap();
+        zip.finish().unwrap();
+
+        assert!(matches!(
+            parse_odt_file(&path),
+            Err(OdtError::InvalidStructure(_))
+        ));
+    }
+}
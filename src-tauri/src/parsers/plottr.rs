@@ -1,11 +1,12 @@
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::fs;
 use std::path::Path;
 use thiserror::Error;
 
 use crate::models::{Beat, Chapter, Character, Location, Project, Scene, SourceType};
 
+use super::encoding::read_text_file;
+
 #[derive(Debug, Error)]
 pub enum PlottrError {
     #[error("Failed to read file: {0}")]
@@ -191,6 +192,10 @@ pub struct ParsedPlottr {
     pub locations: Vec<Location>,
     pub scene_character_refs: Vec<(uuid::Uuid, uuid::Uuid)>,
     pub scene_location_refs: Vec<(uuid::Uuid, uuid::Uuid)>,
+    /// Character attribute names in the order Plottr lists them
+    pub character_attribute_names: Vec<String>,
+    /// Location attribute names in the order Plottr lists them
+    pub location_attribute_names: Vec<String>,
 }
 
 // ============================================================================
@@ -274,6 +279,90 @@ fn extract_attribute_value(value: &serde_json::Value) -> Option<String> {
     }
 }
 
+/// Custom attribute names declared for `entity` (`"characters"` or `"places"`)
+/// in the top-level `customAttributes`, in the order Plottr shows them
+fn declared_attribute_names(
+    custom_attributes: Option<&serde_json::Value>,
+    entity: &str,
+) -> Vec<String> {
+    custom_attributes
+        .and_then(|defs| defs.get(entity))
+        .and_then(|defs| defs.as_array())
+        .map(|defs| {
+            defs.iter()
+                .filter_map(|def| def.get("name").and_then(|n| n.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Attribute values filled in from character/place templates:
+/// `"templates": [{ "attributes": [{ "name": ..., "value": ... }] }]`
+fn template_attribute_values(templates: Option<&serde_json::Value>) -> Vec<(String, String)> {
+    let Some(templates) = templates.and_then(|t| t.as_array()) else {
+        return Vec::new();
+    };
+    templates
+        .iter()
+        .filter_map(|template| template.get("attributes").and_then(|a| a.as_array()))
+        .flatten()
+        .filter_map(|attr| {
+            let name = attr.get("name")?.as_str()?;
+            let value = extract_attribute_value(attr.get("value")?)?;
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Collect a character's or place's custom attributes.
+///
+/// Declared attributes come first, then template attributes, then any other
+/// unrecognised fields (older files don't always declare them). Names not yet
+/// in `order` are appended to it so the import can keep Plottr's ordering.
+fn collect_custom_attributes(
+    fields: &HashMap<String, serde_json::Value>,
+    known_fields: &[&str],
+    declared: &[String],
+    order: &mut Vec<String>,
+) -> Vec<(String, String)> {
+    let mut attrs: Vec<(String, String)> = declared
+        .iter()
+        .filter_map(|name| {
+            let value = extract_attribute_value(fields.get(name)?)?;
+            Some((name.clone(), value))
+        })
+        .collect();
+
+    for (name, value) in template_attribute_values(fields.get("templates")) {
+        if !attrs.iter().any(|(n, _)| *n == name) {
+            attrs.push((name, value));
+        }
+    }
+
+    let mut undeclared: Vec<(&String, &serde_json::Value)> = fields
+        .iter()
+        .filter(|(key, _)| {
+            !known_fields.contains(&key.as_str())
+                && !declared.contains(key)
+                && !attrs.iter().any(|(n, _)| n == *key)
+        })
+        .collect();
+    undeclared.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, value) in undeclared {
+        if let Some(value) = extract_attribute_value(value) {
+            attrs.push((key.clone(), value));
+        }
+    }
+
+    for (name, _) in &attrs {
+        if !order.contains(name) {
+            order.push(name.clone());
+        }
+    }
+    attrs
+}
+
 /// Parse beats from either a simple array or nested structure
 /// Simple format (older Plottr): [{"id": 1, "title": "Act 1", "position": 0}, ...]
 /// Nested format (newer Plottr): {"1": {"children": {...}, "heap": {...}, "index": {beat_id: beat_data}}, "series": {...}}
@@ -318,7 +407,7 @@ fn parse_beats_from_structure(beats_value: &serde_json::Value) -> Vec<PlottrBeat
 
 pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, PlottrError> {
     let path = path.as_ref();
-    let content = fs::read_to_string(path)?;
+    let content = read_text_file(path)?;
     let plottr: PlottrFile = serde_json::from_str(&content)?;
 
     // Extract project name - prefer series name, fall back to filename
@@ -389,6 +478,8 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
         .collect();
 
     // Parse characters
+    let declared = declared_attribute_names(plottr.custom_attributes.as_ref(), "characters");
+    let mut character_attribute_names = declared.clone();
     let characters: Vec<Character> = plottr
         .characters
         .iter()
@@ -419,13 +510,12 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
             ];
 
             // Add custom attributes
-            for (key, value) in &pc.custom_attributes {
-                if !known_fields.contains(&key.as_str()) {
-                    if let Some(attr_value) = extract_attribute_value(value) {
-                        attrs.insert(key.clone(), attr_value);
-                    }
-                }
-            }
+            attrs.extend(collect_custom_attributes(
+                &pc.custom_attributes,
+                &known_fields,
+                &declared,
+                &mut character_attribute_names,
+            ));
 
             // Extract description (can be plain text or rich text)
             let description = pc.description.as_ref().and_then(extract_attribute_value);
@@ -449,6 +539,8 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
         .collect();
 
     // Parse locations (places)
+    let declared = declared_attribute_names(plottr.custom_attributes.as_ref(), "places");
+    let mut location_attribute_names = declared.clone();
     let locations: Vec<Location> = plottr
         .places
         .iter()
@@ -476,13 +568,12 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
                 "bookIds",
             ];
 
-            for (key, value) in &pp.custom_attributes {
-                if !known_fields.contains(&key.as_str()) {
-                    if let Some(attr_value) = extract_attribute_value(value) {
-                        attrs.insert(key.clone(), attr_value);
-                    }
-                }
-            }
+            attrs.extend(collect_custom_attributes(
+                &pp.custom_attributes,
+                &known_fields,
+                &declared,
+                &mut location_attribute_names,
+            ));
 
             let description = pp.description.as_ref().and_then(extract_attribute_value);
 
@@ -523,17 +614,54 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
             let mut sorted_cards = cards;
             sorted_cards.sort_by_key(|c| (c.position_within_line, c.position));
 
-            // Filter out cards with no description content (these are typically summary placeholders)
+            // Cards with no description content are typically summary placeholders
             // that appear on Plottr's "Summary" storyline with no actual scene content
-            let content_cards: Vec<_> = sorted_cards
-                .into_iter()
-                .filter(|card| {
+            let (content_cards, title_cards): (Vec<_>, Vec<_>) =
+                sorted_cards.into_iter().partition(|card| {
                     card.description
                         .as_ref()
                         .and_then(extract_text_from_rich_text)
                         .is_some_and(|s| !s.trim().is_empty())
-                })
-                .collect();
+                });
+
+            // A column holding only title cards is a beat sheet column (e.g. Save the
+            // Cat's "Catalyst"): its cards become the beats of one scene for the column
+            if content_cards.is_empty() {
+                let title_cards: Vec<_> = title_cards
+                    .into_iter()
+                    .filter(|card| !card.title.trim().is_empty())
+                    .collect();
+                if title_cards.is_empty() {
+                    continue;
+                }
+
+                let scene = Scene::new(chapter.id, chapter.title.clone(), None, 0)
+                    .with_source_id(Some(format!("beat-sheet:{}", beat_id_str)));
+                for (beat_idx, card) in title_cards.iter().enumerate() {
+                    let beat = Beat::new(scene.id, card.title.trim().to_string(), beat_idx as i32)
+                        .with_source_id(Some(value_to_string(&card.id)));
+                    beats.push(beat);
+
+                    for char_id in &card.characters {
+                        if let Some(character) = character_map.get(&value_to_string(char_id)) {
+                            let scene_ref = (scene.id, character.id);
+                            if !scene_character_refs.contains(&scene_ref) {
+                                scene_character_refs.push(scene_ref);
+                            }
+                        }
+                    }
+                    for place_id in &card.places {
+                        if let Some(location) = location_map.get(&value_to_string(place_id)) {
+                            let scene_ref = (scene.id, location.id);
+                            if !scene_location_refs.contains(&scene_ref) {
+                                scene_location_refs.push(scene_ref);
+                            }
+                        }
+                    }
+                }
+                scenes.push(scene);
+                continue;
+            }
 
             for (idx, card) in content_cards.iter().enumerate() {
                 // Extract paragraphs from description
@@ -581,6 +709,10 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
         }
     }
 
+    if let Some(message) = super::source_ids::find_duplicate_source_id(&chapters, &scenes, &beats) {
+        return Err(PlottrError::InvalidStructure(message));
+    }
+
     Ok(ParsedPlottr {
         project,
         chapters,
@@ -590,6 +722,8 @@ pub fn parse_plottr_file<P: AsRef<Path>>(path: P) -> Result<ParsedPlottr, Plottr
         locations,
         scene_character_refs,
         scene_location_refs,
+        character_attribute_names,
+        location_attribute_names,
     })
 }
 
@@ -755,6 +889,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_custom_attributes() {
+        let result = parse_plottr_file(fixture_path("custom-attributes.pltr")).unwrap();
+
+        let mara = result.characters.iter().find(|c| c.name == "Mara").unwrap();
+        assert_eq!(mara.attributes.get("Eye Color").unwrap(), "Green");
+        assert_eq!(mara.attributes.get("Occupation").unwrap(), "Cartographer");
+        assert_eq!(mara.attributes.get("Age").unwrap(), "34");
+        assert_eq!(mara.attributes.get("notes").unwrap(), "Keeps every draft.");
+        let tobin = result
+            .characters
+            .iter()
+            .find(|c| c.name == "Tobin")
+            .unwrap();
+        assert_eq!(tobin.attributes.len(), 1);
+
+        // Declared order first, then template attributes
+        assert_eq!(
+            result.character_attribute_names,
+            ["Eye Color", "Occupation", "Age"]
+        );
+
+        let archive = &result.locations[0];
+        assert_eq!(archive.attributes.get("Climate").unwrap(), "Dry and cold");
+        assert_eq!(result.location_attribute_names, ["Climate"]);
+    }
+
     #[test]
     fn test_parse_hamlet_scene_relationships() {
         let path = fixture_path("hamlet.pltr");
@@ -871,4 +1032,81 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_card_description_becomes_scene_synopsis() {
+        let parsed = parse_plottr_file(fixture_path("hamlet.pltr")).unwrap();
+
+        let scene = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "The guards see a ghost")
+            .expect("card becomes a scene");
+        let synopsis = scene
+            .synopsis
+            .as_deref()
+            .expect("synopsis from description");
+        let first_beat = parsed
+            .beats
+            .iter()
+            .find(|b| b.scene_id == scene.id && b.position == 0)
+            .unwrap();
+        assert_eq!(synopsis, first_beat.content);
+        assert!(!synopsis.trim().is_empty());
+    }
+
+    #[test]
+    fn test_beat_sheet_cards_become_beats() {
+        let parsed = parse_plottr_file(fixture_path("beat-sheet.pltr")).unwrap();
+        assert_eq!(parsed.chapters.len(), 2);
+
+        // A described card is still a scene, its description on the synopsis
+        let opening = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Storm over the harbor")
+            .unwrap();
+        assert_eq!(
+            opening.synopsis.as_deref(),
+            Some("Ada trims the lamp as the storm rolls in.")
+        );
+
+        // Title-only cards in the "Catalyst" column become beats of one scene
+        let catalyst = parsed
+            .chapters
+            .iter()
+            .find(|c| c.title == "Catalyst")
+            .unwrap();
+        let beat_sheet_scenes: Vec<_> = parsed
+            .scenes
+            .iter()
+            .filter(|s| s.chapter_id == catalyst.id)
+            .collect();
+        assert_eq!(beat_sheet_scenes.len(), 1);
+        let scene = beat_sheet_scenes[0];
+        assert_eq!(scene.title, "Catalyst");
+        assert_eq!(scene.synopsis, None);
+        assert_eq!(scene.source_id.as_deref(), Some("beat-sheet:2"));
+
+        let mut sheet_beats: Vec<_> = parsed
+            .beats
+            .iter()
+            .filter(|b| b.scene_id == scene.id)
+            .collect();
+        sheet_beats.sort_by_key(|b| b.position);
+        let contents: Vec<&str> = sheet_beats.iter().map(|b| b.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec!["The letter arrives", "Ada refuses the summons"]
+        );
+        assert!(sheet_beats.iter().all(|b| b.prose.is_none()));
+
+        // Character refs from both cards collapse into one scene reference
+        let ada_refs = parsed
+            .scene_character_refs
+            .iter()
+            .filter(|(scene_id, _)| *scene_id == scene.id)
+            .count();
+        assert_eq!(ada_refs, 1);
+    }
 }
@@ -16,6 +16,8 @@ use std::io::Cursor;
 use thiserror::Error;
 use uuid::Uuid;
 
+use super::encoding::read_text_file;
+
 #[derive(Debug, Error)]
 pub enum ScrivenerError {
     #[error("Failed to read file: {0}")]
@@ -41,6 +43,19 @@ pub struct BinderItem {
     pub include_in_compile: bool,
     pub children: Vec<BinderItem>,
     pub kindling_project_type: Option<String>,
+    /// Label assigned in the inspector (`<LabelID>`), resolved against `<LabelSettings>`
+    pub label: Option<ScrivxMetaOption>,
+    /// Status assigned in the inspector (`<StatusID>`), resolved against `<StatusSettings>`
+    pub status: Option<ScrivxMetaOption>,
+}
+
+/// A Label or Status entry defined in the project's settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrivxMetaOption {
+    pub id: String,
+    pub title: String,
+    /// Label color as `#RRGGBB` (statuses have no color)
+    pub color: Option<String>,
 }
 
 /// Parsed contents of a .scrivx file
@@ -48,6 +63,8 @@ pub struct BinderItem {
 pub struct ScrivxDocument {
     pub project_id: String,
     pub binder: Vec<BinderItem>,
+    pub labels: Vec<ScrivxMetaOption>,
+    pub statuses: Vec<ScrivxMetaOption>,
 }
 
 /// Parse a .scrivx XML string into a document tree
@@ -58,12 +75,33 @@ pub fn parse_scrivx(xml: &str) -> Result<ScrivxDocument, ScrivenerError> {
     let mut project_id = String::new();
     let mut binder_items: Vec<BinderItem> = Vec::new();
     let mut in_binder = false;
+    let mut labels: Vec<ScrivxMetaOption> = Vec::new();
+    let mut statuses: Vec<ScrivxMetaOption> = Vec::new();
+    // Label/Status definition whose text content is being read
+    let mut pending_option: Option<(bool, ScrivxMetaOption)> = None;
 
     loop {
         match reader.read_event() {
             Ok(Event::Start(ref e)) => {
                 let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                if name == "ScrivenerProject" {
+                if (name == "Label" || name == "Status") && !in_binder {
+                    let mut option = ScrivxMetaOption {
+                        id: String::new(),
+                        title: String::new(),
+                        color: None,
+                    };
+                    for attr in e.attributes().flatten() {
+                        match attr.key.as_ref() {
+                            b"ID" => option.id = String::from_utf8_lossy(&attr.value).to_string(),
+                            b"Color" => {
+                                option.color =
+                                    scrivener_color_to_hex(&String::from_utf8_lossy(&attr.value))
+                            }
+                            _ => {}
+                        }
+                    }
+                    pending_option = Some((name == "Label", option));
+                } else if name == "ScrivenerProject" {
                     for attr in e.attributes().flatten() {
                         if attr.key.as_ref() == b"Identifier" {
                             project_id = String::from_utf8_lossy(&attr.value).to_string();
@@ -76,9 +114,27 @@ pub fn parse_scrivx(xml: &str) -> Result<ScrivxDocument, ScrivenerError> {
                     binder_items.push(item);
                 }
             }
+            Ok(Event::Text(ref e)) => {
+                if let Some((_, ref mut option)) = pending_option {
+                    let raw = String::from_utf8_lossy(e);
+                    let text = unescape(&raw)
+                        .map(|c| c.to_string())
+                        .unwrap_or_else(|_| raw.to_string());
+                    option.title.push_str(&text);
+                }
+            }
             Ok(Event::End(ref e)) => {
-                if e.name().as_ref() == b"Binder" {
+                let name = e.name();
+                if name.as_ref() == b"Binder" {
                     in_binder = false;
+                } else if name.as_ref() == b"Label" || name.as_ref() == b"Status" {
+                    if let Some((is_label, option)) = pending_option.take() {
+                        if is_label {
+                            labels.push(option);
+                        } else {
+                            statuses.push(option);
+                        }
+                    }
                 }
             }
             Ok(Event::Eof) => break,
@@ -87,12 +143,110 @@ pub fn parse_scrivx(xml: &str) -> Result<ScrivxDocument, ScrivenerError> {
         }
     }
 
+    // Label/Status settings follow the binder, so resolve the IDs afterwards
+    resolve_meta_options(&mut binder_items, &labels, &statuses);
+
     Ok(ScrivxDocument {
         project_id,
         binder: binder_items,
+        labels,
+        statuses,
     })
 }
 
+/// Replace the bare `LabelID`/`StatusID` placeholders on each binder item with the
+/// matching settings entry. Unknown IDs and Scrivener's "-1" (No Label / No Status)
+/// resolve to `None`.
+fn resolve_meta_options(
+    items: &mut [BinderItem],
+    labels: &[ScrivxMetaOption],
+    statuses: &[ScrivxMetaOption],
+) {
+    let lookup = |placeholder: Option<ScrivxMetaOption>, options: &[ScrivxMetaOption]| {
+        placeholder
+            .filter(|p| p.id != "-1")
+            .and_then(|p| options.iter().find(|o| o.id == p.id).cloned())
+    };
+    for item in items {
+        item.label = lookup(item.label.take(), labels);
+        item.status = lookup(item.status.take(), statuses);
+        resolve_meta_options(&mut item.children, labels, statuses);
+    }
+}
+
+/// Convert a Scrivener label color (space-separated RGB floats in 0..=1, e.g.
+/// `"0.698039 0.556863 0.403922"`) to `#RRGGBB`.
+fn scrivener_color_to_hex(raw: &str) -> Option<String> {
+    let components: Vec<f32> = raw
+        .split_whitespace()
+        .map(|c| c.parse::<f32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    if components.len() < 3 {
+        return None;
+    }
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Some(format!(
+        "#{:02X}{:02X}{:02X}",
+        channel(components[0]),
+        channel(components[1]),
+        channel(components[2])
+    ))
+}
+
+/// Map a Scrivener Status title to the nearest Kindling [`SceneStatus`].
+///
+/// Scrivener statuses are user-editable, so matching is by whole word on the
+/// lowercased title (the defaults are To Do, In Progress, First Draft,
+/// Revised Draft, Final Draft, Done). Only whole words count, so "Incomplete"
+/// is not Final and "Unedited" is not Revised:
+///
+/// | Scrivener status has the word                                     | Kindling |
+/// |-------------------------------------------------------------------|----------|
+/// | final, done, complete(d), finished                                | Final    |
+/// | revise(d), revision, edit(ed/ing), rewrite/rewriting/rewritten,   | Revised  |
+/// | polish(ed/ing), or the phrase "second draft"                      |          |
+/// | anything else / no status                                         | Draft    |
+///
+/// [`SceneStatus`]: crate::models::SceneStatus
+pub fn scene_status_from_scrivener(status: Option<&str>) -> crate::models::SceneStatus {
+    use crate::models::SceneStatus;
+
+    const FINAL_WORDS: &[&str] = &["final", "done", "complete", "completed", "finished"];
+    const REVISED_WORDS: &[&str] = &[
+        "revise",
+        "revised",
+        "revision",
+        "revisions",
+        "edit",
+        "edited",
+        "editing",
+        "rewrite",
+        "rewriting",
+        "rewritten",
+        "polish",
+        "polished",
+        "polishing",
+    ];
+
+    let Some(title) = status.map(str::to_lowercase) else {
+        return SceneStatus::Draft;
+    };
+    let words: Vec<&str> = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if words.iter().any(|w| FINAL_WORDS.contains(w)) {
+        SceneStatus::Final
+    } else if words.iter().any(|w| REVISED_WORDS.contains(w))
+        || words.windows(2).any(|pair| pair == ["second", "draft"])
+    {
+        SceneStatus::Revised
+    } else {
+        SceneStatus::Draft
+    }
+}
+
 fn parse_binder_item(
     reader: &mut Reader<&[u8]>,
     start: &BytesStart,
@@ -116,6 +270,8 @@ fn parse_binder_item(
     let mut include_in_compile = true;
     let mut children: Vec<BinderItem> = Vec::new();
     let mut kindling_project_type: Option<String> = None;
+    let mut label_id: Option<String> = None;
+    let mut status_id: Option<String> = None;
     let mut current_element = String::new();
     let mut compile_buf = String::new();
 
@@ -143,6 +299,8 @@ fn parse_binder_item(
                             .get_or_insert_with(String::new)
                             .push_str(&text);
                     }
+                    "LabelID" => label_id.get_or_insert_with(String::new).push_str(&text),
+                    "StatusID" => status_id.get_or_insert_with(String::new).push_str(&text),
                     _ => {}
                 }
             }
@@ -174,6 +332,17 @@ fn parse_binder_item(
         include_in_compile,
         children,
         kindling_project_type,
+        // Titles and colors are filled in by `resolve_meta_options`
+        label: label_id.map(|id| ScrivxMetaOption {
+            id: id.trim().to_string(),
+            title: String::new(),
+            color: None,
+        }),
+        status: status_id.map(|id| ScrivxMetaOption {
+            id: id.trim().to_string(),
+            title: String::new(),
+            color: None,
+        }),
     })
 }
 
@@ -637,7 +806,7 @@ fn process_tag(tag: &str, rtf: &mut String, tags: &mut Vec<String>, first_paragr
     }
 }
 
-fn escape_rtf(text: &str) -> String {
+pub(crate) fn escape_rtf(text: &str) -> String {
     let mut result = String::with_capacity(text.len());
     for c in text.chars() {
         match c {
@@ -1047,6 +1216,8 @@ fn process_binder_children(
                         locked: false,
                         source_id: Some(child.uuid.clone()),
                         planning_status: Default::default(),
+                        color: None,
+                        word_target: None,
                     });
                     *position += 1;
 
@@ -1071,6 +1242,8 @@ fn process_binder_children(
                         locked: false,
                         source_id: Some(child.uuid.clone()),
                         planning_status: Default::default(),
+                        color: None,
+                        word_target: None,
                     };
 
                     let mut scene_pos: i32 = 0;
@@ -1097,9 +1270,18 @@ fn process_binder_children(
                                 archived: false,
                                 locked: false,
                                 scene_type: Default::default(),
-                                scene_status: Default::default(),
+                                scene_status: scene_status_from_scrivener(
+                                    scene_item.status.as_ref().map(|s| s.title.as_str()),
+                                ),
                                 planning_status: Default::default(),
                                 editor_mode: Default::default(),
+                                compile_position: None,
+                                include_in_compile: true,
+                                color: scene_item.label.as_ref().and_then(|l| l.color.clone()),
+                                pov_character_id: None,
+                                notes: None,
+                                word_target: None,
+                                custom_status: None,
                             });
                             scene_pos += 1;
                         }
@@ -1121,6 +1303,8 @@ fn process_binder_children(
                     locked: false,
                     source_id: Some(child.uuid.clone()),
                     planning_status: Default::default(),
+                    color: None,
+                    word_target: None,
                 };
 
                 let prose = read_rtf_content(data_dir, &child.uuid);
@@ -1144,9 +1328,18 @@ fn process_binder_children(
                     archived: false,
                     locked: false,
                     scene_type: Default::default(),
-                    scene_status: Default::default(),
+                    scene_status: scene_status_from_scrivener(
+                        child.status.as_ref().map(|s| s.title.as_str()),
+                    ),
                     planning_status: Default::default(),
                     editor_mode: Default::default(),
+                    compile_position: None,
+                    include_in_compile: true,
+                    color: child.label.as_ref().and_then(|l| l.color.clone()),
+                    pov_character_id: None,
+                    notes: None,
+                    word_target: None,
+                    custom_status: None,
                 });
 
                 chapters.push(chapter);
@@ -1165,7 +1358,7 @@ pub fn parse_scrivener_bundle(
 
     // Find and read the .scrivx file
     let scrivx_path = find_scrivx_in_bundle(scriv_path)?;
-    let xml = std::fs::read_to_string(&scrivx_path).map_err(ScrivenerError::IoError)?;
+    let xml = read_text_file(&scrivx_path).map_err(ScrivenerError::IoError)?;
     let doc = parse_scrivx(&xml)?;
 
     // Extract project name from the .scriv directory name
@@ -1254,7 +1447,7 @@ fn find_scrivx_in_bundle(
 fn read_rtf_content(data_dir: &std::path::Path, uuid: &str) -> Option<String> {
     let rtf_path = data_dir.join(uuid).join("content.rtf");
     if rtf_path.exists() {
-        if let Ok(rtf) = std::fs::read_to_string(&rtf_path) {
+        if let Ok(rtf) = read_text_file(&rtf_path) {
             let html = rtf_to_html(&rtf);
             if html.is_empty() {
                 None
@@ -1390,6 +1583,164 @@ mod tests {
         assert_eq!(doc.binder[0].children[0].children[0].uuid, "SC-1");
     }
 
+    const LABELED_SCRIVX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ScrivenerProject Identifier="LBL-1" Version="2.0">
+  <Binder>
+    <BinderItem UUID="DRAFT" Type="DraftFolder" Created="2024-01-01" Modified="2024-01-01">
+      <Title>Draft</Title>
+      <Children>
+        <BinderItem UUID="CH1" Type="Folder" Created="2024-01-01" Modified="2024-01-01">
+          <Title>Chapter One</Title>
+          <Children>
+            <BinderItem UUID="SC1" Type="Text" Created="2024-01-01" Modified="2024-01-01">
+              <Title>Labeled</Title>
+              <MetaData>
+                <IncludeInCompile>Yes</IncludeInCompile>
+                <LabelID>1</LabelID>
+                <StatusID>4</StatusID>
+              </MetaData>
+            </BinderItem>
+            <BinderItem UUID="SC2" Type="Text" Created="2024-01-01" Modified="2024-01-01">
+              <Title>Unlabeled</Title>
+              <MetaData>
+                <LabelID>-1</LabelID>
+                <StatusID>3</StatusID>
+              </MetaData>
+            </BinderItem>
+          </Children>
+        </BinderItem>
+      </Children>
+    </BinderItem>
+  </Binder>
+  <LabelSettings>
+    <Title>Label</Title>
+    <Labels>
+      <Label ID="-1">No Label</Label>
+      <Label ID="1" Color="1.0 0.666667 0.0">POV: Alice</Label>
+    </Labels>
+  </LabelSettings>
+  <StatusSettings>
+    <Title>Status</Title>
+    <StatusItems>
+      <Status ID="-1">No Status</Status>
+      <Status ID="2">First Draft</Status>
+      <Status ID="3">Revised Draft</Status>
+      <Status ID="4">Final Draft</Status>
+    </StatusItems>
+  </StatusSettings>
+</ScrivenerProject>"#;
+
+    #[test]
+    fn test_parse_scrivx_labels_and_status() {
+        let doc = parse_scrivx(LABELED_SCRIVX).unwrap();
+        assert_eq!(doc.labels.len(), 2);
+        assert_eq!(doc.statuses.len(), 4);
+
+        let scenes = &doc.binder[0].children[0].children;
+        assert_eq!(
+            scenes[0].label,
+            Some(ScrivxMetaOption {
+                id: "1".to_string(),
+                title: "POV: Alice".to_string(),
+                color: Some("#FFAA00".to_string()),
+            })
+        );
+        assert_eq!(
+            scenes[0].status.as_ref().map(|s| s.title.as_str()),
+            Some("Final Draft")
+        );
+        assert!(scenes[1].label.is_none(), "-1 means no label");
+        assert_eq!(
+            scenes[1].status.as_ref().map(|s| s.title.as_str()),
+            Some("Revised Draft")
+        );
+    }
+
+    #[test]
+    fn test_scene_status_from_scrivener() {
+        use crate::models::SceneStatus;
+
+        assert_eq!(scene_status_from_scrivener(None), SceneStatus::Draft);
+        assert_eq!(
+            scene_status_from_scrivener(Some("To Do")),
+            SceneStatus::Draft
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("First Draft")),
+            SceneStatus::Draft
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Revised Draft")),
+            SceneStatus::Revised
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Final Draft")),
+            SceneStatus::Final
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Done")),
+            SceneStatus::Final
+        );
+
+        // Custom statuses map by keyword, ignoring case and padding
+        assert_eq!(
+            scene_status_from_scrivener(Some("  SECOND DRAFT ")),
+            SceneStatus::Revised
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Needs Rewriting")),
+            SceneStatus::Revised
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Polished")),
+            SceneStatus::Revised
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Final Polish")),
+            SceneStatus::Final
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Complete")),
+            SceneStatus::Final
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Outline Only")),
+            SceneStatus::Draft
+        );
+        assert_eq!(scene_status_from_scrivener(Some("")), SceneStatus::Draft);
+
+        // Keywords only count as whole words
+        assert_eq!(
+            scene_status_from_scrivener(Some("Incomplete")),
+            SceneStatus::Draft
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Unedited")),
+            SceneStatus::Draft
+        );
+        assert_eq!(
+            scene_status_from_scrivener(Some("Edit-pass 2")),
+            SceneStatus::Revised
+        );
+    }
+
+    #[test]
+    fn test_parse_scrivener_bundle_maps_status_and_label_color() {
+        use crate::models::SceneStatus;
+
+        let dir = tempfile::tempdir().unwrap();
+        let scriv = dir.path().join("Labeled.scriv");
+        std::fs::create_dir_all(&scriv).unwrap();
+        std::fs::write(scriv.join("Labeled.scrivx"), LABELED_SCRIVX).unwrap();
+
+        let parsed = parse_scrivener_bundle(&scriv).unwrap();
+        assert_eq!(parsed.scenes.len(), 2);
+        assert_eq!(parsed.scenes[0].scene_status, SceneStatus::Final);
+        assert_eq!(parsed.scenes[1].scene_status, SceneStatus::Revised);
+        assert_eq!(parsed.scenes[0].color.as_deref(), Some("#FFAA00"));
+        assert_eq!(parsed.scenes[1].color, None);
+    }
+
     #[test]
     fn test_collect_text_documents() {
         let items = vec![BinderItem {
@@ -1400,6 +1751,8 @@ mod tests {
             modified: String::new(),
             include_in_compile: true,
             kindling_project_type: None,
+            label: None,
+            status: None,
             children: vec![
                 BinderItem {
                     uuid: "SC-1".to_string(),
@@ -1409,6 +1762,8 @@ mod tests {
                     modified: String::new(),
                     include_in_compile: true,
                     kindling_project_type: None,
+                    label: None,
+                    status: None,
                     children: vec![],
                 },
                 BinderItem {
@@ -1419,6 +1774,8 @@ mod tests {
                     modified: String::new(),
                     include_in_compile: true,
                     kindling_project_type: None,
+                    label: None,
+                    status: None,
                     children: vec![BinderItem {
                         uuid: "SC-2".to_string(),
                         item_type: "Text".to_string(),
@@ -1427,6 +1784,8 @@ mod tests {
                         modified: String::new(),
                         include_in_compile: true,
                         kindling_project_type: None,
+                        label: None,
+                        status: None,
                         children: vec![],
                     }],
                 },
@@ -0,0 +1,45 @@
+//! Source id validation for imports that support reimport (Plottr, yWriter)
+//!
+//! Reimport matches chapters, scenes and beats to existing rows by
+//! `source_id`. A source file that repeats an id would make that match
+//! ambiguous and let sync overwrite the wrong item, so such files are
+//! rejected at parse time instead.
+
+use std::collections::HashSet;
+
+use crate::models::{Beat, Chapter, Scene};
+
+/// Error message for a source id shared by two items of the same kind
+pub(crate) fn duplicate_source_id_message(kind: &str, source_id: &str) -> String {
+    format!(
+        "Duplicate {} ID \"{}\": the source file is malformed and cannot be imported safely",
+        kind, source_id
+    )
+}
+
+/// First source id used by more than one chapter, scene or beat
+fn first_duplicate<'a>(ids: impl Iterator<Item = Option<&'a String>>) -> Option<&'a str> {
+    let mut seen = HashSet::new();
+    ids.flatten()
+        .find(|id| !seen.insert(id.as_str()))
+        .map(String::as_str)
+}
+
+/// Describe the first source id shared by two chapters, two scenes or two
+/// beats, or `None` when every id is unique within its kind
+pub(crate) fn find_duplicate_source_id(
+    chapters: &[Chapter],
+    scenes: &[Scene],
+    beats: &[Beat],
+) -> Option<String> {
+    first_duplicate(chapters.iter().map(|c| c.source_id.as_ref()))
+        .map(|id| duplicate_source_id_message("chapter", id))
+        .or_else(|| {
+            first_duplicate(scenes.iter().map(|s| s.source_id.as_ref()))
+                .map(|id| duplicate_source_id_message("scene", id))
+        })
+        .or_else(|| {
+            first_duplicate(beats.iter().map(|b| b.source_id.as_ref()))
+                .map(|id| duplicate_source_id_message("beat", id))
+        })
+}
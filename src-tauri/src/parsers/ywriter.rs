@@ -12,7 +12,6 @@
 //! - yWriter Character → Kindling Character
 //! - yWriter Location → Kindling Location
 
-use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
 use quick_xml::escape::unescape;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -26,6 +25,8 @@ use crate::models::{
     SourceType,
 };
 
+use super::encoding::decode_content;
+
 #[derive(Debug, Error)]
 pub enum YWriterError {
     #[error("Failed to read file: {0}")]
@@ -49,6 +50,8 @@ struct YWriterProject {
     author: Option<String>,
     description: Option<String>,
     word_target: Option<i32>,
+    /// Labels for the four custom scene fields (`<FieldTitle1>`..`<FieldTitle4>`)
+    scene_field_titles: [Option<String>; 4],
 }
 
 #[derive(Debug, Default)]
@@ -88,9 +91,14 @@ struct YWriterScene {
     reaction_scene: bool,
     character_ids: Vec<i32>,
     location_ids: Vec<i32>,
+    viewpoint_character_id: Option<i32>,
+    notes: Option<String>,
     date: Option<String>,
     time: Option<String>,
     day: Option<String>,
+    /// Custom fields `<Field1>`..`<Field4>`
+    custom_fields: [Option<String>; 4],
+    word_target: Option<i32>,
 }
 
 /// Raw yWriter character data
@@ -99,6 +107,7 @@ struct YWriterCharacter {
     id: i32,
     title: String,
     full_name: Option<String>,
+    aka: Option<String>,
     description: Option<String>,
     bio: Option<String>,
     goals: Option<String>,
@@ -141,43 +150,12 @@ pub struct ParsedYWriter {
     pub reference_items: Vec<ReferenceItem>,
     pub scene_character_refs: Vec<(uuid::Uuid, uuid::Uuid)>,
     pub scene_location_refs: Vec<(uuid::Uuid, uuid::Uuid)>,
-}
-
-// ============================================================================
-// Encoding Detection
-// ============================================================================
-
-/// Detect the encoding of a byte slice by checking for BOM
-fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
-    // Check for UTF-16 BOM
-    if bytes.len() >= 2 {
-        if bytes[0] == 0xFF && bytes[1] == 0xFE {
-            return UTF_16LE;
-        }
-        if bytes[0] == 0xFE && bytes[1] == 0xFF {
-            return UTF_16BE;
-        }
-    }
-    // Check for UTF-8 BOM
-    if bytes.len() >= 3 && bytes[0] == 0xEF && bytes[1] == 0xBB && bytes[2] == 0xBF {
-        return UTF_8;
-    }
-    // Default to UTF-8
-    UTF_8
-}
-
-/// Decode bytes to string, handling various encodings
-fn decode_content(bytes: &[u8]) -> Result<String, YWriterError> {
-    let encoding = detect_encoding(bytes);
-
-    let (decoded, _, had_errors) = encoding.decode(bytes);
-    if had_errors {
-        return Err(YWriterError::EncodingError(
-            "Failed to decode file content".to_string(),
-        ));
-    }
-
-    Ok(decoded.into_owned())
+    /// Alternate names for characters as (character_id, alias) pairs
+    pub character_aliases: Vec<(uuid::Uuid, String)>,
+    /// Alternate names for locations as (location_id, alias) pairs
+    pub location_aliases: Vec<(uuid::Uuid, String)>,
+    /// Alternate names for items as (reference_item_id, alias) pairs
+    pub reference_item_aliases: Vec<(uuid::Uuid, String)>,
 }
 
 // ============================================================================
@@ -193,19 +171,36 @@ fn parse_id_list(s: &str) -> Vec<i32> {
 
 /// Convert yWriter markup to HTML
 /// yWriter uses: [i]italic[/i], [b]bold[/b]
+///
+/// Blank lines separate paragraphs and single newlines become `<br>`. Text
+/// with no blank lines at all is yWriter's one-line-per-paragraph layout, so
+/// each line becomes its own paragraph instead.
 pub fn convert_ywriter_markup(text: &str) -> String {
-    text.replace("[i]", "<em>")
+    let text = text
+        .replace("\r\n", "\n")
+        .replace("[i]", "<em>")
         .replace("[/i]", "</em>")
         .replace("[b]", "<strong>")
-        .replace("[/b]", "</strong>")
-        // Convert line breaks to HTML paragraphs
-        .split("\n\n")
+        .replace("[/b]", "</strong>");
+    let separator = if text.contains("\n\n") { "\n\n" } else { "\n" };
+    // Convert line breaks to HTML paragraphs
+    text.split(separator)
         .filter(|p| !p.trim().is_empty())
         .map(|p| format!("<p>{}</p>", p.trim().replace('\n', "<br>")))
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// Decode a numeric character reference name such as `#233` or `#x2014`
+fn resolve_char_ref(ref_name: &str) -> Option<char> {
+    let number = ref_name.strip_prefix('#')?;
+    let code = match number.strip_prefix(['x', 'X']) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => number.parse::<u32>().ok()?,
+    };
+    char::from_u32(code)
+}
+
 /// Get text content from current XML element
 fn read_element_text(
     reader: &mut Reader<&[u8]>,
@@ -228,22 +223,22 @@ fn read_element_text(
             Event::GeneralRef(e) => {
                 // Handle entity references like &amp; &lt; &gt; &quot; &apos;
                 let ref_name = String::from_utf8_lossy(&e).to_string();
-                let resolved = match ref_name.as_str() {
-                    "amp" => "&",
-                    "lt" => "<",
-                    "gt" => ">",
-                    "quot" => "\"",
-                    "apos" => "'",
-                    _ => {
-                        // For numeric character references or unknown entities,
-                        // try to pass through for now
-                        text.push('&');
-                        text.push_str(&ref_name);
-                        text.push(';');
-                        continue;
-                    }
-                };
-                text.push_str(resolved);
+                match ref_name.as_str() {
+                    "amp" => text.push('&'),
+                    "lt" => text.push('<'),
+                    "gt" => text.push('>'),
+                    "quot" => text.push('"'),
+                    "apos" => text.push('\''),
+                    _ => match resolve_char_ref(&ref_name) {
+                        Some(c) => text.push(c),
+                        None => {
+                            // Unknown or invalid entities pass through literally
+                            text.push('&');
+                            text.push_str(&ref_name);
+                            text.push(';');
+                        }
+                    },
+                }
             }
             Event::End(_) => break,
             Event::Eof => break,
@@ -262,7 +257,7 @@ fn read_element_text(
 pub fn parse_ywriter_file<P: AsRef<Path>>(path: P) -> Result<ParsedYWriter, YWriterError> {
     let path = path.as_ref();
     let bytes = fs::read(path)?;
-    let content = decode_content(&bytes)?;
+    let content = decode_content(&bytes).map_err(YWriterError::EncodingError)?;
 
     parse_ywriter_content(&content, path)
 }
@@ -344,6 +339,16 @@ fn parse_ywriter_content(content: &str, path: &Path) -> Result<ParsedYWriter, YW
                         let text = read_element_text(&mut reader, &mut buf)?;
                         project_data.word_target = text.parse().ok();
                     }
+                    "FieldTitle1" | "FieldTitle2" | "FieldTitle3" | "FieldTitle4"
+                        if in_project && current_scene.is_none() =>
+                    {
+                        let index = custom_field_index(&tag_name);
+                        let text = read_element_text(&mut reader, &mut buf)?;
+                        let title = text.trim();
+                        if !title.is_empty() {
+                            project_data.scene_field_titles[index] = Some(title.to_string());
+                        }
+                    }
                     // Project note fields
                     "ID" if current_project_note.is_some() => {
                         let text = read_element_text(&mut reader, &mut buf)?;
@@ -495,6 +500,12 @@ fn parse_ywriter_content(content: &str, path: &Path) -> Result<ParsedYWriter, YW
                             }
                         }
                     }
+                    "ViewpointCharacter" if current_scene.is_some() => {
+                        let text = read_element_text(&mut reader, &mut buf)?;
+                        if let Some(ref mut sc) = current_scene {
+                            sc.viewpoint_character_id = text.trim().parse().ok();
+                        }
+                    }
                     "Locations" if current_scene.is_some() => {
                         // Enter Locations block for this scene
                         // This block may contain <LocID> children OR semicolon-separated text
@@ -508,6 +519,28 @@ fn parse_ywriter_content(content: &str, path: &Path) -> Result<ParsedYWriter, YW
                             }
                         }
                     }
+                    "Notes" if current_scene.is_some() => {
+                        let text = read_element_text(&mut reader, &mut buf)?;
+                        if let Some(ref mut sc) = current_scene {
+                            sc.notes = Some(text);
+                        }
+                    }
+                    "WordTarget" if current_scene.is_some() => {
+                        let text = read_element_text(&mut reader, &mut buf)?;
+                        if let Some(ref mut sc) = current_scene {
+                            sc.word_target = text.trim().parse().ok().filter(|&t: &i32| t > 0);
+                        }
+                    }
+                    "Field1" | "Field2" | "Field3" | "Field4" if current_scene.is_some() => {
+                        let index = custom_field_index(&tag_name);
+                        let text = read_element_text(&mut reader, &mut buf)?;
+                        if let Some(ref mut sc) = current_scene {
+                            let value = text.trim();
+                            if !value.is_empty() {
+                                sc.custom_fields[index] = Some(value.to_string());
+                            }
+                        }
+                    }
                     "Items" if current_scene.is_some() => {
                         // Skip Items block
                     }
@@ -554,6 +587,12 @@ fn parse_ywriter_content(content: &str, path: &Path) -> Result<ParsedYWriter, YW
                             ch.full_name = Some(text);
                         }
                     }
+                    "Aka" | "AKA" if current_character.is_some() => {
+                        let text = read_element_text(&mut reader, &mut buf)?;
+                        if let Some(ref mut ch) = current_character {
+                            ch.aka = Some(text);
+                        }
+                    }
                     "Desc" if current_character.is_some() => {
                         let text = read_element_text(&mut reader, &mut buf)?;
                         if let Some(ref mut ch) = current_character {
@@ -657,6 +696,15 @@ fn parse_ywriter_content(content: &str, path: &Path) -> Result<ParsedYWriter, YW
                     }
                     "SCENE" => {
                         if let Some(sc) = current_scene.take() {
+                            // A repeated ID would silently replace the earlier scene
+                            if scenes.contains_key(&sc.id) {
+                                return Err(YWriterError::InvalidStructure(
+                                    super::source_ids::duplicate_source_id_message(
+                                        "scene",
+                                        &sc.id.to_string(),
+                                    ),
+                                ));
+                            }
                             scenes.insert(sc.id, sc);
                         }
                     }
@@ -732,6 +780,62 @@ fn parse_ywriter_content(content: &str, path: &Path) -> Result<ParsedYWriter, YW
 // Conversion to Kindling Model
 // ============================================================================
 
+/// Zero-based index of a `FieldN` or `FieldTitleN` tag
+fn custom_field_index(tag_name: &str) -> usize {
+    let digit = tag_name.chars().last().and_then(|c| c.to_digit(10));
+    digit.map_or(0, |n| n as usize - 1)
+}
+
+/// Scene notes followed by any custom fields as `Label: value` lines
+fn scene_notes(yw_scene: &YWriterScene, field_titles: &[Option<String>; 4]) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(notes) = yw_scene.notes.as_deref().map(str::trim) {
+        if !notes.is_empty() {
+            parts.push(notes.to_string());
+        }
+    }
+
+    let fields: Vec<String> = yw_scene
+        .custom_fields
+        .iter()
+        .zip(field_titles)
+        .enumerate()
+        .filter_map(|(i, (value, title))| {
+            let value = value.as_deref()?;
+            let label = title.clone().unwrap_or_else(|| format!("Field {}", i + 1));
+            Some(format!("{}: {}", label, value))
+        })
+        .collect();
+    if !fields.is_empty() {
+        parts.push(fields.join("\n"));
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("\n\n"))
+    }
+}
+
+/// Map a yWriter scene `<Status>` value to a Kindling [`SceneStatus`].
+///
+/// | yWriter          | Kindling  |
+/// |------------------|-----------|
+/// | 1 Outline        | Draft     |
+/// | 2 Draft          | Draft     |
+/// | 3 1st Edit       | Revised   |
+/// | 4 2nd Edit       | Revised   |
+/// | 5 Done           | Final     |
+///
+/// Missing or unrecognised values fall back to Draft.
+fn scene_status_from_ywriter(status: i32) -> SceneStatus {
+    match status {
+        3 | 4 => SceneStatus::Revised,
+        5 => SceneStatus::Final,
+        _ => SceneStatus::Draft,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn convert_to_kindling(
     project_data: YWriterProject,
@@ -811,6 +915,7 @@ fn convert_to_kindling(
 
     // Convert characters
     let mut kindling_characters: Vec<Character> = Vec::new();
+    let mut character_aliases: Vec<(uuid::Uuid, String)> = Vec::new();
     for (yw_id, yw_char) in &yw_characters {
         // Build a combined description from yWriter's description, bio, goals, and notes
         // Output as HTML for proper rendering in the References panel
@@ -850,12 +955,32 @@ fn convert_to_kindling(
             description,
             Some(yw_id.to_string()),
         );
+
+        // The short title doubles as an alias when a distinct full name is set
+        let mut aliases: Vec<String> = Vec::new();
+        let title = yw_char.title.trim();
+        if !title.is_empty() && !title.eq_ignore_ascii_case(character.name.trim()) {
+            aliases.push(title.to_string());
+        }
+        if let Some(ref aka) = yw_char.aka {
+            aliases.extend(split_aliases(aka));
+        }
+        for alias in aliases {
+            let duplicate = character_aliases
+                .iter()
+                .any(|(id, existing)| *id == character.id && existing.eq_ignore_ascii_case(&alias));
+            if !duplicate {
+                character_aliases.push((character.id, alias));
+            }
+        }
+
         yw_char_id_to_uuid.insert(*yw_id, character.id);
         kindling_characters.push(character);
     }
 
     // Convert locations
     let mut kindling_locations: Vec<Location> = Vec::new();
+    let mut location_aliases: Vec<(uuid::Uuid, String)> = Vec::new();
     for (yw_id, yw_loc) in &yw_locations {
         // Build location description as HTML for proper rendering
         let mut description_parts = Vec::new();
@@ -878,12 +1003,18 @@ fn convert_to_kindling(
             description,
             Some(yw_id.to_string()),
         );
+        if let Some(ref aka) = yw_loc.aka {
+            for alias in split_aliases(aka) {
+                location_aliases.push((location.id, alias));
+            }
+        }
         yw_loc_id_to_uuid.insert(*yw_id, location.id);
         kindling_locations.push(location);
     }
 
     // Convert items into generic reference items
     let mut reference_items: Vec<ReferenceItem> = Vec::new();
+    let mut reference_item_aliases: Vec<(uuid::Uuid, String)> = Vec::new();
     for (yw_id, yw_item) in &yw_items {
         let description = yw_item.description.as_ref().and_then(|desc| {
             if desc.trim().is_empty() {
@@ -894,12 +1025,6 @@ fn convert_to_kindling(
         });
 
         let mut attributes = HashMap::new();
-        if let Some(ref aka) = yw_item.aka {
-            let trimmed = aka.trim();
-            if !trimmed.is_empty() {
-                attributes.insert("aliases".to_string(), trimmed.to_string());
-            }
-        }
         if let Some(ref tags) = yw_item.tags {
             let trimmed = tags.trim();
             if !trimmed.is_empty() {
@@ -921,6 +1046,11 @@ fn convert_to_kindling(
             Some(yw_id.to_string()),
         )
         .with_attributes(attributes);
+        if let Some(ref aka) = yw_item.aka {
+            for alias in split_aliases(aka) {
+                reference_item_aliases.push((item.id, alias));
+            }
+        }
         reference_items.push(item);
     }
 
@@ -958,11 +1088,7 @@ fn convert_to_kindling(
                         _ => SceneType::Normal,
                     }
                 };
-                let scene_status = match yw_scene.status {
-                    3 | 4 => SceneStatus::Revised,
-                    5 => SceneStatus::Final,
-                    _ => SceneStatus::Draft,
-                };
+                let scene_status = scene_status_from_ywriter(yw_scene.status);
                 let mut scene = Scene::new(
                     chapter.id,
                     yw_scene.title.clone(),
@@ -972,6 +1098,11 @@ fn convert_to_kindling(
                 .with_source_id(Some(yw_scene_id.to_string()));
                 scene.scene_type = scene_type;
                 scene.scene_status = scene_status;
+                scene.pov_character_id = yw_scene
+                    .viewpoint_character_id
+                    .and_then(|id| yw_char_id_to_uuid.get(&id).copied());
+                scene.notes = scene_notes(yw_scene, &project_data.scene_field_titles);
+                scene.word_target = yw_scene.word_target;
 
                 yw_scene_id_to_uuid.insert(*yw_scene_id, scene.id);
 
@@ -1065,6 +1196,15 @@ fn convert_to_kindling(
         kindling_chapters.push(chapter);
     }
 
+    // Chapters listing the same scene would give two scenes one source id
+    if let Some(message) = super::source_ids::find_duplicate_source_id(
+        &kindling_chapters,
+        &kindling_scenes,
+        &kindling_beats,
+    ) {
+        return Err(YWriterError::InvalidStructure(message));
+    }
+
     Ok(ParsedYWriter {
         project,
         chapters: kindling_chapters,
@@ -1075,9 +1215,24 @@ fn convert_to_kindling(
         reference_items,
         scene_character_refs,
         scene_location_refs,
+        character_aliases,
+        location_aliases,
+        reference_item_aliases,
     })
 }
 
+/// Split a yWriter "Aka" value into individual aliases.
+///
+/// yWriter stores alternate names as free text, so commas and semicolons
+/// are treated as separators.
+fn split_aliases(raw: &str) -> Vec<String> {
+    raw.split([',', ';'])
+        .map(|alias| alias.trim())
+        .filter(|alias| !alias.is_empty())
+        .map(|alias| alias.to_string())
+        .collect()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1087,6 +1242,30 @@ mod tests {
     use super::*;
     use crate::models::{SceneStatus, SceneType};
 
+    #[test]
+    fn test_numeric_character_references_are_decoded() {
+        let xml = r#"<?xml version="1.0"?>
+<YWRITER7>
+  <PROJECT><Title>Caf&#233;</Title></PROJECT>
+  <SCENES>
+    <SCENE><ID>1</ID><Title>Arrival</Title>
+      <SceneContent>Caf&#233;s shut&#x2014;then&#X2014;opened.&#xD800;&bogus;</SceneContent>
+    </SCENE>
+  </SCENES>
+  <CHAPTERS>
+    <CHAPTER><ID>1</ID><Title>One</Title><Scenes>1</Scenes></CHAPTER>
+  </CHAPTERS>
+</YWRITER7>"#;
+
+        let parsed = parse_ywriter_content(xml, Path::new("cafe.yw7")).unwrap();
+
+        assert_eq!(parsed.project.name, "Café");
+        let prose = parsed.beats[0].prose.as_deref().unwrap();
+        assert!(prose.contains("Cafés shut—then—opened."), "{prose}");
+        // Surrogates are not characters; unknown names stay as written
+        assert!(prose.contains("&#xD800;&bogus;"), "{prose}");
+    }
+
     #[test]
     fn test_parse_id_list() {
         assert_eq!(parse_id_list("1;2;3"), vec![1, 2, 3]);
@@ -1110,32 +1289,17 @@ mod tests {
         assert!(result.contains("<p>Para two.</p>"));
 
         // Test line breaks within paragraph
-        let result = convert_ywriter_markup("Line one.\nLine two.");
-        assert!(result.contains("<br>"));
+        let result = convert_ywriter_markup("Line one.\nLine two.\n\nNext.");
+        assert!(result.contains("<p>Line one.<br>Line two.</p>"));
     }
 
     #[test]
-    fn test_detect_encoding_utf8() {
-        let bytes = b"Hello world";
-        assert_eq!(detect_encoding(bytes), UTF_8);
-    }
-
-    #[test]
-    fn test_detect_encoding_utf8_bom() {
-        let bytes = &[0xEF, 0xBB, 0xBF, b'H', b'i'];
-        assert_eq!(detect_encoding(bytes), UTF_8);
-    }
+    fn test_convert_ywriter_markup_single_newline_paragraphs() {
+        let result = convert_ywriter_markup("First para.\r\nSecond para.\nThird para.\n");
 
-    #[test]
-    fn test_detect_encoding_utf16le() {
-        let bytes = &[0xFF, 0xFE, 0x48, 0x00];
-        assert_eq!(detect_encoding(bytes), UTF_16LE);
-    }
-
-    #[test]
-    fn test_detect_encoding_utf16be() {
-        let bytes = &[0xFE, 0xFF, 0x00, 0x48];
-        assert_eq!(detect_encoding(bytes), UTF_16BE);
+        assert_eq!(result.matches("<p>").count(), 3);
+        assert!(result.contains("<p>Second para.</p>"));
+        assert!(!result.contains("<br>"));
     }
 
     #[test]
@@ -1171,6 +1335,10 @@ mod tests {
 
         // Check scenes
         assert_eq!(parsed.scenes.len(), 7);
+        assert!(parsed
+            .scenes
+            .iter()
+            .all(|s| s.scene_status == SceneStatus::Draft));
 
         // Check characters
         assert_eq!(parsed.characters.len(), 10);
@@ -1686,6 +1854,53 @@ mod tests {
         assert!(forest_desc.contains("<em>Also known as:</em> Sherwood"));
     }
 
+    #[test]
+    fn test_aliases_mapped_structurally() {
+        let xml = r#"<?xml version="1.0"?>
+<YWRITER7>
+  <PROJECT>
+    <Title>Alias Test</Title>
+  </PROJECT>
+  <LOCATIONS>
+    <LOCATION>
+      <ID>1</ID>
+      <Title>The Castle</Title>
+      <Aka>Fort Knox; The Keep</Aka>
+    </LOCATION>
+  </LOCATIONS>
+  <CHARACTERS>
+    <CHARACTER>
+      <ID>1</ID>
+      <Title>Lizzy</Title>
+      <FullName>Elizabeth Bennet</FullName>
+      <Aka>Eliza, lizzy</Aka>
+    </CHARACTER>
+  </CHARACTERS>
+</YWRITER7>"#;
+
+        let parsed = parse_ywriter_content(xml, Path::new("test.yw7")).unwrap();
+
+        let castle = &parsed.locations[0];
+        assert!(!castle.attributes.contains_key("aliases"));
+        assert_eq!(
+            parsed.location_aliases,
+            vec![
+                (castle.id, "Fort Knox".to_string()),
+                (castle.id, "The Keep".to_string())
+            ]
+        );
+
+        let character = &parsed.characters[0];
+        assert_eq!(character.name, "Elizabeth Bennet");
+        let aliases: Vec<&str> = parsed
+            .character_aliases
+            .iter()
+            .filter(|(id, _)| *id == character.id)
+            .map(|(_, alias)| alias.as_str())
+            .collect();
+        assert_eq!(aliases, vec!["Lizzy", "Eliza"]);
+    }
+
     #[test]
     fn test_item_fields_mapped_to_reference_attributes() {
         let xml = r#"<?xml version="1.0"?>
@@ -1715,8 +1930,8 @@ mod tests {
         let desc = item.description.as_ref().unwrap();
         assert!(desc.contains("<em>rusty</em>"));
         assert_eq!(
-            item.attributes.get("aliases").map(String::as_str),
-            Some("The Rust Key")
+            parsed.reference_item_aliases,
+            vec![(item.id, "The Rust Key".to_string())]
         );
         assert_eq!(
             item.attributes.get("tags").map(String::as_str),
@@ -1751,6 +1966,7 @@ mod tests {
       <Status>2</Status>
       <ReactionScene>0</ReactionScene>
       <Characters>1;2</Characters>
+      <ViewpointCharacter>2</ViewpointCharacter>
       <Locations>1</Locations>
     </SCENE>
   </SCENES>
@@ -1788,6 +2004,133 @@ mod tests {
 
         // All characters should still be imported
         assert_eq!(parsed.characters.len(), 3);
+
+        let bob = parsed.characters.iter().find(|c| c.name == "Bob").unwrap();
+        assert_eq!(parsed.scenes[0].pov_character_id, Some(bob.id));
+    }
+
+    #[test]
+    fn test_scene_notes_are_imported() {
+        let xml = r#"<?xml version="1.0"?>
+<YWRITER7>
+  <PROJECT>
+    <Title>Notes Test</Title>
+  </PROJECT>
+  <CHAPTERS>
+    <CHAPTER>
+      <ID>1</ID>
+      <SortOrder>1</SortOrder>
+      <Title>Chapter One</Title>
+      <Type>0</Type>
+      <Scenes>1;2</Scenes>
+    </CHAPTER>
+  </CHAPTERS>
+  <SCENES>
+    <SCENE>
+      <ID>1</ID>
+      <Title>Annotated</Title>
+      <Desc>The synopsis</Desc>
+      <Notes>Check the tide tables before revising.</Notes>
+    </SCENE>
+    <SCENE>
+      <ID>2</ID>
+      <Title>Blank Notes</Title>
+      <Notes>   </Notes>
+    </SCENE>
+  </SCENES>
+</YWRITER7>"#;
+
+        let parsed = parse_ywriter_content(xml, Path::new("test.yw7")).unwrap();
+        let annotated = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Annotated")
+            .unwrap();
+        assert_eq!(
+            annotated.notes.as_deref(),
+            Some("Check the tide tables before revising.")
+        );
+        // Notes stay separate from the synopsis
+        assert_eq!(annotated.synopsis.as_deref(), Some("The synopsis"));
+
+        let blank = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Blank Notes")
+            .unwrap();
+        assert_eq!(blank.notes, None);
+    }
+
+    #[test]
+    fn test_duplicate_scene_ids_are_rejected() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/duplicate-ids.yw7");
+        let err = parse_ywriter_file(&path).unwrap_err().to_string();
+        assert!(err.contains("Duplicate scene ID \"5\""), "got: {}", err);
+
+        // Two chapters listing the same scene would also share a source id
+        let xml = r#"<?xml version="1.0"?>
+<YWRITER7>
+  <PROJECT><Title>Shared</Title></PROJECT>
+  <CHAPTERS>
+    <CHAPTER><ID>1</ID><Title>One</Title><Type>0</Type><Scenes>5</Scenes></CHAPTER>
+    <CHAPTER><ID>2</ID><Title>Two</Title><Type>0</Type><Scenes>5</Scenes></CHAPTER>
+  </CHAPTERS>
+  <SCENES>
+    <SCENE><ID>5</ID><Title>Shared Scene</Title></SCENE>
+  </SCENES>
+</YWRITER7>"#;
+        let err = parse_ywriter_content(xml, Path::new("test.yw7"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Duplicate scene ID \"5\""), "got: {}", err);
+    }
+
+    #[test]
+    fn test_custom_scene_fields_are_imported() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/custom-fields.yw7");
+        let parsed = parse_ywriter_file(&path).unwrap();
+
+        let rated = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Rated Scene")
+            .unwrap();
+        // Field1 uses the project's label; Field3 has none and falls back to "Field 3"
+        assert_eq!(
+            rated.notes.as_deref(),
+            Some("Tighten the opening.\n\nTension: 4\nField 3: Night")
+        );
+
+        // Blank fields add nothing
+        let plain = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Plain Scene")
+            .unwrap();
+        assert_eq!(plain.notes, None);
+    }
+
+    #[test]
+    fn test_scene_word_target_is_imported() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/custom-fields.yw7");
+        let parsed = parse_ywriter_file(&path).unwrap();
+
+        let rated = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Rated Scene")
+            .unwrap();
+        assert_eq!(rated.word_target, Some(1500));
+        // The project word target is unaffected by scene targets
+        assert_eq!(parsed.project.word_target, None);
+
+        // A zero target means no goal
+        let plain = parsed
+            .scenes
+            .iter()
+            .find(|s| s.title == "Plain Scene")
+            .unwrap();
+        assert_eq!(plain.word_target, None);
     }
 
     #[test]
@@ -1844,6 +2187,51 @@ mod tests {
         assert_eq!(unused_scene.scene_status, SceneStatus::Final);
     }
 
+    #[test]
+    fn test_every_ywriter_status_is_mapped() {
+        let xml = r#"<?xml version="1.0"?>
+<YWRITER7>
+  <PROJECT>
+    <Title>Status Test</Title>
+  </PROJECT>
+  <CHAPTERS>
+    <CHAPTER>
+      <ID>1</ID>
+      <SortOrder>1</SortOrder>
+      <Title>Chapter One</Title>
+      <Type>0</Type>
+      <Scenes>1;2;3;4;5;6</Scenes>
+    </CHAPTER>
+  </CHAPTERS>
+  <SCENES>
+    <SCENE><ID>1</ID><Title>Outline</Title><Status>1</Status></SCENE>
+    <SCENE><ID>2</ID><Title>Draft</Title><Status>2</Status></SCENE>
+    <SCENE><ID>3</ID><Title>1st Edit</Title><Status>3</Status></SCENE>
+    <SCENE><ID>4</ID><Title>2nd Edit</Title><Status>4</Status></SCENE>
+    <SCENE><ID>5</ID><Title>Done</Title><Status>5</Status></SCENE>
+    <SCENE><ID>6</ID><Title>No Status</Title></SCENE>
+  </SCENES>
+</YWRITER7>"#;
+
+        let parsed = parse_ywriter_content(xml, Path::new("test.yw7")).unwrap();
+        let statuses: Vec<(&str, SceneStatus)> = parsed
+            .scenes
+            .iter()
+            .map(|s| (s.title.as_str(), s.scene_status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("Outline", SceneStatus::Draft),
+                ("Draft", SceneStatus::Draft),
+                ("1st Edit", SceneStatus::Revised),
+                ("2nd Edit", SceneStatus::Revised),
+                ("Done", SceneStatus::Final),
+                ("No Status", SceneStatus::Draft),
+            ]
+        );
+    }
+
     // ========================================================================
     // XML Entity & Encoding Tests
     // ========================================================================
@@ -8,6 +8,7 @@ import type {
   Chapter,
   Scene,
   Beat,
+  BeatRevision,
   Character,
   Location,
   ReferenceItem,
@@ -48,7 +49,11 @@ let fieldDefinitions: FieldDefinition[] = [];
 let fieldValues: FieldValue[] = [];
 let tags: Tag[] = [];
 let entityTags: EntityTag[] = [];
+let sceneTags: { scene_id: string; tag: string }[] = [];
 let savedFilters: SavedFilter[] = [];
+let beatRevisions: BeatRevision[] = [];
+/** Scenes archived along with their chapter, restored with it */
+const cascadeArchivedSceneIds = new Set<string>();
 
 let idCounter = 100;
 
@@ -56,6 +61,13 @@ function nextId(prefix: string): string {
   return `${prefix}-${idCounter++}-${Math.random().toString(36).slice(2, 9)}`;
 }
 
+function sceneTagsFor(sceneId: string): string[] {
+  return sceneTags
+    .filter((st) => st.scene_id === sceneId)
+    .map((st) => st.tag)
+    .sort((a, b) => a.toLowerCase().localeCompare(b.toLowerCase()));
+}
+
 function getArg<T>(args: Record<string, unknown>, ...keys: string[]): T | undefined {
   for (const k of keys) {
     const v = args[k];
@@ -87,6 +99,8 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     case "import_plottr":
     case "import_ywriter":
     case "import_markdown":
+    case "import_odt":
+    case "import_docx":
     case "import_longform":
     case "import_scrivener": {
       const path = getArg<string>(args, "path") ?? "/mock/path/story.pltr";
@@ -126,10 +140,36 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       return p as T;
     }
 
-    case "get_recent_projects":
+    case "get_recent_projects": {
+      const limit = getArg<number | null>(args, "limit") ?? 10;
+      return projects.slice(0, limit) as T;
+    }
+
     case "get_all_projects":
       return projects as T;
 
+    case "get_recent_project_infos": {
+      const limit = getArg<number | null>(args, "limit") ?? 10;
+      return projects.slice(0, limit).map((p) => {
+        const projectChapterIds = new Set(
+          chapters.filter((c) => c.project_id === p.id && !c.archived).map((c) => c.id)
+        );
+        const projectScenes = scenes.filter(
+          (s) => projectChapterIds.has(s.chapter_id) && !s.archived
+        );
+        const sceneIds = new Set(projectScenes.map((s) => s.id));
+        const wordCount = beats
+          .filter((b) => sceneIds.has(b.scene_id))
+          .reduce((sum, b) => sum + (b.prose?.split(/\s+/).filter(Boolean).length ?? 0), 0);
+        return {
+          project: p,
+          scene_count: projectScenes.length,
+          word_count: wordCount,
+          modified_relative: "just now",
+        };
+      }) as T;
+    }
+
     case "update_project_settings": {
       const settings = getArg<Partial<Project>>(args, "settings");
       if (!projectId || !settings) throw new Error("Missing projectId or settings");
@@ -167,13 +207,17 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       const title = getArg<string>(args, "title") ?? "New Chapter";
       const isPart = getArg<boolean>(args, "isPart", "is_part") ?? false;
       const afterId = getArg<string>(args, "afterId", "after_id");
+      const requested = getArg<number | null>(args, "position");
       if (!projectId) throw new Error("Missing projectId");
       const projectChapters = chapters.filter((c) => c.project_id === projectId && !c.archived);
       const maxPos = projectChapters.length
         ? Math.max(...projectChapters.map((c) => c.position))
         : -1;
       let position = maxPos + 1;
-      if (afterId) {
+      if (requested != null) {
+        position = Math.min(Math.max(requested, 0), maxPos + 1);
+        for (const c of projectChapters) if (c.position >= position) c.position += 1;
+      } else if (afterId) {
         const after = projectChapters.find((c) => c.id === afterId);
         if (after) position = after.position + 1;
       }
@@ -202,15 +246,21 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     case "create_scene": {
       const title = getArg<string>(args, "title") ?? "New Scene";
       if (!chapterId) throw new Error("Missing chapterId");
+      const requested = getArg<number | null>(args, "position");
       const chapterScenes = scenes.filter((s) => s.chapter_id === chapterId && !s.archived);
       const maxPos = chapterScenes.length ? Math.max(...chapterScenes.map((s) => s.position)) : -1;
+      let position = maxPos + 1;
+      if (requested != null) {
+        position = Math.min(Math.max(requested, 0), maxPos + 1);
+        for (const s of chapterScenes) if (s.position >= position) s.position += 1;
+      }
       const sc: Scene = {
         id: nextId("sc"),
         chapter_id: chapterId,
         title,
         synopsis: null,
         prose: null,
-        position: maxPos + 1,
+        position,
         source_id: null,
         archived: false,
         locked: false,
@@ -355,6 +405,25 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     case "get_characters":
       return characters.filter((c) => c.project_id === projectId) as T;
 
+    case "get_character_appearances":
+      // The mock does not track scene-character references
+      return [] as T;
+
+    case "find_orphaned_refs":
+      return [] as T;
+
+    case "get_chapter_status_summary":
+      return chapters
+        .filter((c) => c.project_id === projectId && !c.archived)
+        .sort((a, b) => a.position - b.position)
+        .map((c) => {
+          const counts = { draft: 0, revised: 0, final: 0 };
+          for (const s of scenes) {
+            if (s.chapter_id === c.id && !s.archived) counts[s.scene_status] += 1;
+          }
+          return { chapter_id: c.id, chapter_title: c.title, counts };
+        }) as T;
+
     case "get_locations":
       return locations.filter((l) => l.project_id === projectId) as T;
 
@@ -365,6 +434,9 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       return list as T;
     }
 
+    case "get_all_reference_items":
+      return referenceItems.filter((r) => r.project_id === projectId) as T;
+
     case "get_scene_reference_items": {
       const list = referenceItems.filter((r) => r.reference_type === referenceType);
       return list as T;
@@ -402,6 +474,10 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       }>(args, "reference");
       if (!projectId || !referenceType || !reference)
         throw new Error("Missing args for create_reference");
+      const project = projects.find((p) => p.id === projectId);
+      const enabled = project?.reference_types ?? [];
+      if (project && !enabled.includes(referenceType as ReferenceItem["reference_type"]))
+        throw new Error(`Reference type '${referenceType}' is not enabled for this project`);
       const newRef: ReferenceItem = {
         id: nextId("ref"),
         project_id: projectId,
@@ -451,12 +527,44 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
 
     case "save_beat_prose": {
       const prose = getArg<string>(args, "prose");
+      const recordRevision = getArg<boolean>(args, "recordRevision", "record_revision");
       if (!beatId) throw new Error("Missing beatId");
       const b = beats.find((x) => x.id === beatId);
+      if (b && recordRevision && b.prose && b.prose !== prose) {
+        beatRevisions.unshift({
+          id: nextId("rev"),
+          beat_id: b.id,
+          prose: b.prose,
+          created_at: new Date().toISOString(),
+        });
+      }
       if (b) b.prose = prose ?? null;
       return undefined as T;
     }
 
+    case "get_beat_revisions": {
+      if (!beatId) throw new Error("Missing beatId");
+      return beatRevisions.filter((r) => r.beat_id === beatId) as T;
+    }
+
+    case "restore_beat_revision": {
+      const revisionId = getArg<string>(args, "revisionId", "revision_id");
+      const rev = beatRevisions.find((r) => r.id === revisionId);
+      if (!rev) throw new Error("Revision not found");
+      const b = beats.find((x) => x.id === rev.beat_id);
+      if (!b) throw new Error("Beat not found");
+      if (b.prose && b.prose !== rev.prose) {
+        beatRevisions.unshift({
+          id: nextId("rev"),
+          beat_id: b.id,
+          prose: b.prose,
+          created_at: new Date().toISOString(),
+        });
+      }
+      b.prose = rev.prose;
+      return { ...b } as T;
+    }
+
     case "save_scene_synopsis": {
       const synopsis = getArg<string | null>(args, "synopsis");
       if (!sceneId) throw new Error("Missing sceneId");
@@ -465,14 +573,69 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       return undefined as T;
     }
 
+    case "update_scene_notes": {
+      const notes = getArg<string | null>(args, "notes");
+      if (!sceneId) throw new Error("Missing sceneId");
+      const s = scenes.find((x) => x.id === sceneId);
+      if (s) s.notes = notes ?? null;
+      return undefined as T;
+    }
+
     case "update_scene_metadata": {
       const metadata = getArg<{ scene_type?: string; scene_status?: string }>(args, "metadata");
       if (!sceneId || !metadata) return undefined as T;
       const s = scenes.find((x) => x.id === sceneId);
       if (s) {
         if (metadata.scene_type) s.scene_type = metadata.scene_type as Scene["scene_type"];
-        if (metadata.scene_status) s.scene_status = metadata.scene_status as Scene["scene_status"];
+        if (metadata.scene_status) {
+          const builtin = ["draft", "revised", "final"].includes(metadata.scene_status);
+          s.scene_status = (builtin ? metadata.scene_status : "draft") as Scene["scene_status"];
+          s.custom_status = builtin ? null : metadata.scene_status;
+        }
+      }
+      return undefined as T;
+    }
+
+    case "update_scene_compile_position": {
+      const compilePosition = getArg<number | null>(args, "compilePosition", "compile_position");
+      const s = scenes.find((x) => x.id === sceneId);
+      if (s) s.compile_position = compilePosition ?? null;
+      return undefined as T;
+    }
+
+    case "set_scene_compile_inclusion": {
+      const include = getArg<boolean>(args, "includeInCompile", "include_in_compile");
+      const s = scenes.find((x) => x.id === sceneId);
+      if (s) s.include_in_compile = include ?? true;
+      return undefined as T;
+    }
+
+    case "set_chapter_word_target": {
+      const wordTarget = getArg<number | null>(args, "wordTarget", "word_target");
+      const ch = chapters.find((c) => c.id === chapterId);
+      if (ch) ch.word_target = wordTarget ?? null;
+      return undefined as T;
+    }
+
+    case "set_chapter_color":
+    case "set_scene_color": {
+      const color = getArg<string | null>(args, "color");
+      if (color != null && !/^#[0-9a-fA-F]{6}$/.test(color.trim())) {
+        throw new Error(`Invalid color '${color}': expected a hex color like #FFAA00`);
       }
+      const normalized = color == null ? null : color.trim().toUpperCase();
+      const target =
+        cmd === "set_chapter_color"
+          ? chapters.find((c) => c.id === chapterId)
+          : scenes.find((x) => x.id === sceneId);
+      if (target) target.color = normalized;
+      return undefined as T;
+    }
+
+    case "set_scene_pov": {
+      const characterId = getArg<string | null>(args, "characterId", "character_id");
+      const s = scenes.find((x) => x.id === sceneId);
+      if (s) s.pov_character_id = characterId ?? null;
       return undefined as T;
     }
 
@@ -500,7 +663,21 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     }
 
     case "move_scene_to_chapter":
+    case "move_and_reorder_scene":
+      return undefined as T;
+
+    case "move_chapter": {
+      const newPosition = getArg<number>(args, "newPosition", "new_position") ?? 0;
+      const ordered = chapters
+        .filter((c) => c.project_id === projectId)
+        .sort((a, b) => a.position - b.position);
+      const from = ordered.findIndex((c) => c.id === chapterId);
+      if (from === -1) throw new Error("Chapter not found");
+      const [moved] = ordered.splice(from, 1);
+      ordered.splice(Math.max(0, Math.min(newPosition, ordered.length)), 0, moved);
+      ordered.forEach((c, i) => (c.position = i));
       return undefined as T;
+    }
 
     case "get_chapter_content_counts": {
       if (!chapterId) throw new Error("Missing chapterId");
@@ -553,6 +730,23 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       return sc as T;
     }
 
+    case "consolidate_scene_prose": {
+      if (!sceneId) throw new Error("Missing sceneId");
+      const clearBeats = getArg<boolean>(args, "clearBeats", "clear_beats") ?? false;
+      const sc = scenes.find((s) => s.id === sceneId);
+      if (!sc) throw new Error(`Scene not found: ${sceneId}`);
+      const sceneBeats = beats.filter((b) => b.scene_id === sceneId);
+      sceneBeats.sort((a, b) => a.position - b.position);
+      sc.prose = sceneBeats
+        .map((b) => b.prose ?? "")
+        .filter((p) => p.trim() !== "")
+        .join("\n");
+      if (clearBeats) {
+        beats = beats.filter((b) => b.scene_id !== sceneId);
+      }
+      return sc as T;
+    }
+
     case "save_scene_page_prose": {
       if (!sceneId) throw new Error("Missing sceneId");
       const prose = getArg<string>(args, "prose") ?? "";
@@ -572,11 +766,19 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
         beats_added: 0,
         beats_updated: 0,
         prose_preserved: 0,
+        items_skipped: 0,
+        scenes_archived: 0,
+        items: [],
       } as T;
     }
 
     case "get_sync_preview":
-      return { additions: [], changes: [] } as T;
+      return {
+        additions: [],
+        changes: [],
+        strategy: getArg<string>(args, "strategy") ?? "prefer_source",
+        conflicts: [],
+      } as T;
 
     case "apply_sync": {
       const proj = projects.find((p) => p.id === projectId);
@@ -589,6 +791,9 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
         beats_added: 0,
         beats_updated: 0,
         prose_preserved: 0,
+        items_skipped: 0,
+        scenes_archived: 0,
+        items: [],
       } as T;
     }
 
@@ -673,6 +878,7 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     case "archive_scene": {
       const sc = scenes.find((s) => s.id === sceneId);
       if (sc) sc.archived = true;
+      if (sceneId) cascadeArchivedSceneIds.delete(sceneId);
       return undefined as T;
     }
 
@@ -687,9 +893,32 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       const sc = scenes.find((s) => s.id === sceneId);
       if (!sc) throw new Error("Scene not found");
       sc.archived = false;
+      cascadeArchivedSceneIds.delete(sc.id);
       return sc as T;
     }
 
+    case "archive_chapter_cascade": {
+      const ch = chapters.find((c) => c.id === chapterId);
+      if (ch) ch.archived = true;
+      for (const sc of scenes.filter((s) => s.chapter_id === chapterId && !s.archived)) {
+        sc.archived = true;
+        cascadeArchivedSceneIds.add(sc.id);
+      }
+      return undefined as T;
+    }
+
+    case "restore_chapter_cascade": {
+      const ch = chapters.find((c) => c.id === chapterId);
+      if (!ch) throw new Error("Chapter not found");
+      ch.archived = false;
+      for (const sc of scenes.filter((s) => cascadeArchivedSceneIds.has(s.id))) {
+        if (sc.chapter_id !== chapterId) continue;
+        sc.archived = false;
+        cascadeArchivedSceneIds.delete(sc.id);
+      }
+      return ch as T;
+    }
+
     case "get_archived_items": {
       const archCh = chapters.filter((c) => c.project_id === projectId && c.archived);
       const archSc = scenes.filter((s) => {
@@ -735,6 +964,7 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     case "export_to_docx":
     case "export_to_epub":
     case "generate_treatment":
+    case "export_outline":
     case "preview_scrivener_matches":
       return [] as T;
 
@@ -767,10 +997,12 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
 
     case "create_snapshot": {
       if (!projectId) throw new Error("Missing projectId");
-      const options = getArg<{ name: string; description?: string; trigger_type?: string }>(
-        args,
-        "options"
-      );
+      const options = getArg<{
+        name: string;
+        description?: string;
+        trigger_type?: string;
+        compression?: string;
+      }>(args, "options");
       const name = options?.name ?? "Snapshot";
       const proj = projects.find((p) => p.id === projectId);
       if (!proj) throw new Error(`Project not found: ${projectId}`);
@@ -798,6 +1030,8 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
         beat_count: beatCount,
         word_count: 0,
         schema_version: 1,
+        compression: (options?.compression as SnapshotMetadata["compression"]) ?? "gzip",
+        checksum: null,
       };
       snapshots.push(meta);
       return meta as T;
@@ -806,15 +1040,76 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
     case "list_snapshots":
       return snapshots.filter((s) => s.project_id === projectId) as T;
 
+    case "get_snapshot_history":
+      return snapshots
+        .filter((s) => s.project_id === projectId)
+        .sort((a, b) => a.created_at.localeCompare(b.created_at))
+        .map((s) => ({
+          snapshot_id: s.id,
+          created_at: s.created_at,
+          word_count: s.word_count,
+          chapter_count: s.chapter_count,
+          scene_count: s.scene_count,
+        })) as T;
+
     case "delete_snapshot": {
       snapshots = snapshots.filter((s) => s.id !== snapshotId);
       return undefined as T;
     }
 
+    case "prune_snapshots": {
+      const policy = getArg<{ keep_last_n?: number | null; keep_days?: number | null }>(
+        args,
+        "policy"
+      );
+      if (policy?.keep_last_n == null && policy?.keep_days == null) {
+        return { pruned: 0, bytes_reclaimed: 0 } as T;
+      }
+      const cutoff =
+        policy.keep_days != null ? Date.now() - policy.keep_days * 24 * 60 * 60 * 1000 : null;
+      const newest = snapshots
+        .filter((s) => s.project_id === projectId)
+        .sort((a, b) => b.created_at.localeCompare(a.created_at))
+        .slice(0, policy.keep_last_n ?? 0)
+        .map((s) => s.id);
+      const pruned = snapshots.filter(
+        (s) =>
+          s.project_id === projectId &&
+          s.trigger_type !== "manual" &&
+          !newest.includes(s.id) &&
+          (cutoff === null || Date.parse(s.created_at) < cutoff)
+      );
+      snapshots = snapshots.filter((s) => !pruned.includes(s));
+      return {
+        pruned: pruned.length,
+        bytes_reclaimed: pruned.reduce((sum, s) => sum + s.file_size, 0),
+      } as T;
+    }
+
     case "restore_snapshot": {
-      const proj = projects.find((p) => p.id === projectId);
+      const snap = snapshots.find((s) => s.id === snapshotId);
+      const proj = projects.find((p) => p.id === (snap?.project_id ?? projectId));
       if (!proj) throw new Error("Project not found");
-      return proj as T;
+      const options = getArg<{ confirm?: boolean }>(args, "options");
+      const preview = {
+        overwrites_current: true,
+        current_chapter_count: snap?.chapter_count ?? 0,
+        current_scene_count: snap?.scene_count ?? 0,
+        current_beat_count: snap?.beat_count ?? 0,
+        current_word_count: snap?.word_count ?? 0,
+        snapshot_chapter_count: snap?.chapter_count ?? 0,
+        snapshot_scene_count: snap?.scene_count ?? 0,
+        snapshot_beat_count: snap?.beat_count ?? 0,
+        snapshot_word_count: snap?.word_count ?? 0,
+        warnings: [],
+      };
+      const applied = options?.confirm === true;
+      return {
+        applied,
+        preview,
+        project: applied ? proj : null,
+        safety_snapshot: null,
+      } as T;
     }
 
     case "preview_snapshot": {
@@ -827,9 +1122,38 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       } as T;
     }
 
+    case "diff_snapshot": {
+      const snap = snapshots.find((s) => s.id === snapshotId);
+      if (!snap) throw new Error("Snapshot not found");
+      return {
+        snapshot_id: snap.id,
+        snapshot_word_count: snap.word_count ?? 0,
+        current_word_count: snap.word_count ?? 0,
+        word_delta: 0,
+        items: [],
+      } as T;
+    }
+
     case "get_app_settings":
       return appSettings as T;
 
+    case "get_database_path":
+      return "/mock/kindling.db" as T;
+
+    case "backup_database": {
+      const destination = getArg<string>(args, "destination") ?? "/mock/kindling-backup.db";
+      return { path: destination, size_bytes: 0 } as T;
+    }
+
+    case "export_project_archive":
+      return (getArg<string>(args, "outputPath", "output_path") ?? "/mock/project.kindling") as T;
+
+    case "import_project_archive": {
+      void getArg<string>(args, "path");
+      const project = projects[0]!;
+      return { ...project, id: crypto.randomUUID(), created_at: new Date().toISOString() } as T;
+    }
+
     case "update_app_settings": {
       const settings = getArg<Partial<AppSettings>>(args, "settings");
       if (settings) appSettings = { ...appSettings, ...settings };
@@ -1062,6 +1386,39 @@ export async function invoke<T>(cmd: string, args: Record<string, unknown> = {})
       }) as T;
     }
 
+    case "add_scene_tag": {
+      if (!sceneId) throw new Error("Missing sceneId");
+      const tag = (getArg<string>(args, "tag") ?? "").trim();
+      if (!tag) throw new Error("Tag cannot be empty");
+      const key = tag.toLowerCase();
+      if (!sceneTags.some((st) => st.scene_id === sceneId && st.tag.toLowerCase() === key)) {
+        sceneTags.push({ scene_id: sceneId, tag });
+      }
+      return sceneTagsFor(sceneId) as T;
+    }
+
+    case "remove_scene_tag": {
+      if (!sceneId) throw new Error("Missing sceneId");
+      const key = (getArg<string>(args, "tag") ?? "").trim().toLowerCase();
+      sceneTags = sceneTags.filter(
+        (st) => !(st.scene_id === sceneId && st.tag.toLowerCase() === key)
+      );
+      return sceneTagsFor(sceneId) as T;
+    }
+
+    case "get_scene_tags": {
+      if (!sceneId) throw new Error("Missing sceneId");
+      return sceneTagsFor(sceneId) as T;
+    }
+
+    case "get_scenes_by_tag": {
+      const key = (getArg<string>(args, "tag") ?? "").trim().toLowerCase();
+      const tagged = new Set(
+        sceneTags.filter((st) => st.tag.toLowerCase() === key).map((st) => st.scene_id)
+      );
+      return scenes.filter((s) => tagged.has(s.id)).map((s) => s.id) as T;
+    }
+
     case "filter_entities": {
       const entType = getArg<string>(args, "entityType", "entity_type") ?? "";
       const filterJson = getArg<string>(args, "filterJson", "filter_json") ?? "{}";
@@ -8,7 +8,16 @@
  */
 
 /** Supported outline import formats */
-export type SourceType = "Plottr" | "Markdown" | "YWriter" | "Scrivener" | "Longform" | "Blank";
+export type SourceType =
+  | "Plottr"
+  | "Markdown"
+  | "YWriter"
+  | "Scrivener"
+  | "Longform"
+  | "Fountain"
+  | "Odt"
+  | "Docx"
+  | "Blank";
 
 /** Project type: novel (default) or screenplay */
 export type ProjectType = "novel" | "screenplay";
@@ -50,6 +59,27 @@ export interface Project {
   project_type: ProjectType;
   /** Target page count (screenplay, ~250 words/page) */
   target_page_count: number | null;
+  /** Custom scene workflow stages offered alongside the built-in statuses */
+  scene_statuses?: string[];
+  /** Publisher or imprint (title page and EPUB metadata) */
+  publisher?: string | null;
+  /** ISBN (title page and EPUB identifier) */
+  isbn?: string | null;
+  /** Series this book belongs to */
+  series_name?: string | null;
+  /** Position in the series, e.g. 2 for "Book 2 of ..." */
+  series_index?: number | null;
+}
+
+/** A recently opened project with the summary shown on the start screen */
+export interface RecentProjectInfo {
+  project: Project;
+  /** Scenes outside the archive */
+  scene_count: number;
+  /** Word count read from the project's cached count */
+  word_count: number;
+  /** How long ago the project was modified, e.g. "3 days ago" */
+  modified_relative: string;
 }
 
 /** App-wide settings (stored in JSON file, not database) */
@@ -64,6 +94,10 @@ export interface AppSettings {
   contact_phone: string | null;
   /** Email address */
   contact_email: string | null;
+  /** Author website */
+  website?: string | null;
+  /** Take an automatic snapshot after this many new words (null or 0 disables) */
+  auto_snapshot_word_threshold?: number | null;
 }
 
 /** A chapter groups related scenes together */
@@ -85,6 +119,10 @@ export interface Chapter {
   synopsis: string | null;
   /** Rolling outline planning status */
   planning_status: PlanningStatus;
+  /** Color label as a `#RRGGBB` hex string */
+  color?: string | null;
+  /** Word-count goal for this chapter */
+  word_target?: number | null;
 }
 
 /** A scene is the primary unit of writing, containing beats and prose */
@@ -108,6 +146,20 @@ export interface Scene {
   planning_status: PlanningStatus;
   /** Editor mode: beat cards or full-page prose */
   editor_mode: EditorMode;
+  /** Order in compiled output; null follows `position` */
+  compile_position?: number | null;
+  /** False for notes/research scenes left out of exports and word counts */
+  include_in_compile?: boolean;
+  /** Color label as a `#RRGGBB` hex string */
+  color?: string | null;
+  /** Character whose point of view the scene is told from */
+  pov_character_id?: string | null;
+  /** Private author notes; never exported */
+  notes?: string | null;
+  /** Word-count goal for this scene */
+  word_target?: number | null;
+  /** Custom status from the project's `scene_statuses`; `scene_status` reads "draft" while set */
+  custom_status?: string | null;
 }
 
 export type EditorMode = "beat" | "page";
@@ -122,6 +174,18 @@ export interface ArchivedItems {
   scenes: Scene[];
 }
 
+/** Container for items in the trash (permanently deleted when the trash is emptied) */
+export interface TrashedItems {
+  chapters: Chapter[];
+  scenes: Scene[];
+}
+
+/** Counts of items permanently removed by empty_trash */
+export interface EmptyTrashResult {
+  chapters_deleted: number;
+  scenes_deleted: number;
+}
+
 /**
  * A beat is a story point within a scene.
  * Writers expand beats to write prose beneath them.
@@ -137,6 +201,14 @@ export interface Beat {
   position: number;
 }
 
+/** An earlier version of a beat's prose, newest first when listed */
+export interface BeatRevision {
+  id: string;
+  beat_id: string;
+  prose: string;
+  created_at: string;
+}
+
 /** Inline discovery note for capturing ideas while writing */
 export interface DiscoveryNote {
   id: string;
@@ -158,6 +230,14 @@ export interface Character {
   source_id: string | null;
 }
 
+/** A scene a character appears in, listed in manuscript order */
+export interface CharacterAppearance {
+  scene_id: string;
+  scene_title: string;
+  chapter_id: string;
+  chapter_title: string;
+}
+
 /** A location reference card shown in the References panel */
 export interface Location {
   id: string;
@@ -213,6 +293,13 @@ export interface ReferenceSuggestion {
   confidence: number;
 }
 
+/** Number of scene references created by `auto_link_references`, per entity type */
+export interface AutoLinkCounts {
+  characters: number;
+  locations: number;
+  reference_items: number;
+}
+
 /** Supported reference types for the References panel */
 export type ReferenceTypeId = "characters" | "locations" | "items" | "objectives" | "organizations";
 
@@ -273,6 +360,13 @@ export interface SceneReferenceState {
   expanded: boolean;
 }
 
+/** A scene reference whose character, location or reference item no longer exists */
+export interface OrphanedRef {
+  scene_id: string;
+  reference_type: "characters" | "locations" | "reference_items";
+  reference_id: string;
+}
+
 export interface SceneReferenceStateUpdate {
   reference_id: string;
   position: number;
@@ -310,7 +404,8 @@ export interface SyncAddition {
 export interface SyncChange {
   id: string;
   item_type: "chapter" | "scene" | "beat";
-  field: "title" | "synopsis" | "content";
+  /** "missing" means the scene was removed from the source and will be archived */
+  field: "title" | "synopsis" | "content" | "missing";
   item_title: string;
   current_value: string;
   new_value: string;
@@ -318,10 +413,27 @@ export interface SyncChange {
   db_id: string;
 }
 
+/** How apply_sync resolves existing items whose source version changed */
+export type SyncStrategy = "prefer_source" | "prefer_local" | "keep_both";
+
+/** What a sync strategy will do to a changed item */
+export type SyncConflictAction = "use_source" | "keep_local" | "archive_and_add";
+
+/** An existing scene or beat whose source version differs from the local one */
+export interface SyncConflict {
+  db_id: string;
+  item_type: "scene" | "beat";
+  item_title: string;
+  action: SyncConflictAction;
+}
+
 /** Preview of changes that would occur during a sync operation */
 export interface SyncPreview {
   additions: SyncAddition[];
   changes: SyncChange[];
+  /** Strategy the conflict actions were computed for */
+  strategy: SyncStrategy;
+  conflicts: SyncConflict[];
 }
 
 /** Summary statistics after a reimport operation completes */
@@ -336,6 +448,30 @@ export interface ReimportSummary {
   prose_preserved: number;
 }
 
+/** What apply_sync did to one chapter, scene or beat */
+export type SyncAction =
+  | { kind: "created" }
+  | { kind: "updated" }
+  | { kind: "skipped"; reason: string }
+  | { kind: "archived_missing" };
+
+export interface SyncReportItem {
+  /** Database ID, or the parsed ID for additions that were not made */
+  id: string;
+  item_type: "chapter" | "scene" | "beat";
+  title: string;
+  action: SyncAction;
+}
+
+/** Change log returned by apply_sync */
+export interface SyncReport extends ReimportSummary {
+  /** Items left untouched, e.g. because they are locked */
+  items_skipped: number;
+  /** Scenes archived because they were removed from the source */
+  scenes_archived: number;
+  items: SyncReportItem[];
+}
+
 // =============================================================================
 // Export Types
 // Used for exporting projects to various formats (Markdown, DOCX)
@@ -358,8 +494,26 @@ export interface MarkdownExportOptions {
   export_name?: string;
   /** Create a snapshot before exporting */
   create_snapshot?: boolean;
+  /** Prefix scene headings with status and word count (review copy) */
+  annotate_scenes?: boolean;
+  include_references?: boolean;
+  /** Order scenes by compile position instead of binder position */
+  use_compile_order?: boolean;
+  /** Start each scene file with YAML frontmatter (title, status, POV) */
+  include_frontmatter?: boolean;
+  /** Heading level (1-6) for scene titles; defaults to 1 */
+  scene_heading_level?: number;
+  /** Heading level (1-6) for beat markers; defaults to 2 */
+  beat_heading_level?: number;
+  /** One file per scene (default), per chapter, or for the whole export */
+  output_granularity?: MarkdownGranularity;
+  /** Marker between scenes in per-chapter and single-file exports */
+  scene_break_style?: SceneBreakStyle;
 }
 
+/** How a Markdown export splits the manuscript into files */
+export type MarkdownGranularity = "per_scene" | "per_chapter" | "single_file";
+
 /** Options for Longform export */
 export interface LongformExportOptions {
   /** What to export (project, chapter, or scene) */
@@ -382,8 +536,10 @@ export type ChapterHeadingStyle =
   | "number_arabic"
   | "number_arabic_and_title";
 
-/** Scene break marker style for DOCX export */
-export type SceneBreakStyle = "hash" | "asterisks" | "asterism" | "blank_line";
+/** Built-in scene break markers */
+export type SceneBreakPreset = "hash" | "asterisks" | "asterism" | "blank_line";
+/** Scene break marker style for DOCX export; custom markers are trimmed to 20 characters */
+export type SceneBreakStyle = SceneBreakPreset | { custom: string };
 
 /** Font family for DOCX export */
 export type FontFamily = "courier_new" | "times_new_roman";
@@ -391,6 +547,15 @@ export type FontFamily = "courier_new" | "times_new_roman";
 /** Line spacing option for DOCX export */
 export type LineSpacingOption = "single" | "one_and_half" | "double";
 
+/** DOCX body paragraphs: first-line indent in twips (default 720) or block spacing */
+export type ParagraphStyle = { first_line_indent: number } | "block_spaced";
+
+/** Quotation marks used for exported prose (default "smart") */
+export type QuoteStyle = "straight" | "smart" | "guillemets";
+
+/** Dash and spacing conventions for exported prose (default "english") */
+export type PunctuationLocale = "english" | "french";
+
 /** Options for DOCX export */
 export interface DocxExportOptions {
   /** What to export (project, chapter, or scene) */
@@ -415,6 +580,125 @@ export interface DocxExportOptions {
   font_family?: FontFamily;
   /** Line spacing for body text */
   line_spacing?: LineSpacingOption;
+  /** Render each chapter's synopsis as a centered italic epigraph under its heading */
+  include_chapter_synopsis?: boolean;
+  /** Page margin on all four sides, in inches (default 1.0) */
+  margin_inches?: number;
+  /** Insert a Word table of contents field after the title page */
+  include_toc?: boolean;
+  /** Order scenes by compile position instead of binder position */
+  use_compile_order?: boolean;
+  /** Quotation marks for prose and synopses */
+  quote_style?: QuoteStyle;
+  /** Dash and spacing conventions for prose and synopses */
+  punctuation_locale?: PunctuationLocale;
+  /** First-line indents or block paragraphs for body text */
+  paragraph_style?: ParagraphStyle;
+  /** Added to every chapter number, e.g. 10 starts at "CHAPTER ELEVEN" */
+  chapter_number_offset?: number;
+}
+
+/** Options for RTF export */
+export interface RtfExportOptions {
+  /** What to export (project, chapter, or scene) */
+  scope: ExportScope;
+  /** Output file path (full path including filename) */
+  output_path: string;
+  /** Create a snapshot before exporting */
+  create_snapshot?: boolean;
+  /** Add page breaks between chapters */
+  page_breaks_between_chapters?: boolean;
+  /** Chapter heading style */
+  chapter_heading_style?: ChapterHeadingStyle;
+  /** Scene break marker style */
+  scene_break_style?: SceneBreakStyle;
+  /** Font family for body text */
+  font_family?: FontFamily;
+  /** Line spacing for body text */
+  line_spacing?: LineSpacingOption;
+  /** Quotation marks */
+  quote_style?: QuoteStyle;
+  /** Dash and spacing conventions */
+  punctuation_locale?: PunctuationLocale;
+}
+
+/** A writing session bounded by project word counts at start and end */
+export interface WritingSession {
+  id: string;
+  project_id: string;
+  /** Local date the session started on (YYYY-MM-DD) */
+  date: string;
+  start_word_count: number;
+  /** Null while the session is still in progress */
+  end_word_count: number | null;
+  created_at: string;
+}
+
+/** Words written on a single day, summed over that day's sessions */
+export interface SessionStat {
+  date: string;
+  words_written: number;
+  session_count: number;
+}
+
+/** Progress of the project word count towards its word target */
+export interface WordTargetProgress {
+  target: number | null;
+  current: number;
+  /** Percent of the target reached; may exceed 100. Null without a positive target */
+  percent: number | null;
+  /** Words left to reach the target; negative once it is exceeded */
+  remaining: number | null;
+}
+
+/** Scene counts by status for one chapter, for the progress dashboard */
+export interface ChapterStatusSummary {
+  chapter_id: string;
+  chapter_title: string;
+  /** Every status is present, with zero for statuses no scene has */
+  counts: Record<SceneStatus, number>;
+}
+
+/** Options for plain-text export */
+export interface TextExportOptions {
+  /** What to export (project, chapter, or scene) */
+  scope: ExportScope;
+  /** Output file path (full path including filename) */
+  output_path: string;
+  /** Create a snapshot before exporting */
+  create_snapshot?: boolean;
+  /** Chapter heading style */
+  chapter_heading_style?: ChapterHeadingStyle;
+  /** Scene break marker style */
+  scene_break_style?: SceneBreakStyle;
+  /** Quotation marks */
+  quote_style?: QuoteStyle;
+  /** Dash and spacing conventions */
+  punctuation_locale?: PunctuationLocale;
+}
+
+/** Options for LaTeX export */
+export interface LatexExportOptions {
+  /** What to export (project, chapter, or scene) */
+  scope: ExportScope;
+  /** Output file path (full path including filename) */
+  output_path: string;
+  /** Create a snapshot before exporting */
+  create_snapshot?: boolean;
+  /** Scene break marker style */
+  scene_break_style?: SceneBreakStyle;
+  /** Quotation marks */
+  quote_style?: QuoteStyle;
+  /** Dash and spacing conventions */
+  punctuation_locale?: PunctuationLocale;
+}
+
+/** Options for the character/location reference sheet export */
+export interface ReferenceSheetOptions {
+  /** Output directory path */
+  output_path: string;
+  /** Custom file name (without extension) */
+  export_name?: string | null;
 }
 
 /** Styling themes for EPUB export */
@@ -426,6 +710,13 @@ export interface EpubMetadata {
   author: string;
   description?: string;
   language: string;
+  /** Falls back to the project's publisher when omitted */
+  publisher?: string;
+  /** Falls back to the project's ISBN when omitted */
+  isbn?: string;
+  /** Falls back to the project's series when omitted */
+  series_name?: string;
+  series_index?: number;
 }
 
 /** Options for EPUB export */
@@ -448,6 +739,10 @@ export interface EpubExportOptions {
   include_cover_image: boolean;
   /** Cover image file path */
   cover_image_path?: string;
+  /** Quotation marks */
+  quote_style?: QuoteStyle;
+  /** Dash and spacing conventions */
+  punctuation_locale?: PunctuationLocale;
 }
 
 /** Detail level for treatment generation */
@@ -464,6 +759,18 @@ export interface TreatmentOptions {
   create_snapshot?: boolean;
 }
 
+/** Output format for a synopsis-only outline */
+export type OutlineFormat = "markdown" | "docx";
+
+/** Options for exporting chapter headings and scene synopses without prose */
+export interface OutlineExportOptions {
+  /** What to export (project, chapter, or scene) */
+  scope: ExportScope;
+  format: OutlineFormat;
+  /** Output file path (full path including filename) */
+  output_path: string;
+}
+
 /** Export mode for Scrivener */
 export type ScrivenerExportMode = "create_new" | "update";
 
@@ -495,6 +802,26 @@ export interface ExportResult {
   chapters_exported: number;
   /** Total scenes exported */
   scenes_exported: number;
+  /** Words of prose in the exported content, for exports that compute it */
+  word_count?: number | null;
+  /** Estimated manuscript pages at 250 words per page */
+  estimated_pages?: number | null;
+}
+
+/** Payload of the `export_progress` event emitted during DOCX and Markdown exports */
+export interface ExportProgress {
+  current: number;
+  total: number;
+  /** "chapters" while rendering, "writing" while saving the output file */
+  stage: "chapters" | "writing";
+}
+
+/** Result of backing up the whole database */
+export interface DatabaseBackup {
+  /** Path the backup was written to */
+  path: string;
+  /** Size of the backup file in bytes */
+  size_bytes: number;
 }
 
 // =============================================================================
@@ -505,6 +832,9 @@ export interface ExportResult {
 /** Trigger type for snapshot creation */
 export type SnapshotTrigger = "manual" | "export" | "auto";
 
+/** Compression used for a snapshot archive */
+export type SnapshotCompression = "none" | "gzip" | "zstd";
+
 /** Restore mode for snapshots */
 export type RestoreMode = "replace_current" | "create_new";
 
@@ -524,6 +854,9 @@ export interface SnapshotMetadata {
   beat_count: number;
   word_count?: number | null;
   schema_version: number;
+  compression: SnapshotCompression;
+  /** SHA-256 of the uncompressed payload; absent on older snapshots */
+  checksum?: string | null;
 }
 
 /** Options for creating a snapshot */
@@ -531,12 +864,38 @@ export interface CreateSnapshotOptions {
   name: string;
   description?: string;
   trigger_type: SnapshotTrigger;
+  compression?: SnapshotCompression;
 }
 
 /** Options for restoring a snapshot */
 export interface RestoreSnapshotOptions {
   mode: RestoreMode;
   new_project_name?: string;
+  /** Apply the restore; when false only a preview is returned */
+  confirm?: boolean;
+}
+
+/** What a restore will change, compared against the live project */
+export interface RestorePreview {
+  overwrites_current: boolean;
+  current_chapter_count: number;
+  current_scene_count: number;
+  current_beat_count: number;
+  current_word_count: number;
+  snapshot_chapter_count: number;
+  snapshot_scene_count: number;
+  snapshot_beat_count: number;
+  snapshot_word_count: number;
+  warnings: string[];
+}
+
+/** Result of restore_snapshot; project is set once the restore is confirmed */
+export interface RestoreSnapshotResult {
+  applied: boolean;
+  preview: RestorePreview;
+  project: Project | null;
+  /** Snapshot of the live project taken just before it was overwritten */
+  safety_snapshot: SnapshotMetadata | null;
 }
 
 /** Preview of a snapshot (lightweight metadata) */
@@ -545,6 +904,52 @@ export interface SnapshotPreview {
   project_name: string;
 }
 
+/** One point in a project's growth over time */
+export interface SnapshotHistoryPoint {
+  snapshot_id: string;
+  created_at: string;
+  word_count: number | null;
+  chapter_count: number;
+  scene_count: number;
+}
+
+/** Which snapshots prune_snapshots keeps; manual snapshots are always kept */
+export interface SnapshotRetentionPolicy {
+  /** Keep this many of the most recent snapshots */
+  keep_last_n?: number | null;
+  /** Keep snapshots created within this many days */
+  keep_days?: number | null;
+}
+
+/** Result of pruning snapshots */
+export interface PruneSnapshotsResult {
+  pruned: number;
+  bytes_reclaimed: number;
+}
+
+/** How an item differs between a snapshot and the current project */
+export type SnapshotDiffStatus = "added" | "removed" | "modified" | "unchanged";
+
+/** Comparison of one chapter, scene, or beat against a snapshot */
+export interface SnapshotDiffItem {
+  item_type: "chapter" | "scene" | "beat";
+  id: string;
+  title: string;
+  status: SnapshotDiffStatus;
+  /** Whether the prose text differs once HTML markup is stripped */
+  prose_changed: boolean;
+  word_delta: number;
+}
+
+/** Changes between a snapshot and the current project */
+export interface SnapshotDiff {
+  snapshot_id: string;
+  snapshot_word_count: number;
+  current_word_count: number;
+  word_delta: number;
+  items: SnapshotDiffItem[];
+}
+
 /** A scene within a story template */
 export interface TemplateScene {
   title: string;
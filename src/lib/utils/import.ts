@@ -32,6 +32,16 @@ const IMPORT_CONFIGS: Record<string, ImportOptions> = {
     filters: [{ name: "yWriter 7", extensions: ["yw7"] }],
     label: "yWriter file",
   },
+  odt: {
+    command: "import_odt",
+    filters: [{ name: "OpenDocument Text", extensions: ["odt"] }],
+    label: "ODT document",
+  },
+  docx: {
+    command: "import_docx",
+    filters: [{ name: "Word Document", extensions: ["docx"] }],
+    label: "Word document",
+  },
   longform: {
     command: "import_longform",
     filters: [{ name: "Longform Index", extensions: ["md", "markdown"] }],